@@ -0,0 +1,113 @@
+//! Session PnL / fee attribution per instrument (average-cost basis).
+//!
+//! Feeds the evidence/attribution chain (AT-005). Realized PnL is gross; fees are
+//! tracked separately so net PnL is `realized_pnl - fees_paid`.
+
+use std::collections::HashMap;
+
+use crate::execution::Side;
+
+/// Positions within this of zero are flat; absorbs f64 residue from summing fill sizes
+/// (0.1 + 0.2 closed by 0.3).
+const POSITION_EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributionError {
+    /// qty/price must be finite and > 0; fee must be finite.
+    InvalidFill,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct InstrumentAttribution {
+    /// Signed position (long > 0, short < 0).
+    position: f64,
+    avg_price: f64,
+    realized_pnl: f64,
+    fees_paid: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Attribution {
+    instruments: HashMap<String, InstrumentAttribution>,
+}
+
+impl Attribution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_fill(
+        &mut self,
+        instrument: &str,
+        side: Side,
+        qty: f64,
+        price: f64,
+        fee: f64,
+    ) -> Result<(), AttributionError> {
+        if !qty.is_finite() || !price.is_finite() || !fee.is_finite() || qty <= 0.0 || price <= 0.0
+        {
+            return Err(AttributionError::InvalidFill);
+        }
+
+        let entry = self.instruments.entry(instrument.to_string()).or_default();
+        entry.fees_paid += fee;
+
+        let signed_qty = match side {
+            Side::Buy => qty,
+            Side::Sell => -qty,
+        };
+
+        if entry.position.abs() <= POSITION_EPSILON
+            || entry.position.signum() == signed_qty.signum()
+        {
+            // Opening or adding: blend into the average cost
+            let open_qty = entry.position.abs();
+            entry.avg_price = (open_qty * entry.avg_price + qty * price) / (open_qty + qty);
+            entry.position += signed_qty;
+            return Ok(());
+        }
+
+        // Reducing: realize against the average cost, then open any remainder
+        // at the fill price (position flipped through zero)
+        let close_qty = qty.min(entry.position.abs());
+        entry.realized_pnl += close_qty * (price - entry.avg_price) * entry.position.signum();
+        let remaining = qty - close_qty;
+        if remaining > POSITION_EPSILON {
+            entry.position = remaining * signed_qty.signum();
+            entry.avg_price = price;
+        } else {
+            entry.position += signed_qty;
+            if entry.position.abs() <= POSITION_EPSILON {
+                entry.position = 0.0;
+                entry.avg_price = 0.0;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn realized_pnl(&self, instrument: &str) -> f64 {
+        self.instruments
+            .get(instrument)
+            .map_or(0.0, |entry| entry.realized_pnl)
+    }
+
+    pub fn fees_paid(&self, instrument: &str) -> f64 {
+        self.instruments
+            .get(instrument)
+            .map_or(0.0, |entry| entry.fees_paid)
+    }
+
+    /// Signed open position.
+    pub fn position(&self, instrument: &str) -> f64 {
+        self.instruments
+            .get(instrument)
+            .map_or(0.0, |entry| entry.position)
+    }
+
+    /// Average cost of the open position (0 when flat).
+    pub fn avg_price(&self, instrument: &str) -> f64 {
+        self.instruments
+            .get(instrument)
+            .map_or(0.0, |entry| entry.avg_price)
+    }
+}
@@ -0,0 +1,86 @@
+//! Decision replay: re-derives each capsule's trading mode with the current policy logic
+//! and reports where it disagrees with what was recorded, catching drift between live
+//! decisions and code changes.
+//!
+//! Capsules record `risk_state` in `inputs` and `trading_mode` in `outputs`, using the
+//! variant names (`Healthy`, `ReduceOnly`, ...). Capsules without a recorded `trading_mode`
+//! did not make a mode decision and are skipped.
+
+use crate::risk::{PolicyGuard, RiskState, TradingMode};
+
+use super::TruthCapsule;
+
+const INPUT_RISK_STATE: &str = "risk_state";
+const OUTPUT_TRADING_MODE: &str = "trading_mode";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecisionDiffKind {
+    ModeMismatch {
+        recorded: String,
+        recomputed: TradingMode,
+    },
+    /// The stored inputs cannot be re-run (missing or unparseable `risk_state`).
+    Unreplayable { input: &'static str },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionDiff {
+    pub decision_snapshot_id: String,
+    pub ts_ms: u64,
+    pub kind: DecisionDiffKind,
+}
+
+/// One diff per capsule that does not replay identically, in capsule order.
+pub fn replay_decisions(capsules: &[TruthCapsule]) -> Vec<DecisionDiff> {
+    capsules.iter().filter_map(replay_capsule).collect()
+}
+
+fn replay_capsule(capsule: &TruthCapsule) -> Option<DecisionDiff> {
+    let recorded = capsule.outputs.get(OUTPUT_TRADING_MODE)?;
+    let diff = |kind| DecisionDiff {
+        decision_snapshot_id: capsule.decision_snapshot_id.clone(),
+        ts_ms: capsule.ts_ms,
+        kind,
+    };
+    let Some(risk_state) = capsule
+        .inputs
+        .get(INPUT_RISK_STATE)
+        .and_then(|value| parse_risk_state(value))
+    else {
+        return Some(diff(DecisionDiffKind::Unreplayable {
+            input: INPUT_RISK_STATE,
+        }));
+    };
+    let recomputed = PolicyGuard::get_effective_mode(risk_state);
+    if recorded == trading_mode_name(recomputed) {
+        return None;
+    }
+    eprintln!(
+        "decision_replay_mismatch decision_snapshot_id={} recorded={} recomputed={}",
+        capsule.decision_snapshot_id,
+        recorded,
+        trading_mode_name(recomputed)
+    );
+    Some(diff(DecisionDiffKind::ModeMismatch {
+        recorded: recorded.clone(),
+        recomputed,
+    }))
+}
+
+fn parse_risk_state(value: &str) -> Option<RiskState> {
+    match value {
+        "Healthy" => Some(RiskState::Healthy),
+        "Degraded" => Some(RiskState::Degraded),
+        "Maintenance" => Some(RiskState::Maintenance),
+        "Kill" => Some(RiskState::Kill),
+        _ => None,
+    }
+}
+
+fn trading_mode_name(mode: TradingMode) -> &'static str {
+    match mode {
+        TradingMode::Active => "Active",
+        TradingMode::ReduceOnly => "ReduceOnly",
+        TradingMode::Kill => "Kill",
+    }
+}
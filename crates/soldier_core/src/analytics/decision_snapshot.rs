@@ -0,0 +1,200 @@
+//! Decision Snapshot persistence (CONTRACT §Decision Snapshot).
+//!
+//! A compact top-N L2 snapshot is captured at decision time and appended to a sink keyed by
+//! `decision_snapshot_id`. Every failed write bumps `write_errors()`, which feeds the
+//! EvidenceGuard `decision_snapshot_write_errors` input.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::execution::{L2BookLevel, L2BookSnapshot};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionSnapshot {
+    pub decision_snapshot_id: String,
+    pub instrument_id: String,
+    pub ts_ms: u64,
+    pub bids: Vec<L2BookLevel>,
+    pub asks: Vec<L2BookLevel>,
+}
+
+impl DecisionSnapshot {
+    /// Captures the best `depth` levels per side of `book`.
+    pub fn from_book(
+        decision_snapshot_id: &str,
+        instrument_id: &str,
+        book: &L2BookSnapshot,
+        depth: usize,
+    ) -> Self {
+        Self {
+            decision_snapshot_id: decision_snapshot_id.to_string(),
+            instrument_id: instrument_id.to_string(),
+            ts_ms: book.ts_ms,
+            bids: book.bids.iter().take(depth).cloned().collect(),
+            asks: book.asks.iter().take(depth).cloned().collect(),
+        }
+    }
+
+    /// One append-only line: `id|instrument|ts_ms|bids|asks`, levels as `price@qty`.
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            self.decision_snapshot_id,
+            self.instrument_id,
+            self.ts_ms,
+            levels_to_field(&self.bids),
+            levels_to_field(&self.asks)
+        )
+    }
+}
+
+/// `snapshot_coverage_pct` as a fraction in `[0, 1]`: the share of `expected_interval_ms`
+/// slots in the trailing `window_hours` (ending at `now_ms`) holding at least one snapshot.
+/// Slots are counted once, so a burst cannot mask a gap elsewhere in the window; timestamps
+/// outside the window are ignored and a zero interval or window yields `0.0`.
+pub fn coverage_pct(
+    snapshots: &[u64],
+    window_hours: u64,
+    now_ms: u64,
+    expected_interval_ms: u64,
+) -> f64 {
+    let window_ms = window_hours.saturating_mul(3_600_000);
+    if window_ms == 0 || expected_interval_ms == 0 {
+        return 0.0;
+    }
+    let expected_slots = window_ms.div_ceil(expected_interval_ms);
+    let mut slots: Vec<u64> = snapshots
+        .iter()
+        .filter(|&&ts_ms| ts_ms <= now_ms)
+        .map(|&ts_ms| (now_ms - ts_ms) / expected_interval_ms)
+        .filter(|&slot| slot < expected_slots)
+        .collect();
+    slots.sort_unstable();
+    slots.dedup();
+    (slots.len() as f64 / expected_slots as f64).clamp(0.0, 1.0)
+}
+
+fn levels_to_field(levels: &[L2BookLevel]) -> String {
+    levels
+        .iter()
+        .map(|level| format!("{}@{}", level.price, level.qty))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecisionSnapshotError {
+    /// The sink refused the record (capacity exhausted, e.g. disk full).
+    SinkFull,
+    Io(String),
+}
+
+pub trait DecisionSnapshotWriter {
+    fn write(&self, snapshot: &DecisionSnapshot) -> Result<(), DecisionSnapshotError>;
+
+    /// Failed writes since construction.
+    fn write_errors(&self) -> u64;
+}
+
+fn record_write_error(counter: &AtomicU64, err: &DecisionSnapshotError) {
+    let total = counter.fetch_add(1, Ordering::Relaxed) + 1;
+    eprintln!("decision_snapshot_write_errors_total={total} error={err:?}");
+}
+
+/// Default sink; `with_capacity` bounds it so tests can simulate a full disk.
+#[derive(Debug, Default)]
+pub struct InMemoryDecisionSnapshotWriter {
+    snapshots: Mutex<Vec<DecisionSnapshot>>,
+    capacity: Option<usize>,
+    write_errors: AtomicU64,
+}
+
+impl InMemoryDecisionSnapshotWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    pub fn snapshots(&self) -> Vec<DecisionSnapshot> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<DecisionSnapshot>> {
+        match self.snapshots.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                eprintln!("decision_snapshot writer lock poisoned, recovering");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+impl DecisionSnapshotWriter for InMemoryDecisionSnapshotWriter {
+    fn write(&self, snapshot: &DecisionSnapshot) -> Result<(), DecisionSnapshotError> {
+        let mut snapshots = self.lock();
+        if let Some(capacity) = self.capacity
+            && snapshots.len() >= capacity
+        {
+            let err = DecisionSnapshotError::SinkFull;
+            record_write_error(&self.write_errors, &err);
+            return Err(err);
+        }
+        snapshots.push(snapshot.clone());
+        Ok(())
+    }
+
+    fn write_errors(&self) -> u64 {
+        self.write_errors.load(Ordering::Relaxed)
+    }
+}
+
+/// Appends one line per snapshot (see [`DecisionSnapshot::to_line`]) to `path`.
+#[derive(Debug)]
+pub struct FileDecisionSnapshotWriter {
+    path: PathBuf,
+    write_errors: AtomicU64,
+}
+
+impl FileDecisionSnapshotWriter {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            write_errors: AtomicU64::new(0),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl DecisionSnapshotWriter for FileDecisionSnapshotWriter {
+    fn write(&self, snapshot: &DecisionSnapshot) -> Result<(), DecisionSnapshotError> {
+        append_line(&self.path, &snapshot.to_line()).map_err(|err| {
+            let err = DecisionSnapshotError::Io(err.to_string());
+            record_write_error(&self.write_errors, &err);
+            err
+        })
+    }
+
+    fn write_errors(&self) -> u64 {
+        self.write_errors.load(Ordering::Relaxed)
+    }
+}
+
+/// Appends `line` plus a newline to `path` and syncs it; shared by the analytics file sinks.
+pub(crate) fn append_line(path: &Path, line: &str) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    file.sync_data()
+}
@@ -0,0 +1,22 @@
+pub mod attribution;
+pub mod decision_replay;
+pub mod decision_snapshot;
+pub mod replay_apply;
+pub mod rolling_count;
+pub mod truth_capsule;
+
+pub use attribution::{Attribution, AttributionError};
+pub use decision_replay::{DecisionDiff, DecisionDiffKind, replay_decisions};
+pub use decision_snapshot::{
+    DecisionSnapshot, DecisionSnapshotError, DecisionSnapshotWriter, FileDecisionSnapshotWriter,
+    InMemoryDecisionSnapshotWriter, coverage_pct,
+};
+pub use replay_apply::{
+    REPLAY_DEGRADED_MIN_COVERAGE, REPLAY_GOOD_MIN_COVERAGE, ReplayApplyMode, ReplayQuality,
+    replay_apply_decision, replay_quality,
+};
+pub use rolling_count::RollingCount;
+pub use truth_capsule::{
+    FileTruthCapsuleWriter, InMemoryTruthCapsuleWriter, TruthCapsule, TruthCapsuleError,
+    TruthCapsuleWriter,
+};
@@ -0,0 +1,78 @@
+//! Replay quality ladder and patch apply mode (CONTRACT §Replay Gatekeeper).
+//!
+//! `snapshot_coverage_pct` is the fraction from [`super::coverage_pct`], so the contract's
+//! 95% / 80% thresholds are `0.95` / `0.80` here.
+
+pub const REPLAY_GOOD_MIN_COVERAGE: f64 = 0.95;
+pub const REPLAY_DEGRADED_MIN_COVERAGE: f64 = 0.80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayQuality {
+    Good,
+    Degraded,
+    Broken,
+}
+
+impl ReplayQuality {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReplayQuality::Good => "GOOD",
+            ReplayQuality::Degraded => "DEGRADED",
+            ReplayQuality::Broken => "BROKEN",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayApplyMode {
+    Apply,
+    ApplyWithHaircut,
+    ShadowOnly,
+}
+
+impl ReplayApplyMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReplayApplyMode::Apply => "APPLY",
+            ReplayApplyMode::ApplyWithHaircut => "APPLY_WITH_HAIRCUT",
+            ReplayApplyMode::ShadowOnly => "SHADOW_ONLY",
+        }
+    }
+}
+
+/// `None` coverage means it could not be computed; that, unreadable snapshots, or a
+/// non-finite value are all BROKEN.
+pub fn replay_quality(coverage_pct: Option<f64>, snapshots_readable: bool) -> ReplayQuality {
+    match coverage_pct {
+        Some(coverage) if snapshots_readable && coverage.is_finite() => {
+            if coverage >= REPLAY_GOOD_MIN_COVERAGE {
+                ReplayQuality::Good
+            } else if coverage >= REPLAY_DEGRADED_MIN_COVERAGE {
+                ReplayQuality::Degraded
+            } else {
+                ReplayQuality::Broken
+            }
+        }
+        _ => ReplayQuality::Broken,
+    }
+}
+
+/// Apply mode and the `open_haircut_mult` currently applied (`1.0` when no haircut).
+/// DEGRADED applies with the configured haircut, which must be in `(0, 1]`; a missing or
+/// out-of-range haircut is treated as BROKEN and falls back to SHADOW_ONLY.
+pub fn replay_apply_decision(
+    replay_quality: ReplayQuality,
+    open_haircut_mult: Option<f64>,
+) -> (ReplayApplyMode, f64) {
+    match replay_quality {
+        ReplayQuality::Good => (ReplayApplyMode::Apply, 1.0),
+        ReplayQuality::Degraded => match open_haircut_mult {
+            Some(mult) if mult > 0.0 && mult <= 1.0 => (ReplayApplyMode::ApplyWithHaircut, mult),
+            _ => {
+                eprintln!("replay_apply_shadow_only reason=open_haircut_mult_invalid");
+                (ReplayApplyMode::ShadowOnly, 1.0)
+            }
+        },
+        ReplayQuality::Broken => (ReplayApplyMode::ShadowOnly, 1.0),
+    }
+}
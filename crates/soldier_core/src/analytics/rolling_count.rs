@@ -0,0 +1,48 @@
+//! Rolling event counter (e.g. 429 / 10028 responses over 5 minutes).
+//!
+//! One instance per event type feeds every consumer (`/status`, PolicyGuard inputs) so their
+//! `count_*_5m` values cannot diverge. Timestamps older than `retention_ms` are pruned on each
+//! observe and query, so memory is bounded by the event rate within the retention window.
+
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct RollingCount {
+    retention_ms: u64,
+    /// Event timestamps in observation order.
+    events: VecDeque<u64>,
+}
+
+impl RollingCount {
+    /// `retention_ms` is the longest window callers may query.
+    pub fn new(retention_ms: u64) -> Self {
+        Self {
+            retention_ms,
+            events: VecDeque::new(),
+        }
+    }
+
+    pub fn observe(&mut self, now_ms: u64) {
+        self.prune(now_ms);
+        self.events.push_back(now_ms);
+    }
+
+    /// Events in `(now_ms - window_ms, now_ms]`; `window_ms` is capped at the retention.
+    pub fn count_within(&mut self, now_ms: u64, window_ms: u64) -> u64 {
+        self.prune(now_ms);
+        let window_ms = window_ms.min(self.retention_ms);
+        self.events
+            .iter()
+            .filter(|&&ts| ts <= now_ms && now_ms - ts < window_ms)
+            .count() as u64
+    }
+
+    fn prune(&mut self, now_ms: u64) {
+        while let Some(&oldest) = self.events.front() {
+            if now_ms.saturating_sub(oldest) < self.retention_ms {
+                break;
+            }
+            self.events.pop_front();
+        }
+    }
+}
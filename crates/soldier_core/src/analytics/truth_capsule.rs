@@ -0,0 +1,152 @@
+//! Truth Capsule persistence.
+//!
+//! A capsule records the inputs and outputs of one decision, keyed by `decision_snapshot_id`,
+//! so the decision can be audited later. Mirrors `decision_snapshot`: every failed write bumps
+//! `truth_capsule_write_errors()`, the EvidenceGuard `truth_capsule_write_errors` input.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::decision_snapshot::append_line;
+use crate::json::json_string;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TruthCapsule {
+    pub decision_snapshot_id: String,
+    pub ts_ms: u64,
+    /// Keyed maps keep serialization order deterministic.
+    pub inputs: BTreeMap<String, String>,
+    pub outputs: BTreeMap<String, String>,
+}
+
+impl TruthCapsule {
+    /// Compact JSON with sorted keys:
+    /// `{"decision_snapshot_id":..,"inputs":{..},"outputs":{..},"ts_ms":..}`.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"decision_snapshot_id\":{},\"inputs\":{},\"outputs\":{},\"ts_ms\":{}}}",
+            json_string(&self.decision_snapshot_id),
+            json_object(&self.inputs),
+            json_object(&self.outputs),
+            self.ts_ms
+        )
+    }
+}
+
+fn json_object(fields: &BTreeMap<String, String>) -> String {
+    let body = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{}", json_string(key), json_string(value)))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{{{body}}}")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TruthCapsuleError {
+    /// The sink refused the record (capacity exhausted, e.g. disk full).
+    SinkFull,
+    Io(String),
+}
+
+pub trait TruthCapsuleWriter {
+    fn write(&self, capsule: &TruthCapsule) -> Result<(), TruthCapsuleError>;
+
+    /// Failed writes since construction.
+    fn truth_capsule_write_errors(&self) -> u64;
+}
+
+fn record_write_error(counter: &AtomicU64, err: &TruthCapsuleError) {
+    let total = counter.fetch_add(1, Ordering::Relaxed) + 1;
+    eprintln!("truth_capsule_write_errors_total={total} error={err:?}");
+}
+
+/// Default sink holding serialized capsules; `with_capacity` simulates a full disk.
+#[derive(Debug, Default)]
+pub struct InMemoryTruthCapsuleWriter {
+    lines: Mutex<Vec<String>>,
+    capacity: Option<usize>,
+    write_errors: AtomicU64,
+}
+
+impl InMemoryTruthCapsuleWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        match self.lines.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                eprintln!("truth_capsule writer lock poisoned, recovering");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+impl TruthCapsuleWriter for InMemoryTruthCapsuleWriter {
+    fn write(&self, capsule: &TruthCapsule) -> Result<(), TruthCapsuleError> {
+        let mut lines = self.lock();
+        if let Some(capacity) = self.capacity
+            && lines.len() >= capacity
+        {
+            let err = TruthCapsuleError::SinkFull;
+            record_write_error(&self.write_errors, &err);
+            return Err(err);
+        }
+        lines.push(capsule.to_json());
+        Ok(())
+    }
+
+    fn truth_capsule_write_errors(&self) -> u64 {
+        self.write_errors.load(Ordering::Relaxed)
+    }
+}
+
+/// Appends one JSON line per capsule to `path`.
+#[derive(Debug)]
+pub struct FileTruthCapsuleWriter {
+    path: PathBuf,
+    write_errors: AtomicU64,
+}
+
+impl FileTruthCapsuleWriter {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            write_errors: AtomicU64::new(0),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl TruthCapsuleWriter for FileTruthCapsuleWriter {
+    fn write(&self, capsule: &TruthCapsule) -> Result<(), TruthCapsuleError> {
+        append_line(&self.path, &capsule.to_json()).map_err(|err| {
+            let err = TruthCapsuleError::Io(err.to_string());
+            record_write_error(&self.write_errors, &err);
+            err
+        })
+    }
+
+    fn truth_capsule_write_errors(&self) -> u64 {
+        self.write_errors.load(Ordering::Relaxed)
+    }
+}
@@ -4,12 +4,15 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::risk::{FeeModelSnapshot, FeeStalenessConfig, RiskState, evaluate_fee_staleness};
 
+use super::gate::evaluate_liquidity_gate_with_metrics;
+use super::gates::evaluate_net_edge_gate_with_metrics;
+use super::pricer::price_ioc_limit_with_metrics;
+use super::quantize::quantize_steps_with_metrics;
 use super::{
     InstrumentQuantization, IntentClassification, L2BookSnapshot, LiquidityGateConfig,
     LiquidityGateIntent, LiquidityGateRejectReason, NetEdgeGateIntent, NetEdgeRejectReason,
     OrderIntent, OrderTypeGuardConfig, OrderTypeRejectReason, PreflightReject, PricerIntent,
-    QuantizeRejectReason, RejectReason, Side, evaluate_liquidity_gate, evaluate_net_edge_gate,
-    preflight, price_ioc_limit, quantize_steps,
+    QuantizeRejectReason, RejectReason, Side, TickRounding, preflight,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -50,6 +53,10 @@ pub enum BuildOrderIntentRejectReason {
 pub enum BuildOrderIntentError {
     Preflight(PreflightReject),
     Rejected(BuildOrderIntentRejectReason),
+    /// `OrderIntentBuilder::build` without a conditionally required field.
+    MissingField {
+        field: &'static str,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -58,6 +65,15 @@ pub enum GateSequenceResult {
     Rejected,
 }
 
+impl BuildOrderIntentOutcome {
+    pub fn gate_sequence_result(&self) -> GateSequenceResult {
+        match self {
+            BuildOrderIntentOutcome::Allowed => GateSequenceResult::Allowed,
+            BuildOrderIntentOutcome::Rejected(_) => GateSequenceResult::Rejected,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RecordIntentOutcome {
     Recorded,
@@ -155,6 +171,106 @@ pub fn gate_sequence_total(result: GateSequenceResult) -> u64 {
     }
 }
 
+impl GateStep {
+    pub fn name(self) -> &'static str {
+        match self {
+            GateStep::Preflight => "Preflight",
+            GateStep::Quantize => "Quantize",
+            GateStep::FeeCache => "FeeCache",
+            GateStep::LiquidityGate => "LiquidityGate",
+            GateStep::NetEdgeGate => "NetEdgeGate",
+            GateStep::Pricer => "Pricer",
+        }
+    }
+}
+
+impl DispatchStep {
+    pub fn name(self) -> &'static str {
+        match self {
+            DispatchStep::RecordIntent => "RecordIntent",
+            DispatchStep::DispatchAttempt => "DispatchAttempt",
+        }
+    }
+}
+
+impl BuildOrderIntentRejectReason {
+    /// Gate whose evaluation produced this reject; `None` outside the gate sequence.
+    pub fn gate_step(&self) -> Option<GateStep> {
+        match self {
+            BuildOrderIntentRejectReason::Preflight(_) => Some(GateStep::Preflight),
+            BuildOrderIntentRejectReason::Quantize(_) => Some(GateStep::Quantize),
+            BuildOrderIntentRejectReason::DispatchAuth(_) => Some(GateStep::FeeCache),
+            BuildOrderIntentRejectReason::LiquidityGate(_) => Some(GateStep::LiquidityGate),
+            BuildOrderIntentRejectReason::NetEdge(_) => Some(GateStep::NetEdgeGate),
+            BuildOrderIntentRejectReason::Pricer(_) => Some(GateStep::Pricer),
+            BuildOrderIntentRejectReason::MissingContext
+            | BuildOrderIntentRejectReason::RecordedBeforeDispatch => None,
+        }
+    }
+}
+
+/// One line per gate in trace order: `<n>. <gate> pass` or `<n>. <gate> REJECT <reason>`.
+/// A reject not owned by the last traced gate (e.g. MissingContext) gets its own `-` line.
+pub fn render_gate_trace(steps: &[GateStep], outcome: &BuildOrderIntentOutcome) -> String {
+    let rejecting = match outcome {
+        BuildOrderIntentOutcome::Rejected(reason)
+            if reason.gate_step().is_some() && reason.gate_step() == steps.last().copied() =>
+        {
+            Some(reason)
+        }
+        _ => None,
+    };
+    let mut out = String::new();
+    for (index, step) in steps.iter().enumerate() {
+        let reject = rejecting.filter(|_| index + 1 == steps.len());
+        push_trace_line(&mut out, &(index + 1).to_string(), step.name(), reject);
+    }
+    if let BuildOrderIntentOutcome::Rejected(reason) = outcome
+        && rejecting.is_none()
+        && !matches!(reason, BuildOrderIntentRejectReason::RecordedBeforeDispatch)
+    {
+        push_trace_line(&mut out, "-", "", Some(reason));
+    }
+    out
+}
+
+/// Dispatch-side counterpart of [`render_gate_trace`]; marks RecordIntent on
+/// `RecordedBeforeDispatch`.
+pub fn render_dispatch_trace(steps: &[DispatchStep], outcome: &BuildOrderIntentOutcome) -> String {
+    let rejecting = match outcome {
+        BuildOrderIntentOutcome::Rejected(
+            reason @ BuildOrderIntentRejectReason::RecordedBeforeDispatch,
+        ) => Some(reason),
+        _ => None,
+    };
+    let mut out = String::new();
+    for (index, step) in steps.iter().enumerate() {
+        let reject = rejecting.filter(|_| index + 1 == steps.len());
+        push_trace_line(&mut out, &(index + 1).to_string(), step.name(), reject);
+    }
+    out
+}
+
+/// Step names are padded to the longest `GateStep`/`DispatchStep` name.
+const TRACE_NAME_WIDTH: usize = 15;
+
+fn push_trace_line(
+    out: &mut String,
+    position: &str,
+    name: &str,
+    reject: Option<&BuildOrderIntentRejectReason>,
+) {
+    use std::fmt::Write as _;
+
+    let _ = match reject {
+        Some(reason) => writeln!(
+            out,
+            "{position:>2}. {name:<TRACE_NAME_WIDTH$} REJECT {reason:?}"
+        ),
+        None => writeln!(out, "{position:>2}. {name:<TRACE_NAME_WIDTH$} pass"),
+    };
+}
+
 fn reset_trace() {
     GATE_SEQUENCE_TRACE.with(|trace| trace.borrow_mut().clear());
     DISPATCH_TRACE.with(|trace| trace.borrow_mut().clear());
@@ -193,14 +309,6 @@ fn effective_risk_state(primary: RiskState, fallback: RiskState) -> RiskState {
     fallback
 }
 
-fn fee_rate_for_model(model: &FeeModelSnapshot, is_maker: bool) -> f64 {
-    if is_maker {
-        model.maker_fee_rate
-    } else {
-        model.taker_fee_rate
-    }
-}
-
 fn estimate_notional_usd(fair_price: f64, qty: f64) -> f64 {
     fair_price.abs() * qty.abs()
 }
@@ -251,25 +359,80 @@ pub fn build_order_intent(
         }
     };
 
+    if let Err(reason) = run_context_gates(&context, true) {
+        return Err(reject_with_error(reason));
+    }
+
+    record_dispatch_step(DispatchStep::RecordIntent);
+    if let Some(observers) = context.observers.as_ref() {
+        observers.record_intent();
+    }
+    if context.record_outcome == RecordIntentOutcome::Failed {
+        return Err(reject_with_error(
+            BuildOrderIntentRejectReason::RecordedBeforeDispatch,
+        ));
+    }
+
+    record_dispatch_step(DispatchStep::DispatchAttempt);
+    if let Some(observers) = context.observers.as_ref() {
+        observers.record_dispatch();
+    }
+
+    finish_allowed();
+    Ok(intent)
+}
+
+/// build_order_intent_dry_run evaluates the same gate sequence as build_order_intent against
+/// an explicit context and returns the outcome without dispatch side effects: no RecordIntent
+/// or DispatchAttempt step, no observer updates, no gate_sequence_total increment, and
+/// take_build_order_intent_outcome() is left untouched. The gate trace is still recorded and
+/// readable via take_gate_sequence_trace(). Per-gate reject counters and metric lines are not
+/// touched, so a dry run never shows up in live gate metrics.
+pub fn build_order_intent_dry_run(
+    context: &BuildOrderIntentContext,
+    intent: OrderIntent,
+    config: OrderTypeGuardConfig,
+) -> BuildOrderIntentOutcome {
+    GATE_SEQUENCE_TRACE.with(|trace| trace.borrow_mut().clear());
+    DISPATCH_TRACE.with(|trace| trace.borrow_mut().clear());
+
+    record_gate_step(GateStep::Preflight);
+    if let Err(err) = preflight::preflight_intent_with_metrics(&intent, config, false) {
+        return BuildOrderIntentOutcome::Rejected(BuildOrderIntentRejectReason::Preflight(
+            err.reason,
+        ));
+    }
+
+    match run_context_gates(context, false) {
+        Ok(()) => BuildOrderIntentOutcome::Allowed,
+        Err(reason) => BuildOrderIntentOutcome::Rejected(reason),
+    }
+}
+
+/// Runs every gate after preflight (Quantize through Pricer), recording each step. Gate
+/// reject counters are bumped only when `record_metrics` is set.
+fn run_context_gates(
+    context: &BuildOrderIntentContext,
+    record_metrics: bool,
+) -> Result<(), BuildOrderIntentRejectReason> {
     record_gate_step(GateStep::Quantize);
-    let quantized = match quantize_steps(
+    let quantized = quantize_steps_with_metrics(
         context.side,
         context.raw_qty,
         context.raw_limit_price,
         &context.quantization,
-    ) {
-        Ok(quantized) => quantized,
-        Err(err) => {
-            return Err(reject_with_error(BuildOrderIntentRejectReason::Quantize(
-                err.reason,
-            )));
-        }
-    };
+        record_metrics,
+    )
+    .map_err(|err| BuildOrderIntentRejectReason::Quantize(err.reason))?;
 
     record_gate_step(GateStep::FeeCache);
-    let fee_rate = fee_rate_for_model(&context.fee_model, context.is_maker);
+    // Costed at fair price, the same notional the slippage and net-edge estimates use.
+    let notional_usd = estimate_notional_usd(context.fair_price, quantized.qty_q);
+    let order_cost = context
+        .fee_model
+        .fee_for_notional(notional_usd, context.is_maker);
     let fee_decision = evaluate_fee_staleness(
-        fee_rate,
+        order_cost.map_or(f64::NAN, |cost| cost.fee_bps / 10_000.0),
         context.now_ms,
         context.fee_model.fee_model_cached_at_ts_ms,
         context.fee_staleness_config,
@@ -278,8 +441,8 @@ pub fn build_order_intent(
     if context.classification == IntentClassification::Open
         && combined_risk_state != RiskState::Healthy
     {
-        return Err(reject_with_error(
-            BuildOrderIntentRejectReason::DispatchAuth(combined_risk_state),
+        return Err(BuildOrderIntentRejectReason::DispatchAuth(
+            combined_risk_state,
         ));
     }
 
@@ -291,63 +454,42 @@ pub fn build_order_intent(
         l2_snapshot: context.l2_snapshot.as_ref(),
         now_ms: context.now_ms,
     };
-    let liquidity_outcome =
-        match evaluate_liquidity_gate(&liquidity_intent, context.liquidity_config) {
-            Ok(outcome) => outcome,
-            Err(err) => {
-                return Err(reject_with_error(
-                    BuildOrderIntentRejectReason::LiquidityGate(err.reason),
-                ));
-            }
-        };
+    let liquidity_outcome = evaluate_liquidity_gate_with_metrics(
+        &liquidity_intent,
+        context.liquidity_config,
+        record_metrics,
+    )
+    .map_err(|err| BuildOrderIntentRejectReason::LiquidityGate(err.reason))?;
 
     record_gate_step(GateStep::NetEdgeGate);
-    let notional_usd = estimate_notional_usd(context.fair_price, quantized.qty_q);
     let expected_slippage_usd = estimate_slippage_usd(liquidity_outcome.slippage_bps, notional_usd);
-    let fee_estimate_usd = fee_decision.fee_rate_effective * notional_usd;
+    // An uncostable order has no fee estimate: the net edge gate rejects it as missing input
+    // and the pricer rejects the non-finite fee, so neither treats it as free to trade.
+    let fee_estimate_usd = order_cost.map(|cost| fee_decision.apply_to(cost).fee_usd);
     let net_edge_intent = NetEdgeGateIntent {
         classification: context.classification,
         gross_edge_usd: Some(context.gross_edge_usd),
-        fee_usd: Some(fee_estimate_usd),
+        fee_usd: fee_estimate_usd,
         expected_slippage_usd: Some(expected_slippage_usd),
         min_edge_usd: Some(context.min_edge_usd),
+        notional_usd: Some(notional_usd),
     };
-    if let Err(err) = evaluate_net_edge_gate(&net_edge_intent) {
-        return Err(reject_with_error(BuildOrderIntentRejectReason::NetEdge(
-            err.reason,
-        )));
-    }
+    evaluate_net_edge_gate_with_metrics(&net_edge_intent, record_metrics)
+        .map_err(|err| BuildOrderIntentRejectReason::NetEdge(err.reason))?;
 
     record_gate_step(GateStep::Pricer);
     let pricer_intent = PricerIntent {
         side: context.side,
         fair_price: context.fair_price,
         gross_edge_usd: context.gross_edge_usd,
-        fee_estimate_usd,
+        fee_estimate_usd: fee_estimate_usd.unwrap_or(f64::NAN),
         min_edge_usd: context.min_edge_usd,
         qty: quantized.qty_q,
+        tick_size: Some(context.quantization.tick_size),
+        tick_rounding: TickRounding::Passive,
     };
-    if let Err(err) = price_ioc_limit(&pricer_intent) {
-        return Err(reject_with_error(BuildOrderIntentRejectReason::Pricer(
-            err.reason,
-        )));
-    }
+    price_ioc_limit_with_metrics(&pricer_intent, record_metrics)
+        .map_err(|err| BuildOrderIntentRejectReason::Pricer(err.reason))?;
 
-    record_dispatch_step(DispatchStep::RecordIntent);
-    if let Some(observers) = context.observers.as_ref() {
-        observers.record_intent();
-    }
-    if context.record_outcome == RecordIntentOutcome::Failed {
-        return Err(reject_with_error(
-            BuildOrderIntentRejectReason::RecordedBeforeDispatch,
-        ));
-    }
-
-    record_dispatch_step(DispatchStep::DispatchAttempt);
-    if let Some(observers) = context.observers.as_ref() {
-        observers.record_dispatch();
-    }
-
-    finish_allowed();
-    Ok(intent)
+    Ok(())
 }
@@ -0,0 +1,72 @@
+//! Pre-dispatch size clamp: keeps an intent's quantity within the venue max order size and
+//! the remaining exposure budget instead of sending it to be bounced.
+
+use super::OrderIntent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClampPolicy {
+    RejectOnExceed,
+    ClampToMax,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClampRejectReason {
+    /// No finite sized `notional` on the intent, or a non-finite limit.
+    QuantityMissing,
+    ExceedsVenueMax,
+    ExceedsBudget,
+    /// The allowed quantity is zero (budget exhausted).
+    ClampedToZero,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClampReject {
+    pub reason: ClampRejectReason,
+    pub requested_qty: Option<f64>,
+    pub max_allowed_qty: f64,
+}
+
+/// Returns the intent with `notional.qty` reduced to `min(venue_max, budget_headroom)` when it
+/// exceeds either (under `ClampToMax`), or unchanged when it fits. The sign of `qty` is kept.
+pub fn clamp_intent_size(
+    intent: OrderIntent,
+    venue_max: f64,
+    budget_headroom: f64,
+    policy: ClampPolicy,
+) -> Result<OrderIntent, ClampReject> {
+    let max_allowed_qty = venue_max.min(budget_headroom).max(0.0);
+    let reject = |reason, requested_qty| ClampReject {
+        reason,
+        requested_qty,
+        max_allowed_qty,
+    };
+
+    let Some(mut notional) = intent.notional else {
+        return Err(reject(ClampRejectReason::QuantityMissing, None));
+    };
+    let requested = notional.qty.abs();
+    if !requested.is_finite() || !venue_max.is_finite() || !budget_headroom.is_finite() {
+        return Err(reject(ClampRejectReason::QuantityMissing, Some(requested)));
+    }
+    if requested <= max_allowed_qty {
+        return Ok(intent);
+    }
+
+    let exceeded = if requested > venue_max {
+        ClampRejectReason::ExceedsVenueMax
+    } else {
+        ClampRejectReason::ExceedsBudget
+    };
+    if policy == ClampPolicy::RejectOnExceed {
+        return Err(reject(exceeded, Some(requested)));
+    }
+    if max_allowed_qty <= 0.0 {
+        return Err(reject(ClampRejectReason::ClampedToZero, Some(requested)));
+    }
+
+    notional.qty = max_allowed_qty.copysign(notional.qty);
+    Ok(OrderIntent {
+        notional: Some(notional),
+        ..intent
+    })
+}
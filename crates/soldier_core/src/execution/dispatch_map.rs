@@ -3,7 +3,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use crate::risk::RiskState;
 use crate::venue::InstrumentKind;
 
-use super::{OrderSize, contracts_amount_matches};
+use super::{OrderSize, Side, contracts_amount_matches};
 
 pub struct DispatchMetrics {
     unit_mismatch_total: AtomicU64,
@@ -65,6 +65,22 @@ pub fn reduce_only_from_intent_classification(
     }
 }
 
+/// Position-aware variant: a Close/Hedge is tagged `reduce_only` only when `side` is opposite
+/// the current signed position (i.e. it shrinks `|position|`). A same-side or flat-book
+/// "close" would be rejected or silently not reduce, so it is left untagged (OPEN).
+pub fn reduce_only_from_intent_and_position(
+    classification: IntentClassification,
+    side: Side,
+    signed_position_qty: f64,
+) -> Option<bool> {
+    reduce_only_from_intent_classification(classification)?;
+    let reduces = match side {
+        Side::Sell => signed_position_qty > 0.0,
+        Side::Buy => signed_position_qty < 0.0,
+    };
+    reduces.then_some(true)
+}
+
 pub fn map_order_size_to_deribit_amount(
     instrument_kind: InstrumentKind,
     order_size: &OrderSize,
@@ -97,7 +113,7 @@ pub fn map_order_size_to_deribit_amount_with_metrics(
             (amount, amount)
         }
         InstrumentKind::Perpetual | InstrumentKind::InverseFuture => {
-            if index_price <= 0.0 {
+            if !index_price.is_finite() || index_price <= 0.0 {
                 return reject_unit_mismatch(metrics, "invalid_index_price", None);
             }
             let amount = order_size.qty_usd;
@@ -110,6 +126,9 @@ pub fn map_order_size_to_deribit_amount_with_metrics(
         Some(amount) => amount,
         None => return reject_unit_mismatch(metrics, "missing_canonical", None),
     };
+    if !canonical_amount.is_finite() {
+        return reject_unit_mismatch(metrics, "non_finite_amount", None);
+    }
 
     // Derive or Validate contracts
     let derived_contracts = if let Some(multiplier) = contract_multiplier {
@@ -1,18 +1,18 @@
+//! Emergency close algorithm per CONTRACT.md §3.1
+//! - 3 IOC close attempts with doubling buffer (5→10→20 ticks)
+//! - Reduce-only delta hedge fallback if still exposed
+//! - Logs AtomicNakedEvent on naked exposure
+//! - TradingMode is ReduceOnly during exposure
+//!
+//! Uses dependency injection via OrderDispatcher trait for testability and
+//! production integration.
+
 use super::order_dispatcher::{
     CloseOrderRequest, HedgeOrderRequest, OrderDispatcher, OrderSide, OrderType, TestStubDispatcher,
 };
 use std::sync::Arc;
 use std::time::Instant;
 
-/// Emergency close algorithm per CONTRACT.md §3.1
-/// - 3 IOC close attempts with doubling buffer (5→10→20 ticks)
-/// - Reduce-only delta hedge fallback if still exposed
-/// - Logs AtomicNakedEvent on naked exposure
-/// - TradingMode is ReduceOnly during exposure
-///
-/// Uses dependency injection via OrderDispatcher trait for testability and
-/// production integration.
-
 const MAX_CLOSE_ATTEMPTS: u8 = 3;
 const INITIAL_BUFFER_TICKS: i32 = 5;
 
@@ -249,8 +249,8 @@ mod tests {
 
     #[test]
     fn test_emergency_close_buffer_doubling_sequence() {
-        let buffers = vec![
-            INITIAL_BUFFER_TICKS * 1, // 5
+        let buffers = [
+            INITIAL_BUFFER_TICKS,     // 5
             INITIAL_BUFFER_TICKS * 2, // 10
             INITIAL_BUFFER_TICKS * 4, // 20
         ];
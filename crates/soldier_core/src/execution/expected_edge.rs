@@ -0,0 +1,63 @@
+//! Expected net edge after slippage and fees, decomposed in bps.
+//!
+//! Reuses the LiquidityGate book walk for slippage and the fee model for fees so a strategy
+//! sees the same economics the gates enforce. Everything is relative to the touch:
+//! `net_bps = gross_bps - slippage_bps - fee_bps`.
+
+use crate::risk::FeeModelSnapshot;
+
+use super::gate::{compute_wap_and_slippage, validated_levels};
+use super::{L2BookSnapshot, QuantizedFields, Side};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpectedEdgeIntent {
+    pub side: Side,
+    pub order_qty: f64,
+    pub fair_price: f64,
+    pub is_maker: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpectedEdge {
+    /// Fair price vs the touch, positive when the touch is favourable.
+    pub gross_bps: f64,
+    /// WAP vs the touch for `order_qty` (LiquidityGate definition).
+    pub slippage_bps: f64,
+    pub fee_bps: f64,
+    pub net_bps: f64,
+    pub wap: f64,
+}
+
+/// `None` when the book cannot fill `order_qty` or any input is unusable (fail-closed).
+pub fn expected_net_edge(
+    intent: &ExpectedEdgeIntent,
+    book: &L2BookSnapshot,
+    fee_snapshot: &FeeModelSnapshot,
+) -> Option<ExpectedEdge> {
+    if !intent.fair_price.is_finite() || intent.fair_price <= 0.0 {
+        return None;
+    }
+    let levels = validated_levels(book, intent.side)?;
+    let touch = levels.first()?.price;
+    let stats = compute_wap_and_slippage(intent.order_qty, intent.side, &levels)?;
+
+    let gross_bps = match intent.side {
+        Side::Buy => (intent.fair_price - touch) / touch * 10_000.0,
+        Side::Sell => (touch - intent.fair_price) / touch * 10_000.0,
+    };
+    let fill = QuantizedFields {
+        qty_q: intent.order_qty,
+        limit_price_q: stats.wap,
+    };
+    let fee_bps = fee_snapshot
+        .order_cost(&fill, 1.0, intent.is_maker)?
+        .fee_bps;
+
+    Some(ExpectedEdge {
+        gross_bps,
+        slippage_bps: stats.slippage_bps,
+        fee_bps,
+        net_bps: gross_bps - stats.slippage_bps - fee_bps,
+        wap: stats.wap,
+    })
+}
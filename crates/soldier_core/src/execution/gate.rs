@@ -58,8 +58,15 @@ pub struct LiquidityGateIntent<'a> {
     pub now_ms: u64,
 }
 
+/// Inclusive upper bounds (bps) of the expected-slippage histogram buckets;
+/// values above the last bound land in a trailing `f64::INFINITY` bucket.
+pub const SLIPPAGE_BPS_BUCKETS: [f64; 8] = [0.0, 1.0, 2.5, 5.0, 10.0, 25.0, 50.0, 100.0];
+
+const SLIPPAGE_BPS_BUCKET_COUNT: usize = SLIPPAGE_BPS_BUCKETS.len() + 1;
+
 pub struct LiquidityGateMetrics {
     expected_slippage_samples: AtomicU64,
+    slippage_bps_buckets: [AtomicU64; SLIPPAGE_BPS_BUCKET_COUNT],
     reject_expected_slippage_total: AtomicU64,
     reject_no_l2_total: AtomicU64,
 }
@@ -74,6 +81,7 @@ impl LiquidityGateMetrics {
     pub const fn new() -> Self {
         Self {
             expected_slippage_samples: AtomicU64::new(0),
+            slippage_bps_buckets: [const { AtomicU64::new(0) }; SLIPPAGE_BPS_BUCKET_COUNT],
             reject_expected_slippage_total: AtomicU64::new(0),
             reject_no_l2_total: AtomicU64::new(0),
         }
@@ -106,9 +114,31 @@ impl LiquidityGateMetrics {
         }
     }
 
-    fn record_expected_slippage(&self) {
+    /// `(upper_bound_bps, count)` per bucket, in ascending bound order.
+    pub fn slippage_bps_histogram(&self) -> Vec<(f64, u64)> {
+        SLIPPAGE_BPS_BUCKETS
+            .iter()
+            .copied()
+            .chain(std::iter::once(f64::INFINITY))
+            .zip(self.slippage_bps_buckets.iter())
+            .map(|(bound, count)| (bound, count.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    pub fn reset_slippage_bps_histogram(&self) {
+        for bucket in &self.slippage_bps_buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub fn record_expected_slippage(&self, slippage_bps: f64) {
         self.expected_slippage_samples
             .fetch_add(1, Ordering::Relaxed);
+        let index = SLIPPAGE_BPS_BUCKETS
+            .iter()
+            .position(|bound| slippage_bps <= *bound)
+            .unwrap_or(SLIPPAGE_BPS_BUCKETS.len());
+        self.slippage_bps_buckets[index].fetch_add(1, Ordering::Relaxed);
     }
 }
 
@@ -122,9 +152,48 @@ pub fn expected_slippage_bps_samples() -> u64 {
     LIQUIDITY_GATE_METRICS.expected_slippage_samples()
 }
 
+pub fn slippage_bps_histogram() -> Vec<(f64, u64)> {
+    LIQUIDITY_GATE_METRICS.slippage_bps_histogram()
+}
+
+pub fn reset_slippage_bps_histogram() {
+    LIQUIDITY_GATE_METRICS.reset_slippage_bps_histogram();
+}
+
 pub fn evaluate_liquidity_gate(
     intent: &LiquidityGateIntent<'_>,
     config: LiquidityGateConfig,
+) -> Result<LiquidityGateOutcome, LiquidityGateReject> {
+    evaluate_liquidity_gate_with_metrics(intent, config, true)
+}
+
+/// [`evaluate_liquidity_gate`]; with `record_metrics` false neither the reject counters nor
+/// the expected-slippage histogram are touched (dry runs).
+pub(super) fn evaluate_liquidity_gate_with_metrics(
+    intent: &LiquidityGateIntent<'_>,
+    config: LiquidityGateConfig,
+    record_metrics: bool,
+) -> Result<LiquidityGateOutcome, LiquidityGateReject> {
+    let result = liquidity_gate(intent, config);
+    if record_metrics {
+        // A slippage estimate exists whenever the book was walked, pass or reject.
+        let slippage_bps = match &result {
+            Ok(outcome) => outcome.slippage_bps,
+            Err(reject) => reject.slippage_bps,
+        };
+        if let Some(slippage_bps) = slippage_bps {
+            record_expected_slippage(slippage_bps);
+        }
+        if let Err(reject) = &result {
+            record_reject(reject);
+        }
+    }
+    result
+}
+
+fn liquidity_gate(
+    intent: &LiquidityGateIntent<'_>,
+    config: LiquidityGateConfig,
 ) -> Result<LiquidityGateOutcome, LiquidityGateReject> {
     if intent.classification == IntentClassification::Cancel {
         return Ok(LiquidityGateOutcome {
@@ -163,8 +232,6 @@ pub fn evaluate_liquidity_gate(
         None => return Err(reject_no_l2(None, None)),
     };
 
-    record_expected_slippage(stats.slippage_bps);
-
     if stats.slippage_bps > config.max_slippage_bps {
         return Err(reject_slippage(stats));
     }
@@ -176,13 +243,13 @@ pub fn evaluate_liquidity_gate(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-struct LiquidityGateStats {
-    wap: f64,
-    slippage_bps: f64,
+pub(super) struct LiquidityGateStats {
+    pub(super) wap: f64,
+    pub(super) slippage_bps: f64,
 }
 
 fn reject_slippage(stats: LiquidityGateStats) -> LiquidityGateReject {
-    reject_with_metrics(
+    reject(
         LiquidityGateRejectReason::ExpectedSlippageTooHigh,
         Some(stats.wap),
         Some(stats.slippage_bps),
@@ -190,24 +257,18 @@ fn reject_slippage(stats: LiquidityGateStats) -> LiquidityGateReject {
 }
 
 fn reject_no_l2(wap: Option<f64>, slippage_bps: Option<f64>) -> LiquidityGateReject {
-    reject_with_metrics(
+    reject(
         LiquidityGateRejectReason::LiquidityGateNoL2,
         wap,
         slippage_bps,
     )
 }
 
-fn reject_with_metrics(
+fn reject(
     reason: LiquidityGateRejectReason,
     wap: Option<f64>,
     slippage_bps: Option<f64>,
 ) -> LiquidityGateReject {
-    LIQUIDITY_GATE_METRICS.bump_reject(reason);
-    eprintln!("liquidity_gate_reject_total reason={:?}", reason);
-    eprintln!(
-        "LiquidityGateReject reason={:?} wap={:?} slippage_bps={:?}",
-        reason, wap, slippage_bps
-    );
     LiquidityGateReject {
         reason,
         wap,
@@ -215,8 +276,17 @@ fn reject_with_metrics(
     }
 }
 
+fn record_reject(reject: &LiquidityGateReject) {
+    LIQUIDITY_GATE_METRICS.bump_reject(reject.reason);
+    eprintln!("liquidity_gate_reject_total reason={:?}", reject.reason);
+    eprintln!(
+        "LiquidityGateReject reason={:?} wap={:?} slippage_bps={:?}",
+        reject.reason, reject.wap, reject.slippage_bps
+    );
+}
+
 fn record_expected_slippage(slippage_bps: f64) {
-    LIQUIDITY_GATE_METRICS.record_expected_slippage();
+    LIQUIDITY_GATE_METRICS.record_expected_slippage(slippage_bps);
     eprintln!("expected_slippage_bps value={}", slippage_bps);
 }
 
@@ -227,7 +297,7 @@ fn is_fresh(now_ms: u64, ts_ms: u64, max_age_ms: u64) -> bool {
     now_ms - ts_ms <= max_age_ms
 }
 
-fn validated_levels(snapshot: &L2BookSnapshot, side: Side) -> Option<Vec<L2BookLevel>> {
+pub(super) fn validated_levels(snapshot: &L2BookSnapshot, side: Side) -> Option<Vec<L2BookLevel>> {
     let levels = match side {
         Side::Buy => &snapshot.asks,
         Side::Sell => &snapshot.bids,
@@ -264,7 +334,7 @@ fn validated_levels(snapshot: &L2BookSnapshot, side: Side) -> Option<Vec<L2BookL
     Some(cleaned)
 }
 
-fn compute_wap_and_slippage(
+pub(super) fn compute_wap_and_slippage(
     order_qty: f64,
     side: Side,
     levels: &[L2BookLevel],
@@ -12,11 +12,17 @@ pub enum NetEdgeRejectReason {
 pub struct NetEdgeReject {
     pub reason: NetEdgeRejectReason,
     pub net_edge_usd: Option<f64>,
+    /// `net_edge_usd / notional_usd` in bps; `None` without a positive notional.
+    pub net_edge_bps: Option<f64>,
+    /// Threshold `net_edge_usd` was compared against.
+    pub min_edge_usd: Option<f64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct NetEdgeGateOutcome {
     pub net_edge_usd: Option<f64>,
+    pub net_edge_bps: Option<f64>,
+    pub min_edge_usd: Option<f64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -26,6 +32,8 @@ pub struct NetEdgeGateIntent {
     pub fee_usd: Option<f64>,
     pub expected_slippage_usd: Option<f64>,
     pub min_edge_usd: Option<f64>,
+    /// Order notional used to express the net edge in bps.
+    pub notional_usd: Option<f64>,
 }
 
 pub struct NetEdgeGateMetrics {
@@ -78,8 +86,29 @@ pub fn net_edge_reject_total(reason: NetEdgeRejectReason) -> u64 {
 pub fn evaluate_net_edge_gate(
     intent: &NetEdgeGateIntent,
 ) -> Result<NetEdgeGateOutcome, NetEdgeReject> {
+    evaluate_net_edge_gate_with_metrics(intent, true)
+}
+
+/// [`evaluate_net_edge_gate`]; with `record_metrics` false a reject leaves the net-edge
+/// counters untouched (dry runs).
+pub(super) fn evaluate_net_edge_gate_with_metrics(
+    intent: &NetEdgeGateIntent,
+    record_metrics: bool,
+) -> Result<NetEdgeGateOutcome, NetEdgeReject> {
+    let result = net_edge_gate(intent);
+    if record_metrics && let Err(reject) = &result {
+        record_reject(reject);
+    }
+    result
+}
+
+fn net_edge_gate(intent: &NetEdgeGateIntent) -> Result<NetEdgeGateOutcome, NetEdgeReject> {
     if intent.classification != IntentClassification::Open {
-        return Ok(NetEdgeGateOutcome { net_edge_usd: None });
+        return Ok(NetEdgeGateOutcome {
+            net_edge_usd: None,
+            net_edge_bps: None,
+            min_edge_usd: None,
+        });
     }
 
     let gross = parse_input(intent.gross_edge_usd)?;
@@ -92,18 +121,28 @@ pub fn evaluate_net_edge_gate(
         return Err(reject_missing());
     }
 
+    let net_edge_bps = net_edge_bps(net_edge_usd, intent.notional_usd);
     if net_edge_usd < min_edge {
-        return Err(reject_with_metrics(
+        return Err(reject(
             NetEdgeRejectReason::NetEdgeTooLow,
             Some(net_edge_usd),
+            net_edge_bps,
+            Some(min_edge),
         ));
     }
 
     Ok(NetEdgeGateOutcome {
         net_edge_usd: Some(net_edge_usd),
+        net_edge_bps,
+        min_edge_usd: Some(min_edge),
     })
 }
 
+fn net_edge_bps(net_edge_usd: f64, notional_usd: Option<f64>) -> Option<f64> {
+    let notional_usd = notional_usd.filter(|value| value.is_finite() && *value > 0.0)?;
+    Some(net_edge_usd / notional_usd * 10_000.0)
+}
+
 fn parse_input(value: Option<f64>) -> Result<f64, NetEdgeReject> {
     match value {
         Some(value) if value.is_finite() => Ok(value),
@@ -112,18 +151,28 @@ fn parse_input(value: Option<f64>) -> Result<f64, NetEdgeReject> {
 }
 
 fn reject_missing() -> NetEdgeReject {
-    reject_with_metrics(NetEdgeRejectReason::NetEdgeInputMissing, None)
+    reject(NetEdgeRejectReason::NetEdgeInputMissing, None, None, None)
 }
 
-fn reject_with_metrics(reason: NetEdgeRejectReason, net_edge_usd: Option<f64>) -> NetEdgeReject {
-    NET_EDGE_GATE_METRICS.bump_reject(reason);
-    eprintln!("net_edge_reject_total reason={:?}", reason);
-    eprintln!(
-        "NetEdgeReject reason={:?} net_edge_usd={:?}",
-        reason, net_edge_usd
-    );
+fn reject(
+    reason: NetEdgeRejectReason,
+    net_edge_usd: Option<f64>,
+    net_edge_bps: Option<f64>,
+    min_edge_usd: Option<f64>,
+) -> NetEdgeReject {
     NetEdgeReject {
         reason,
         net_edge_usd,
+        net_edge_bps,
+        min_edge_usd,
     }
 }
+
+fn record_reject(reject: &NetEdgeReject) {
+    NET_EDGE_GATE_METRICS.bump_reject(reject.reason);
+    eprintln!("net_edge_reject_total reason={:?}", reject.reason);
+    eprintln!(
+        "NetEdgeReject reason={:?} net_edge_usd={:?} net_edge_bps={:?} min_edge_usd={:?}",
+        reject.reason, reject.net_edge_usd, reject.net_edge_bps, reject.min_edge_usd
+    );
+}
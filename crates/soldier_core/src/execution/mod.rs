@@ -2,19 +2,23 @@ use std::cell::RefCell;
 
 pub mod atomic_group_executor;
 mod build_order_intent;
+pub mod clamp;
 pub mod dispatch_map;
 pub mod emergency_close;
+pub mod expected_edge;
 pub mod gate;
 pub mod gates;
 pub mod group;
 pub mod label;
 pub mod order_dispatcher;
+pub mod order_intent_builder;
 pub mod order_size;
 pub mod order_type_guard;
 pub mod post_only_guard;
 mod preflight;
 pub mod pricer;
 pub mod quantize;
+pub mod reduce_only_guard;
 pub mod sequencer;
 pub mod state;
 pub mod tlsm;
@@ -22,20 +26,23 @@ pub mod tlsm;
 pub use build_order_intent::{
     BuildOrderIntentContext, BuildOrderIntentError, BuildOrderIntentObservers,
     BuildOrderIntentOutcome, BuildOrderIntentRejectReason, DispatchStep, GateSequenceResult,
-    GateStep, RecordIntentOutcome, build_order_intent, gate_sequence_total,
-    take_build_order_intent_outcome, take_dispatch_trace, take_gate_sequence_trace,
-    with_build_order_intent_context,
+    GateStep, RecordIntentOutcome, build_order_intent, build_order_intent_dry_run,
+    gate_sequence_total, render_dispatch_trace, render_gate_trace, take_build_order_intent_outcome,
+    take_dispatch_trace, take_gate_sequence_trace, with_build_order_intent_context,
 };
+pub use clamp::{ClampPolicy, ClampReject, ClampRejectReason, clamp_intent_size};
 pub use dispatch_map::{
     DeribitOrderAmount, DispatchMetrics, DispatchReject, DispatchRejectReason,
     IntentClassification, map_order_size_to_deribit_amount,
     map_order_size_to_deribit_amount_with_metrics, order_intent_reject_unit_mismatch_total,
-    reduce_only_from_intent_classification,
+    reduce_only_from_intent_and_position, reduce_only_from_intent_classification,
 };
+pub use expected_edge::{ExpectedEdge, ExpectedEdgeIntent, expected_net_edge};
 pub use gate::{
-    L2BookLevel, L2BookSnapshot, LiquidityGateConfig, LiquidityGateIntent, LiquidityGateOutcome,
-    LiquidityGateReject, LiquidityGateRejectReason, evaluate_liquidity_gate,
-    expected_slippage_bps_samples, liquidity_gate_reject_total,
+    L2BookLevel, L2BookSnapshot, LiquidityGateConfig, LiquidityGateIntent, LiquidityGateMetrics,
+    LiquidityGateOutcome, LiquidityGateReject, LiquidityGateRejectReason, SLIPPAGE_BPS_BUCKETS,
+    evaluate_liquidity_gate, expected_slippage_bps_samples, liquidity_gate_reject_total,
+    reset_slippage_bps_histogram, slippage_bps_histogram,
 };
 pub use gates::{
     NetEdgeGateIntent, NetEdgeGateOutcome, NetEdgeReject, NetEdgeRejectReason,
@@ -45,6 +52,7 @@ pub use label::{
     CompactLabelParts, LabelDecodeError, LabelEncodeReject, LabelRejectReason,
     decode_compact_label, encode_compact_label, encode_compact_label_with_hashes,
 };
+pub use order_intent_builder::{OrderIntentBuilder, Unset};
 pub use order_size::{
     CONTRACTS_AMOUNT_MATCH_EPSILON, CONTRACTS_AMOUNT_MATCH_TOLERANCE, OrderSize, OrderSizeError,
     contracts_amount_matches,
@@ -54,20 +62,25 @@ pub use order_type_guard::{
 };
 pub use post_only_guard::{
     PostOnlyIntent, PostOnlyReject, PostOnlyRejectReason, post_only_cross_reject_total,
-    preflight_post_only,
+    post_only_safe_price, preflight_post_only, reprice_post_only,
 };
 pub use preflight::{
-    OrderIntent, PreflightReject, TriggerType, preflight_intent, preflight_reject_total,
+    IntentNotional, LinkedOrderGroup, OpenBudgetReject, OrderIntent, PreflightReject,
+    PriceBandReject, TriggerReference, TriggerType, check_open_order_budget, preflight_intent,
+    preflight_linked_group, preflight_reject_total, price_band_check, select_order_type,
 };
-pub use pricer::{PricerIntent, PricerOutcome, PricerReject, price_ioc_limit};
+pub use pricer::{PricerIntent, PricerOutcome, PricerReject, TickRounding, price_ioc_limit};
 pub use quantize::{
     InstrumentQuantization, QuantizeReject, QuantizeRejectReason, QuantizedFields, QuantizedSteps,
-    Side, quantization_reject_too_small_total, quantize, quantize_from_metadata, quantize_steps,
+    Side, quantization_reject_too_small_total, quantize, quantize_from_metadata, quantize_group,
+    quantize_steps,
 };
+pub use reduce_only_guard::{ReduceOnlyReject, enforce_reduce_only};
 pub use sequencer::{ExecutionStep, IntentKind, RiskState, SequenceError, Sequencer};
 pub use state::{TlsmEvent, TlsmIntent, TlsmLedgerEntry, TlsmSide, TlsmState};
 pub use tlsm::{
-    Tlsm, TlsmError, TlsmLedger, TlsmLedgerError, TlsmTransition, tlsm_out_of_order_total,
+    Tlsm, TlsmError, TlsmLedger, TlsmLedgerError, TlsmTimeouts, TlsmTransition,
+    tlsm_out_of_order_total, tlsm_timeout_total,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,234 @@
+//! Typestate builder for [`OrderIntent`].
+//!
+//! Instrument kind, side, qty and price are tracked in the builder's type, so `build()` only
+//! exists once all four are set:
+//!
+//! ```
+//! use soldier_core::execution::{OrderIntentBuilder, OrderType, Side};
+//! use soldier_core::venue::InstrumentKind;
+//!
+//! let intent = OrderIntentBuilder::new()
+//!     .instrument_kind(InstrumentKind::Perpetual)
+//!     .side(Side::Buy)
+//!     .qty(2.0)
+//!     .price(100.0)
+//!     .order_type(OrderType::Limit)
+//!     .build()
+//!     .expect("complete limit intent");
+//! assert_eq!(intent.limit_price, Some(100.0));
+//! ```
+//!
+//! ```compile_fail
+//! use soldier_core::execution::{OrderIntentBuilder, Side};
+//! use soldier_core::venue::InstrumentKind;
+//!
+//! // No qty or price: `build` is not defined for this builder type.
+//! let _ = OrderIntentBuilder::new()
+//!     .instrument_kind(InstrumentKind::Perpetual)
+//!     .side(Side::Buy)
+//!     .build();
+//! ```
+
+use crate::venue::InstrumentKind;
+
+use super::{
+    BuildOrderIntentError, IntentNotional, LinkedOrderType, OrderIntent, OrderType, Side,
+    TriggerReference, TriggerType,
+};
+
+/// Marker for a required field that has not been set yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unset;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderIntentBuilder<K = Unset, S = Unset, Q = Unset, P = Unset> {
+    instrument_kind: K,
+    side: S,
+    qty: Q,
+    price: P,
+    optional: OptionalFields,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct OptionalFields {
+    order_type: OrderType,
+    contract_multiplier: f64,
+    trigger: Option<TriggerType>,
+    trigger_price: Option<f64>,
+    linked_order_type: Option<LinkedOrderType>,
+    reduce_only: Option<bool>,
+    instrument_tradable: Option<bool>,
+    reference_price: Option<f64>,
+    instrument_id: Option<String>,
+    open_order_count: Option<u32>,
+    trigger_reference: Option<TriggerReference>,
+}
+
+impl OrderIntentBuilder {
+    /// A `Limit` order with a contract multiplier of 1 and every optional field unset.
+    pub fn new() -> Self {
+        Self {
+            instrument_kind: Unset,
+            side: Unset,
+            qty: Unset,
+            price: Unset,
+            optional: OptionalFields {
+                order_type: OrderType::Limit,
+                contract_multiplier: 1.0,
+                trigger: None,
+                trigger_price: None,
+                linked_order_type: None,
+                reduce_only: None,
+                instrument_tradable: None,
+                reference_price: None,
+                instrument_id: None,
+                open_order_count: None,
+                trigger_reference: None,
+            },
+        }
+    }
+}
+
+impl Default for OrderIntentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, S, Q, P> OrderIntentBuilder<K, S, Q, P> {
+    pub fn instrument_kind(
+        self,
+        kind: InstrumentKind,
+    ) -> OrderIntentBuilder<InstrumentKind, S, Q, P> {
+        OrderIntentBuilder {
+            instrument_kind: kind,
+            side: self.side,
+            qty: self.qty,
+            price: self.price,
+            optional: self.optional,
+        }
+    }
+
+    pub fn side(self, side: Side) -> OrderIntentBuilder<K, Side, Q, P> {
+        OrderIntentBuilder {
+            instrument_kind: self.instrument_kind,
+            side,
+            qty: self.qty,
+            price: self.price,
+            optional: self.optional,
+        }
+    }
+
+    pub fn qty(self, qty: f64) -> OrderIntentBuilder<K, S, f64, P> {
+        OrderIntentBuilder {
+            instrument_kind: self.instrument_kind,
+            side: self.side,
+            qty,
+            price: self.price,
+            optional: self.optional,
+        }
+    }
+
+    /// Notional price; also the limit price for `Limit` and `StopLimit` orders.
+    pub fn price(self, price: f64) -> OrderIntentBuilder<K, S, Q, f64> {
+        OrderIntentBuilder {
+            instrument_kind: self.instrument_kind,
+            side: self.side,
+            qty: self.qty,
+            price,
+            optional: self.optional,
+        }
+    }
+
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.optional.order_type = order_type;
+        self
+    }
+
+    pub fn contract_multiplier(mut self, contract_multiplier: f64) -> Self {
+        self.optional.contract_multiplier = contract_multiplier;
+        self
+    }
+
+    pub fn trigger(mut self, trigger: TriggerType, trigger_price: f64) -> Self {
+        self.optional.trigger = Some(trigger);
+        self.optional.trigger_price = Some(trigger_price);
+        self
+    }
+
+    pub fn linked_order_type(mut self, linked_order_type: LinkedOrderType) -> Self {
+        self.optional.linked_order_type = Some(linked_order_type);
+        self
+    }
+
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.optional.reduce_only = Some(reduce_only);
+        self
+    }
+
+    pub fn instrument_tradable(mut self, tradable: bool) -> Self {
+        self.optional.instrument_tradable = Some(tradable);
+        self
+    }
+
+    pub fn reference_price(mut self, reference_price: f64) -> Self {
+        self.optional.reference_price = Some(reference_price);
+        self
+    }
+
+    pub fn instrument_id(mut self, instrument_id: impl Into<String>) -> Self {
+        self.optional.instrument_id = Some(instrument_id.into());
+        self
+    }
+
+    pub fn open_order_count(mut self, open_order_count: u32) -> Self {
+        self.optional.open_order_count = Some(open_order_count);
+        self
+    }
+
+    pub fn trigger_reference(mut self, trigger_reference: TriggerReference) -> Self {
+        self.optional.trigger_reference = Some(trigger_reference);
+        self
+    }
+}
+
+impl OrderIntentBuilder<InstrumentKind, Side, f64, f64> {
+    /// Fails with `MissingField` only for conditionally required fields: stop orders need a
+    /// trigger price (set with its type via `trigger`). Venue and policy checks stay in `preflight_intent`.
+    pub fn build(self) -> Result<OrderIntent, BuildOrderIntentError> {
+        let optional = self.optional;
+        let is_stop = matches!(
+            optional.order_type,
+            OrderType::StopMarket | OrderType::StopLimit
+        );
+        if is_stop && optional.trigger_price.is_none() {
+            return Err(BuildOrderIntentError::MissingField {
+                field: "trigger_price",
+            });
+        }
+        let limit_price = match optional.order_type {
+            OrderType::Limit | OrderType::StopLimit => Some(self.price),
+            OrderType::Market | OrderType::StopMarket => None,
+        };
+        Ok(OrderIntent {
+            instrument_kind: self.instrument_kind,
+            order_type: optional.order_type,
+            trigger: optional.trigger,
+            trigger_price: optional.trigger_price,
+            linked_order_type: optional.linked_order_type,
+            notional: Some(IntentNotional {
+                qty: self.qty,
+                price: self.price,
+                contract_multiplier: optional.contract_multiplier,
+            }),
+            side: Some(self.side),
+            limit_price,
+            reduce_only: optional.reduce_only,
+            instrument_tradable: optional.instrument_tradable,
+            reference_price: optional.reference_price,
+            instrument_id: optional.instrument_id,
+            open_order_count: optional.open_order_count,
+            trigger_reference: optional.trigger_reference,
+        })
+    }
+}
@@ -16,6 +16,7 @@ pub enum OrderSizeError {
     BothCanonical,
     MissingCanonical,
     InvalidIndexPrice,
+    NonFinite,
 }
 
 impl OrderSize {
@@ -40,14 +41,26 @@ impl OrderSize {
         if qty_coin.is_some() && qty_usd.is_some() {
             return Err(OrderSizeError::BothCanonical);
         }
+        if qty_coin.is_some_and(|qty| !qty.is_finite())
+            || qty_usd.is_some_and(|qty| !qty.is_finite())
+        {
+            return Err(OrderSizeError::NonFinite);
+        }
 
         let (qty_coin, qty_usd, notional_usd) = match instrument_kind {
             InstrumentKind::Option | InstrumentKind::LinearFuture => {
                 let qty_coin = qty_coin.ok_or(OrderSizeError::MissingCanonical)?;
+                if !index_price.is_finite() {
+                    return Err(OrderSizeError::NonFinite);
+                }
                 if index_price <= 0.0 {
                     return Err(OrderSizeError::InvalidIndexPrice);
                 }
                 let notional_usd = qty_coin * index_price;
+                // Finite inputs can still overflow (e.g. f64::MAX-scale qty)
+                if !notional_usd.is_finite() {
+                    return Err(OrderSizeError::NonFinite);
+                }
                 (Some(qty_coin), None, notional_usd)
             }
             InstrumentKind::Perpetual | InstrumentKind::InverseFuture => {
@@ -80,5 +93,7 @@ pub fn contracts_amount_matches(amount: f64, contracts: i64, contract_multiplier
     }
     let expected = contracts as f64 * contract_multiplier;
     let denom = amount.abs().max(CONTRACTS_AMOUNT_MATCH_EPSILON);
-    ((amount - expected).abs() / denom) <= CONTRACTS_AMOUNT_MATCH_TOLERANCE
+    let relative_error = (amount - expected).abs() / denom;
+    // Overflowed (inf/NaN) error is an explicit mismatch, not a comparison accident
+    relative_error.is_finite() && relative_error <= CONTRACTS_AMOUNT_MATCH_TOLERANCE
 }
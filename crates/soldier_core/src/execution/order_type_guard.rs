@@ -18,18 +18,74 @@ pub enum OrderTypeRejectReason {
     OrderTypeMarketForbidden,
     OrderTypeStopForbidden,
     LinkedOrderTypeForbidden,
+    BelowMinNotional,
+    AboveMaxNotional,
+    NotionalMissing,
+    LinkedOrderTriggerInconsistent,
+    InstrumentNotTradable,
+    /// Stop trigger on the wrong side of its reference price, or off the tick grid.
+    InvalidTriggerPrice,
+    /// Limit price outside `reference ± max_price_deviation_pct`.
+    PriceOutOfBand,
+    /// A price band is configured but the intent has no usable reference price to check.
+    PriceReferenceMissing,
+    /// A price band is configured but the intent has no limit price to check.
+    LimitPriceMissing,
+    /// The venue does not support the requested order type (and no fallback applied).
+    OrderTypeUnsupported,
+    /// An open would exceed `max_open_orders` resting on the instrument, or the count is unknown.
+    OpenOrderBudgetExceeded,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct OrderTypeGuardConfig {
     pub linked_orders_supported: bool,
     pub enable_linked_orders_for_bot: bool,
+    /// Per-instrument notional floor; `None` disables the check.
+    pub min_notional_usd: Option<f64>,
+    /// Per-instrument notional ceiling; `None` disables the check.
+    pub max_notional_usd: Option<f64>,
+    /// Max limit-price deviation from the intent's reference price, in percent; `None`
+    /// disables the band.
+    pub max_price_deviation_pct: Option<f64>,
+    /// Max resting orders per instrument before new opens are refused; `None` disables the cap.
+    pub max_open_orders: Option<u32>,
 }
 
 impl OrderTypeGuardConfig {
     fn linked_orders_allowed(self) -> bool {
         self.linked_orders_supported && self.enable_linked_orders_for_bot
     }
+
+    fn notional_bounds_configured(self) -> bool {
+        self.min_notional_usd.is_some() || self.max_notional_usd.is_some()
+    }
+}
+
+/// Checks `notional_usd` against the configured bounds. Unknown or non-finite notional fails
+/// closed whenever any bound is configured.
+pub fn validate_notional_bounds(
+    notional_usd: Option<f64>,
+    config: OrderTypeGuardConfig,
+) -> Result<(), OrderTypeRejectReason> {
+    if !config.notional_bounds_configured() {
+        return Ok(());
+    }
+    let notional_usd = match notional_usd {
+        Some(value) if value.is_finite() => value,
+        _ => return Err(OrderTypeRejectReason::NotionalMissing),
+    };
+    if let Some(min) = config.min_notional_usd
+        && notional_usd < min
+    {
+        return Err(OrderTypeRejectReason::BelowMinNotional);
+    }
+    if let Some(max) = config.max_notional_usd
+        && notional_usd > max
+    {
+        return Err(OrderTypeRejectReason::AboveMaxNotional);
+    }
+    Ok(())
 }
 
 pub fn validate_order_type(
@@ -68,6 +68,64 @@ pub fn preflight_post_only(intent: &PostOnlyIntent) -> Result<(), PostOnlyReject
     Ok(())
 }
 
+/// Moves a crossing post-only limit one tick inside the touch (below the ask for buys, above
+/// the bid for sells); non-crossing prices are returned unchanged.
+pub fn post_only_safe_price(
+    side: Side,
+    limit_price: f64,
+    best_bid: Option<f64>,
+    best_ask: Option<f64>,
+    tick_size: f64,
+) -> f64 {
+    if !would_cross(side, limit_price, best_bid, best_ask) {
+        return limit_price;
+    }
+    match (side, best_bid, best_ask) {
+        (Side::Buy, _, Some(ask)) => ask - tick_size,
+        (Side::Sell, Some(bid), _) => bid + tick_size,
+        _ => limit_price,
+    }
+}
+
+/// Reprices a post-only intent inward against the touch, re-reading `book_top` (bid, ask) on
+/// each of up to `max_attempts` tries. An attempt fails if the book is crossed or the safe
+/// price is non-positive; exhausting attempts rejects with `PostOnlyWouldCross`.
+pub fn reprice_post_only(
+    intent: &PostOnlyIntent,
+    mut book_top: impl FnMut() -> (Option<f64>, Option<f64>),
+    tick_size: f64,
+    max_attempts: u32,
+) -> Result<PostOnlyIntent, PostOnlyReject> {
+    if !intent.post_only {
+        return Ok(*intent);
+    }
+    if !tick_size.is_finite() || tick_size <= 0.0 {
+        return Err(reject_with_metrics());
+    }
+    let mut candidate = *intent;
+    for _ in 0..max_attempts {
+        let (best_bid, best_ask) = book_top();
+        candidate.best_bid = best_bid;
+        candidate.best_ask = best_ask;
+        if matches!((best_bid, best_ask), (Some(bid), Some(ask)) if bid >= ask) {
+            continue;
+        }
+        candidate.limit_price = post_only_safe_price(
+            candidate.side,
+            candidate.limit_price,
+            best_bid,
+            best_ask,
+            tick_size,
+        );
+        if candidate.limit_price > 0.0
+            && !would_cross(candidate.side, candidate.limit_price, best_bid, best_ask)
+        {
+            return Ok(candidate);
+        }
+    }
+    Err(reject_with_metrics())
+}
+
 fn would_cross(side: Side, limit_price: f64, best_bid: Option<f64>, best_ask: Option<f64>) -> bool {
     if !limit_price.is_finite() {
         return false;
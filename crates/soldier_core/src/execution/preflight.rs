@@ -1,10 +1,13 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use crate::venue::InstrumentKind;
+use crate::venue::{FeatureFlags, InstrumentKind, VenueCapabilities};
 
+use super::Side;
 use super::order_type_guard::{
-    LinkedOrderType, OrderType, OrderTypeGuardConfig, OrderTypeRejectReason, validate_order_type,
+    LinkedOrderType, OrderType, OrderTypeGuardConfig, OrderTypeRejectReason,
+    validate_notional_bounds, validate_order_type,
 };
+use super::quantize::near_integer;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TriggerType {
@@ -13,13 +16,61 @@ pub enum TriggerType {
     LastPrice,
 }
 
+/// Current price of the trigger's reference (`trigger`) and the instrument tick, used to check
+/// that a stop sits on the correct side of the market and on the tick grid.
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriggerReference {
+    pub trigger: TriggerType,
+    pub reference_price: f64,
+    pub tick_size: f64,
+}
+
+/// Sizing inputs for the notional bounds check: `notional_usd = qty * price * contract_multiplier`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntentNotional {
+    pub qty: f64,
+    pub price: f64,
+    pub contract_multiplier: f64,
+}
+
+impl IntentNotional {
+    pub fn notional_usd(&self) -> f64 {
+        (self.qty * self.price * self.contract_multiplier).abs()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct OrderIntent {
     pub instrument_kind: InstrumentKind,
     pub order_type: OrderType,
     pub trigger: Option<TriggerType>,
     pub trigger_price: Option<f64>,
     pub linked_order_type: Option<LinkedOrderType>,
+    pub notional: Option<IntentNotional>,
+    pub side: Option<Side>,
+    pub limit_price: Option<f64>,
+    pub reduce_only: Option<bool>,
+    /// `InstrumentMetadata::is_tradable_at` for the target instrument; `Some(false)` blocks
+    /// opens (reduce-only closes still pass).
+    pub instrument_tradable: Option<bool>,
+    /// Mark or mid price for the price-band check; required once a band is configured.
+    pub reference_price: Option<f64>,
+    /// Venue instrument name (e.g. `BTC-PERPETUAL`) keying the open-order budget; required for
+    /// opens once `max_open_orders` is configured.
+    pub instrument_id: Option<String>,
+    /// Orders currently resting on `instrument_id`; required for opens once `max_open_orders`
+    /// is configured.
+    pub open_order_count: Option<u32>,
+    /// Required whenever `trigger_price` is set; the trigger is validated against it.
+    pub trigger_reference: Option<TriggerReference>,
+}
+
+/// OCO / stop-with-linked-limit group: `primary` is the resting limit leg, `linked` legs carry
+/// the stop (`trigger_price`) that cancels it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkedOrderGroup {
+    pub primary: OrderIntent,
+    pub linked: Vec<(LinkedOrderType, OrderIntent)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -31,6 +82,17 @@ pub struct PreflightMetrics {
     market_forbidden_total: AtomicU64,
     stop_forbidden_total: AtomicU64,
     linked_order_forbidden_total: AtomicU64,
+    below_min_notional_total: AtomicU64,
+    above_max_notional_total: AtomicU64,
+    notional_missing_total: AtomicU64,
+    linked_trigger_inconsistent_total: AtomicU64,
+    instrument_not_tradable_total: AtomicU64,
+    invalid_trigger_price_total: AtomicU64,
+    price_out_of_band_total: AtomicU64,
+    price_reference_missing_total: AtomicU64,
+    limit_price_missing_total: AtomicU64,
+    order_type_unsupported_total: AtomicU64,
+    open_order_budget_exceeded_total: AtomicU64,
 }
 
 impl PreflightMetrics {
@@ -39,6 +101,17 @@ impl PreflightMetrics {
             market_forbidden_total: AtomicU64::new(0),
             stop_forbidden_total: AtomicU64::new(0),
             linked_order_forbidden_total: AtomicU64::new(0),
+            below_min_notional_total: AtomicU64::new(0),
+            above_max_notional_total: AtomicU64::new(0),
+            notional_missing_total: AtomicU64::new(0),
+            linked_trigger_inconsistent_total: AtomicU64::new(0),
+            instrument_not_tradable_total: AtomicU64::new(0),
+            invalid_trigger_price_total: AtomicU64::new(0),
+            price_out_of_band_total: AtomicU64::new(0),
+            price_reference_missing_total: AtomicU64::new(0),
+            limit_price_missing_total: AtomicU64::new(0),
+            order_type_unsupported_total: AtomicU64::new(0),
+            open_order_budget_exceeded_total: AtomicU64::new(0),
         }
     }
 
@@ -53,6 +126,39 @@ impl PreflightMetrics {
             OrderTypeRejectReason::LinkedOrderTypeForbidden => {
                 self.linked_order_forbidden_total.load(Ordering::Relaxed)
             }
+            OrderTypeRejectReason::BelowMinNotional => {
+                self.below_min_notional_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::AboveMaxNotional => {
+                self.above_max_notional_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::NotionalMissing => {
+                self.notional_missing_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::LinkedOrderTriggerInconsistent => self
+                .linked_trigger_inconsistent_total
+                .load(Ordering::Relaxed),
+            OrderTypeRejectReason::InstrumentNotTradable => {
+                self.instrument_not_tradable_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::InvalidTriggerPrice => {
+                self.invalid_trigger_price_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::PriceOutOfBand => {
+                self.price_out_of_band_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::PriceReferenceMissing => {
+                self.price_reference_missing_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::LimitPriceMissing => {
+                self.limit_price_missing_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::OrderTypeUnsupported => {
+                self.order_type_unsupported_total.load(Ordering::Relaxed)
+            }
+            OrderTypeRejectReason::OpenOrderBudgetExceeded => self
+                .open_order_budget_exceeded_total
+                .load(Ordering::Relaxed),
         }
     }
 
@@ -68,6 +174,48 @@ impl PreflightMetrics {
                 self.linked_order_forbidden_total
                     .fetch_add(1, Ordering::Relaxed);
             }
+            OrderTypeRejectReason::BelowMinNotional => {
+                self.below_min_notional_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::AboveMaxNotional => {
+                self.above_max_notional_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::NotionalMissing => {
+                self.notional_missing_total.fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::LinkedOrderTriggerInconsistent => {
+                self.linked_trigger_inconsistent_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::InstrumentNotTradable => {
+                self.instrument_not_tradable_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::InvalidTriggerPrice => {
+                self.invalid_trigger_price_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::PriceOutOfBand => {
+                self.price_out_of_band_total.fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::PriceReferenceMissing => {
+                self.price_reference_missing_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::LimitPriceMissing => {
+                self.limit_price_missing_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::OrderTypeUnsupported => {
+                self.order_type_unsupported_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            OrderTypeRejectReason::OpenOrderBudgetExceeded => {
+                self.open_order_budget_exceeded_total
+                    .fetch_add(1, Ordering::Relaxed);
+            }
         }
     }
 }
@@ -82,17 +230,254 @@ pub fn preflight_intent(
     intent: &OrderIntent,
     config: OrderTypeGuardConfig,
 ) -> Result<(), PreflightReject> {
+    preflight_intent_with_metrics(intent, config, true)
+}
+
+/// [`preflight_intent`]; with `record_metrics` false a reject leaves the preflight counters
+/// untouched (dry runs).
+pub(super) fn preflight_intent_with_metrics(
+    intent: &OrderIntent,
+    config: OrderTypeGuardConfig,
+    record_metrics: bool,
+) -> Result<(), PreflightReject> {
+    check_intent(intent, config).map_err(|reason| {
+        if record_metrics {
+            reject_with_metrics(reason)
+        } else {
+            PreflightReject { reason }
+        }
+    })
+}
+
+fn check_intent(
+    intent: &OrderIntent,
+    config: OrderTypeGuardConfig,
+) -> Result<(), OrderTypeRejectReason> {
     let has_trigger_fields = intent.trigger.is_some() || intent.trigger_price.is_some();
-    match validate_order_type(
+    validate_order_type(
         intent.instrument_kind,
         intent.order_type,
         has_trigger_fields,
         intent.linked_order_type,
         config,
-    ) {
-        Ok(()) => Ok(()),
-        Err(reason) => Err(reject_with_metrics(reason)),
+    )?;
+    if intent.instrument_tradable == Some(false) && intent.reduce_only != Some(true) {
+        return Err(OrderTypeRejectReason::InstrumentNotTradable);
+    }
+    validate_trigger_price(intent)?;
+    if let Some(max_deviation_pct) = config.max_price_deviation_pct {
+        // `validate_order_type` already refused market and stop types, so every intent here
+        // should be priced; one without a limit price cannot be banded and fails closed.
+        let limit_price = intent
+            .limit_price
+            .ok_or(OrderTypeRejectReason::LimitPriceMissing)?;
+        price_band_check(limit_price, intent.reference_price, max_deviation_pct)
+            .map_err(|reject| reject.reject_reason())?;
+    }
+    if let Some(max_open_orders) = config.max_open_orders
+        && intent.reduce_only != Some(true)
+    {
+        let budget_reject = OrderTypeRejectReason::OpenOrderBudgetExceeded;
+        // An unknown instrument or count cannot prove headroom.
+        let instrument_id = intent.instrument_id.as_deref().ok_or(budget_reject)?;
+        let open_count = intent.open_order_count.ok_or(budget_reject)?;
+        check_open_order_budget(instrument_id, open_count, max_open_orders)
+            .map_err(|_| budget_reject)?;
+    }
+    let notional_usd = intent.notional.map(|notional| notional.notional_usd());
+    validate_notional_bounds(notional_usd, config)
+}
+
+/// Picks the order type to send, rejecting one the venue lacks before it reaches the exchange.
+/// Limit orders are always supported; other types follow the venue flags. There is no
+/// fallback to a supported type: `preflight_intent` already refuses market and stop orders.
+pub fn select_order_type(
+    requested: OrderType,
+    capabilities: &VenueCapabilities,
+) -> Result<OrderType, PreflightReject> {
+    let supported = match requested {
+        OrderType::Limit => true,
+        OrderType::Market => capabilities.market_orders_supported,
+        OrderType::StopMarket => capabilities.stop_market_orders_supported,
+        OrderType::StopLimit => capabilities.stop_limit_orders_supported,
+    };
+    if !supported {
+        return Err(reject_with_metrics(
+            OrderTypeRejectReason::OrderTypeUnsupported,
+        ));
+    }
+    Ok(requested)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PriceBandReject {
+    /// No usable (finite, positive) reference price; the band fails closed.
+    MissingReference,
+    OutOfBand {
+        price: f64,
+        reference_price: f64,
+        deviation_pct: f64,
+    },
+}
+
+impl PriceBandReject {
+    /// Preflight reason, keeping a missing reference distinct from a genuine band breach.
+    pub fn reject_reason(&self) -> OrderTypeRejectReason {
+        match self {
+            PriceBandReject::MissingReference => OrderTypeRejectReason::PriceReferenceMissing,
+            PriceBandReject::OutOfBand { .. } => OrderTypeRejectReason::PriceOutOfBand,
+        }
+    }
+}
+
+/// Rejects a `price` further than `max_deviation_pct` percent (e.g. `10.0`) from
+/// `reference_price` (mark or mid), catching pricer bugs before dispatch.
+pub fn price_band_check(
+    price: f64,
+    reference_price: Option<f64>,
+    max_deviation_pct: f64,
+) -> Result<(), PriceBandReject> {
+    let reference_price = match reference_price {
+        Some(value) if value.is_finite() && value > 0.0 => value,
+        _ => return Err(PriceBandReject::MissingReference),
+    };
+    let deviation_pct = (price - reference_price).abs() / reference_price * 100.0;
+    if !deviation_pct.is_finite() || deviation_pct > max_deviation_pct {
+        return Err(PriceBandReject::OutOfBand {
+            price,
+            reference_price,
+            deviation_pct,
+        });
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenBudgetReject {
+    pub instrument: String,
+    pub current_open_count: u32,
+    pub max_open_orders: u32,
+}
+
+/// Refuses a new open once `current_open_count` orders already rest on `instrument`, keeping
+/// it under venue limits and bounding cancel-all. Reduce-only and cancel intents skip this.
+pub fn check_open_order_budget(
+    instrument: &str,
+    current_open_count: u32,
+    max_open_orders: u32,
+) -> Result<(), OpenBudgetReject> {
+    if current_open_count < max_open_orders {
+        return Ok(());
+    }
+    eprintln!(
+        "open_order_budget_reject instrument={instrument} open_count={current_open_count} max_open_orders={max_open_orders}"
+    );
+    Err(OpenBudgetReject {
+        instrument: instrument.to_string(),
+        current_open_count,
+        max_open_orders,
+    })
+}
+
+/// A stop-buy must trigger above its reference and a stop-sell below it, on the tick grid.
+/// Skipped without a `trigger_price`; a trigger with no `trigger_reference` cannot be checked
+/// and fails closed.
+fn validate_trigger_price(intent: &OrderIntent) -> Result<(), OrderTypeRejectReason> {
+    let Some(trigger_price) = intent.trigger_price else {
+        return Ok(());
+    };
+    let Some(reference) = intent.trigger_reference else {
+        return Err(OrderTypeRejectReason::InvalidTriggerPrice);
+    };
+    let valid_inputs = trigger_price.is_finite()
+        && reference.reference_price.is_finite()
+        && reference.tick_size.is_finite()
+        && reference.tick_size > 0.0
+        && intent.trigger == Some(reference.trigger);
+    let on_side = match intent.side {
+        Some(Side::Buy) => trigger_price > reference.reference_price,
+        Some(Side::Sell) => trigger_price < reference.reference_price,
+        None => false,
+    };
+    let on_grid = near_integer(trigger_price / reference.tick_size).is_some();
+    if !valid_inputs || !on_side || !on_grid {
+        return Err(OrderTypeRejectReason::InvalidTriggerPrice);
+    }
+    Ok(())
+}
+
+/// Preflights a linked group: the venue/bot must permit linked orders for the instrument, every
+/// leg must pass `preflight_intent`, and each stop leg must trigger on the protective side of
+/// the primary limit (below it for sells, above it for buys). Missing side/price fails closed.
+pub fn preflight_linked_group(
+    group: &LinkedOrderGroup,
+    capabilities: VenueCapabilities,
+    feature_flags: FeatureFlags,
+    config: OrderTypeGuardConfig,
+) -> Result<(), PreflightReject> {
+    let permits_linked = |leg: &OrderIntent| {
+        capabilities.linked_orders_supported_for(leg.instrument_kind, feature_flags)
+    };
+    if group.linked.is_empty()
+        || !permits_linked(&group.primary)
+        || !group.linked.iter().all(|(_, leg)| permits_linked(leg))
+    {
+        return Err(reject_with_metrics(
+            OrderTypeRejectReason::LinkedOrderTypeForbidden,
+        ));
+    }
+
+    let leg_config = OrderTypeGuardConfig {
+        linked_orders_supported: capabilities.linked_orders_supported,
+        enable_linked_orders_for_bot: feature_flags.enable_linked_orders_for_bot,
+        ..config
+    };
+    let linked_type = group.linked[0].0;
+    preflight_intent(
+        &OrderIntent {
+            linked_order_type: Some(linked_type),
+            ..group.primary.clone()
+        },
+        leg_config,
+    )?;
+    for (linked_order_type, leg) in &group.linked {
+        preflight_intent(
+            &OrderIntent {
+                linked_order_type: Some(*linked_order_type),
+                ..leg.clone()
+            },
+            leg_config,
+        )?;
+    }
+
+    validate_linked_triggers(group).map_err(reject_with_metrics)
+}
+
+fn validate_linked_triggers(group: &LinkedOrderGroup) -> Result<(), OrderTypeRejectReason> {
+    let inconsistent = OrderTypeRejectReason::LinkedOrderTriggerInconsistent;
+    let primary = &group.primary;
+    let (side, limit_price) = match (primary.side, primary.limit_price) {
+        (Some(side), Some(price)) if price.is_finite() && primary.trigger_price.is_none() => {
+            (side, price)
+        }
+        _ => return Err(inconsistent),
+    };
+    for (_, leg) in &group.linked {
+        if leg.side != Some(side) {
+            return Err(inconsistent);
+        }
+        let Some(trigger_price) = leg.trigger_price else {
+            continue;
+        };
+        let protective = match side {
+            Side::Sell => trigger_price < limit_price,
+            Side::Buy => trigger_price > limit_price,
+        };
+        if !trigger_price.is_finite() || !protective {
+            return Err(inconsistent);
+        }
     }
+    Ok(())
 }
 
 pub fn build_order_intent(
@@ -1,5 +1,16 @@
+use super::quantize::{steps_ceil, steps_floor};
 use super::{RejectReason, Side};
 
+/// Direction for snapping the computed limit to the tick grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TickRounding {
+    /// Buy rounds down, sell rounds up: never more aggressive than computed.
+    #[default]
+    Passive,
+    /// Buy rounds up, sell rounds down, but never past `max_price_for_min_edge`.
+    Aggressive,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PricerIntent {
     pub side: Side,
@@ -8,6 +19,9 @@ pub struct PricerIntent {
     pub fee_estimate_usd: f64,
     pub min_edge_usd: f64,
     pub qty: f64,
+    /// Tick grid for the limit price; `None` leaves the price un-rounded.
+    pub tick_size: Option<f64>,
+    pub tick_rounding: TickRounding,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -24,6 +38,26 @@ pub struct PricerReject {
 }
 
 pub fn price_ioc_limit(intent: &PricerIntent) -> Result<PricerOutcome, PricerReject> {
+    price_ioc_limit_with_metrics(intent, true)
+}
+
+/// [`price_ioc_limit`]; with `record_metrics` false no reject or limit-vs-fair metric lines
+/// are emitted (dry runs).
+pub(super) fn price_ioc_limit_with_metrics(
+    intent: &PricerIntent,
+    record_metrics: bool,
+) -> Result<PricerOutcome, PricerReject> {
+    let result = price(intent);
+    if record_metrics {
+        match &result {
+            Ok(outcome) => record_limit_vs_fair_bps(intent.fair_price, outcome.limit_price),
+            Err(reject) => record_reject(reject),
+        }
+    }
+    result
+}
+
+fn price(intent: &PricerIntent) -> Result<PricerOutcome, PricerReject> {
     let fair_price = parse_positive(intent.fair_price)?;
     let gross_edge = parse_finite(intent.gross_edge_usd)?;
     let fee_estimate = parse_finite(intent.fee_estimate_usd)?;
@@ -57,8 +91,16 @@ pub fn price_ioc_limit(intent: &PricerIntent) -> Result<PricerOutcome, PricerRej
         Side::Buy => proposed_limit.min(max_price_for_min_edge),
         Side::Sell => proposed_limit.max(max_price_for_min_edge),
     };
-
-    record_limit_vs_fair_bps(fair_price, limit_price);
+    let limit_price = match intent.tick_size {
+        Some(tick_size) => round_to_tick(
+            intent.side,
+            limit_price,
+            max_price_for_min_edge,
+            parse_positive(tick_size)?,
+            intent.tick_rounding,
+        )?,
+        None => limit_price,
+    };
 
     Ok(PricerOutcome {
         limit_price,
@@ -67,6 +109,39 @@ pub fn price_ioc_limit(intent: &PricerIntent) -> Result<PricerOutcome, PricerRej
     })
 }
 
+fn round_to_tick(
+    side: Side,
+    limit_price: f64,
+    max_price_for_min_edge: f64,
+    tick_size: f64,
+    rounding: TickRounding,
+) -> Result<f64, PricerReject> {
+    let passive_ticks = match side {
+        Side::Buy => steps_floor(limit_price, tick_size),
+        Side::Sell => steps_ceil(limit_price, tick_size),
+    };
+    let passive = passive_ticks as f64 * tick_size;
+    let rounded = match rounding {
+        TickRounding::Passive => passive,
+        TickRounding::Aggressive => {
+            let aggressive_ticks = match side {
+                Side::Buy => steps_ceil(limit_price, tick_size),
+                Side::Sell => steps_floor(limit_price, tick_size),
+            };
+            let aggressive = aggressive_ticks as f64 * tick_size;
+            let within_min_edge = match side {
+                Side::Buy => aggressive <= max_price_for_min_edge,
+                Side::Sell => aggressive >= max_price_for_min_edge,
+            };
+            if within_min_edge { aggressive } else { passive }
+        }
+    };
+    if !rounded.is_finite() || rounded <= 0.0 {
+        return Err(reject(None));
+    }
+    Ok(rounded)
+}
+
 fn parse_finite(value: f64) -> Result<f64, PricerReject> {
     if value.is_finite() {
         Ok(value)
@@ -84,19 +159,18 @@ fn parse_positive(value: f64) -> Result<f64, PricerReject> {
 }
 
 fn reject(net_edge_usd: Option<f64>) -> PricerReject {
-    reject_with_metrics(RejectReason::NetEdgeTooLow, net_edge_usd)
+    PricerReject {
+        reason: RejectReason::NetEdgeTooLow,
+        net_edge_usd,
+    }
 }
 
-fn reject_with_metrics(reason: RejectReason, net_edge_usd: Option<f64>) -> PricerReject {
-    eprintln!("pricer_reject_total reason={:?}", reason);
+fn record_reject(reject: &PricerReject) {
+    eprintln!("pricer_reject_total reason={:?}", reject.reason);
     eprintln!(
         "PricerReject reason={:?} net_edge_usd={:?}",
-        reason, net_edge_usd
+        reject.reason, reject.net_edge_usd
     );
-    PricerReject {
-        reason,
-        net_edge_usd,
-    }
 }
 
 fn record_limit_vs_fair_bps(fair_price: f64, limit_price: f64) {
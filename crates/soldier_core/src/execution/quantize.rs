@@ -36,6 +36,7 @@ pub enum QuantizeRejectReason {
     TooSmallAfterQuantization,
     InstrumentMetadataMissing,
     InvalidInput,
+    NonPositivePrice,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -83,6 +84,31 @@ pub fn quantize_from_metadata(
     quantize(side, raw_qty, raw_limit_price, &quant)
 }
 
+/// Quantizes every leg of a group, converting each distinct `InstrumentMetadata` once.
+/// Results are per leg (same order) so the caller can reject the whole group if any leg
+/// fails; reject counters bump once per failed leg, as with single-leg `quantize`.
+pub fn quantize_group(
+    legs: &[(InstrumentMetadata, f64, f64, Side)],
+) -> Vec<Result<QuantizedFields, QuantizeReject>> {
+    let mut resolved: Vec<(
+        &InstrumentMetadata,
+        Result<InstrumentQuantization, QuantizeReject>,
+    )> = Vec::new();
+    legs.iter()
+        .map(|(meta, raw_qty, raw_limit_price, side)| {
+            let quant = match resolved.iter().find(|(seen, _)| *seen == meta) {
+                Some((_, quant)) => *quant,
+                None => {
+                    let quant = InstrumentQuantization::from_metadata(meta);
+                    resolved.push((meta, quant));
+                    quant
+                }
+            };
+            quantize(*side, *raw_qty, *raw_limit_price, &quant?)
+        })
+        .collect()
+}
+
 pub fn quantize(
     side: Side,
     raw_qty: f64,
@@ -101,6 +127,34 @@ pub fn quantize_steps(
     raw_qty: f64,
     raw_limit_price: f64,
     meta: &InstrumentQuantization,
+) -> Result<QuantizedSteps, QuantizeReject> {
+    quantize_steps_with_metrics(side, raw_qty, raw_limit_price, meta, true)
+}
+
+/// [`quantize_steps`]; with `record_metrics` false a too-small reject leaves
+/// `quantization_reject_too_small_total` untouched (dry runs).
+pub(super) fn quantize_steps_with_metrics(
+    side: Side,
+    raw_qty: f64,
+    raw_limit_price: f64,
+    meta: &InstrumentQuantization,
+    record_metrics: bool,
+) -> Result<QuantizedSteps, QuantizeReject> {
+    let result = steps(side, raw_qty, raw_limit_price, meta);
+    if record_metrics
+        && let Err(reject) = &result
+        && reject.reason == QuantizeRejectReason::TooSmallAfterQuantization
+    {
+        QUANTIZATION_REJECT_TOO_SMALL_TOTAL.fetch_add(1, Ordering::Relaxed);
+    }
+    result
+}
+
+fn steps(
+    side: Side,
+    raw_qty: f64,
+    raw_limit_price: f64,
+    meta: &InstrumentQuantization,
 ) -> Result<QuantizedSteps, QuantizeReject> {
     validate_metadata(meta)?;
     validate_raw_inputs(raw_qty, raw_limit_price, meta)?;
@@ -108,7 +162,9 @@ pub fn quantize_steps(
     let qty_steps = steps_floor(raw_qty, meta.amount_step);
     let qty_q = qty_steps as f64 * meta.amount_step;
     if qty_q < meta.min_amount {
-        return reject_too_small();
+        return Err(QuantizeReject {
+            reason: QuantizeRejectReason::TooSmallAfterQuantization,
+        });
     }
 
     let price_ticks = match side {
@@ -116,6 +172,11 @@ pub fn quantize_steps(
         Side::Sell => steps_ceil(raw_limit_price, meta.tick_size),
     };
     let limit_price_q = price_ticks as f64 * meta.tick_size;
+    if price_ticks <= 0 || limit_price_q <= 0.0 {
+        return Err(QuantizeReject {
+            reason: QuantizeRejectReason::NonPositivePrice,
+        });
+    }
 
     Ok(QuantizedSteps {
         qty_steps,
@@ -167,7 +228,7 @@ fn validate_raw_inputs(
     Ok(())
 }
 
-fn steps_floor(value: f64, step: f64) -> i64 {
+pub(crate) fn steps_floor(value: f64, step: f64) -> i64 {
     let ratio = value / step;
     if let Some(integer) = near_integer(ratio) {
         return integer;
@@ -175,7 +236,7 @@ fn steps_floor(value: f64, step: f64) -> i64 {
     ratio.floor() as i64
 }
 
-fn steps_ceil(value: f64, step: f64) -> i64 {
+pub(crate) fn steps_ceil(value: f64, step: f64) -> i64 {
     let ratio = value / step;
     if let Some(integer) = near_integer(ratio) {
         return integer;
@@ -183,7 +244,7 @@ fn steps_ceil(value: f64, step: f64) -> i64 {
     ratio.ceil() as i64
 }
 
-fn near_integer(value: f64) -> Option<i64> {
+pub(crate) fn near_integer(value: f64) -> Option<i64> {
     if !value.is_finite() {
         return None;
     }
@@ -194,10 +255,3 @@ fn near_integer(value: f64) -> Option<i64> {
     }
     None
 }
-
-fn reject_too_small<T>() -> Result<T, QuantizeReject> {
-    QUANTIZATION_REJECT_TOO_SMALL_TOTAL.fetch_add(1, Ordering::Relaxed);
-    Err(QuantizeReject {
-        reason: QuantizeRejectReason::TooSmallAfterQuantization,
-    })
-}
@@ -0,0 +1,54 @@
+//! Final reduce-only gate, layered after `build_order_intent` as defense in depth: even if an
+//! upstream derivation bug lets an open through, nothing that grows exposure is dispatched
+//! while the bot is in ReduceOnly or Kill.
+
+use super::{OrderIntent, Side};
+use crate::risk::TradingMode;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReduceOnlyReject {
+    /// Kill permits no intents at all.
+    KillMode,
+    /// Side or a finite size is missing, so the exposure effect is unknown.
+    SizingMissing,
+    IncreasesExposure {
+        current_position: f64,
+        position_after: f64,
+    },
+}
+
+/// Under `Active` the intent passes untouched. Under `ReduceOnly` it passes only if it does
+/// not increase `|current_position|` (signed contracts), and is returned with
+/// `reduce_only = Some(true)` so the venue enforces the same. `Kill` rejects everything.
+pub fn enforce_reduce_only(
+    intent: OrderIntent,
+    trading_mode: TradingMode,
+    current_position: f64,
+) -> Result<OrderIntent, ReduceOnlyReject> {
+    match trading_mode {
+        TradingMode::Active => return Ok(intent),
+        TradingMode::Kill => return Err(ReduceOnlyReject::KillMode),
+        TradingMode::ReduceOnly => {}
+    }
+
+    let qty = match (intent.side, intent.notional) {
+        (Some(Side::Buy), Some(notional)) => notional.qty.abs(),
+        (Some(Side::Sell), Some(notional)) => -notional.qty.abs(),
+        _ => return Err(ReduceOnlyReject::SizingMissing),
+    };
+    if !qty.is_finite() || !current_position.is_finite() {
+        return Err(ReduceOnlyReject::SizingMissing);
+    }
+    let position_after = current_position + qty;
+    if position_after.abs() > current_position.abs() {
+        return Err(ReduceOnlyReject::IncreasesExposure {
+            current_position,
+            position_after,
+        });
+    }
+
+    Ok(OrderIntent {
+        reduce_only: Some(true),
+        ..intent
+    })
+}
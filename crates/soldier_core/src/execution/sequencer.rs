@@ -1,15 +1,15 @@
-use std::sync::Mutex;
+//! Position-Aware Execution Sequencer (CONTRACT.md §1.5)
+//!
+//! Enforces close→confirm→hedge ordering to prevent creating new naked risk
+//! while repairing, hedging, or closing positions.
+//!
+//! Rules:
+//! - Closing: Close → Confirm → Hedge (reduce-only)
+//! - Opening: Open → Confirm → Hedge
+//! - Repairs: Flatten first (emergency_close_algorithm), hedge only after retries fail
+//! - Never increase exposure when RiskState != Healthy
 
-/// Position-Aware Execution Sequencer (CONTRACT.md §1.5)
-///
-/// Enforces close→confirm→hedge ordering to prevent creating new naked risk
-/// while repairing, hedging, or closing positions.
-///
-/// Rules:
-/// - Closing: Close → Confirm → Hedge (reduce-only)
-/// - Opening: Open → Confirm → Hedge
-/// - Repairs: Flatten first (emergency_close_algorithm), hedge only after retries fail
-/// - Never increase exposure when RiskState != Healthy
+use std::sync::Mutex;
 
 const COUNTER_SEQUENCER_ORDER_VIOLATION: &str = "sequencer_order_violation_total";
 
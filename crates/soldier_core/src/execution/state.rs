@@ -1,7 +1,13 @@
+use crate::idempotency::{IntentHashInput, intent_hash};
+
+use super::Side;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TlsmState {
     Created,
     Sent,
+    /// Sent with no ack within `TlsmTimeouts::sent_timeout_ms`; needs reconciliation.
+    Unconfirmed,
     Acked,
     PartiallyFilled,
     Filled,
@@ -21,6 +27,7 @@ impl TlsmState {
         match self {
             TlsmState::Created => "Created",
             TlsmState::Sent => "Sent",
+            TlsmState::Unconfirmed => "Unconfirmed",
             TlsmState::Acked => "Acked",
             TlsmState::PartiallyFilled => "PartiallyFilled",
             TlsmState::Filled => "Filled",
@@ -45,6 +52,15 @@ impl TlsmSide {
     }
 }
 
+impl From<Side> for TlsmSide {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Buy => TlsmSide::Buy,
+            Side::Sell => TlsmSide::Sell,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TlsmIntent {
     pub intent_hash: u64,
@@ -59,6 +75,26 @@ pub struct TlsmIntent {
     pub created_ts: u64,
 }
 
+impl TlsmIntent {
+    /// Builds the ledger-side intent from the same canonical input the dispatcher hashes, so
+    /// `intent_hash` is always `idempotency::intent_hash` (CONTRACT xxhash64) and never
+    /// recomputed differently.
+    pub fn from_hash_input(input: &IntentHashInput<'_>, created_ts: u64) -> Self {
+        Self {
+            intent_hash: intent_hash(input),
+            group_id: input.group_id.to_string(),
+            leg_idx: u32::from(input.leg_idx),
+            instrument: input.instrument_id.to_string(),
+            side: input.side.into(),
+            qty_steps: Some(input.quantized.qty_steps),
+            qty_q: Some(input.quantized.qty_q),
+            limit_price_q: Some(input.quantized.limit_price_q),
+            price_ticks: Some(input.quantized.price_ticks),
+            created_ts,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TlsmEvent {
     Sent { ts_ms: u64 },
@@ -67,6 +103,7 @@ pub enum TlsmEvent {
     Filled { ts_ms: u64 },
     Canceled { ts_ms: u64 },
     Failed { ts_ms: u64 },
+    TimedOut { ts_ms: u64 },
 }
 
 impl TlsmEvent {
@@ -77,7 +114,8 @@ impl TlsmEvent {
             | TlsmEvent::PartiallyFilled { ts_ms }
             | TlsmEvent::Filled { ts_ms }
             | TlsmEvent::Canceled { ts_ms }
-            | TlsmEvent::Failed { ts_ms } => *ts_ms,
+            | TlsmEvent::Failed { ts_ms }
+            | TlsmEvent::TimedOut { ts_ms } => *ts_ms,
         }
     }
 }
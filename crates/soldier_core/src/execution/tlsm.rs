@@ -5,6 +5,7 @@ use super::state::{TlsmEvent, TlsmIntent, TlsmLedgerEntry, TlsmState};
 
 pub struct TlsmMetrics {
     out_of_order_total: AtomicU64,
+    timeout_total: AtomicU64,
 }
 
 impl Default for TlsmMetrics {
@@ -17,12 +18,17 @@ impl TlsmMetrics {
     pub const fn new() -> Self {
         Self {
             out_of_order_total: AtomicU64::new(0),
+            timeout_total: AtomicU64::new(0),
         }
     }
 
     pub fn out_of_order_total(&self) -> u64 {
         self.out_of_order_total.load(Ordering::Relaxed)
     }
+
+    pub fn timeout_total(&self) -> u64 {
+        self.timeout_total.load(Ordering::Relaxed)
+    }
 }
 
 static TLSM_METRICS: TlsmMetrics = TlsmMetrics::new();
@@ -31,6 +37,17 @@ pub fn tlsm_out_of_order_total() -> u64 {
     TLSM_METRICS.out_of_order_total()
 }
 
+pub fn tlsm_timeout_total() -> u64 {
+    TLSM_METRICS.timeout_total()
+}
+
+/// Time-in-state limits enforced by `Tlsm::tick`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlsmTimeouts {
+    /// Sent without an ack for this long moves to `Unconfirmed`.
+    pub sent_timeout_ms: u64,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TlsmTransition {
     pub from: TlsmState,
@@ -78,6 +95,7 @@ pub trait TlsmLedger {
 pub struct Tlsm {
     intent: TlsmIntent,
     state: TlsmState,
+    state_entered_ts: u64,
     sent_ts: Option<u64>,
     ack_ts: Option<u64>,
     last_fill_ts: Option<u64>,
@@ -87,9 +105,11 @@ pub struct Tlsm {
 
 impl Tlsm {
     pub fn new(intent: TlsmIntent) -> Self {
+        let state_entered_ts = intent.created_ts;
         Self {
             intent,
             state: TlsmState::Created,
+            state_entered_ts,
             sent_ts: None,
             ack_ts: None,
             last_fill_ts: None,
@@ -102,6 +122,10 @@ impl Tlsm {
         self.state
     }
 
+    pub fn state_entered_ts(&self) -> u64 {
+        self.state_entered_ts
+    }
+
     pub fn sent_ts(&self) -> Option<u64> {
         self.sent_ts
     }
@@ -131,6 +155,9 @@ impl Tlsm {
         let entry = self.build_ledger_entry_for(to, sent_ts, ack_ts, last_fill_ts);
         ledger.append_transition(&entry)?;
 
+        if to != from {
+            self.state_entered_ts = event.ts_ms();
+        }
         self.state = to;
         self.sent_ts = sent_ts;
         self.ack_ts = ack_ts;
@@ -144,6 +171,30 @@ impl Tlsm {
         })
     }
 
+    /// Emits a timeout transition once time-in-state exceeds its limit (Sent → Unconfirmed after
+    /// `sent_timeout_ms`). The transition is ledgered like any other event; `Ok(None)` means no
+    /// timeout is due.
+    pub fn tick<L: TlsmLedger>(
+        &mut self,
+        ledger: &L,
+        now_ms: u64,
+        timeouts: &TlsmTimeouts,
+    ) -> Result<Option<TlsmTransition>, TlsmError> {
+        let elapsed_ms = now_ms.saturating_sub(self.state_entered_ts);
+        if self.state != TlsmState::Sent || elapsed_ms < timeouts.sent_timeout_ms {
+            return Ok(None);
+        }
+        let transition = self.apply_event(ledger, TlsmEvent::TimedOut { ts_ms: now_ms })?;
+        TLSM_METRICS.timeout_total.fetch_add(1, Ordering::Relaxed);
+        eprintln!(
+            "tlsm_timeout_total from={} to={} elapsed_ms={}",
+            transition.from.as_str(),
+            transition.to.as_str(),
+            elapsed_ms
+        );
+        Ok(Some(transition))
+    }
+
     fn next_state(&self, current: TlsmState, event: &TlsmEvent) -> TlsmState {
         if matches!(event, TlsmEvent::Filled { .. }) {
             return TlsmState::Filled;
@@ -157,13 +208,17 @@ impl Tlsm {
                 _ => current,
             },
             TlsmEvent::Acked { .. } => match current {
-                TlsmState::Created | TlsmState::Sent => TlsmState::Acked,
+                TlsmState::Created | TlsmState::Sent | TlsmState::Unconfirmed => TlsmState::Acked,
                 _ => current,
             },
             TlsmEvent::PartiallyFilled { .. } => TlsmState::PartiallyFilled,
             TlsmEvent::Canceled { .. } => TlsmState::Canceled,
             TlsmEvent::Failed { .. } => TlsmState::Failed,
             TlsmEvent::Filled { .. } => TlsmState::Filled,
+            TlsmEvent::TimedOut { .. } => match current {
+                TlsmState::Sent => TlsmState::Unconfirmed,
+                _ => current,
+            },
         }
     }
 
@@ -189,7 +244,7 @@ impl Tlsm {
                     None => *ts_ms,
                 });
             }
-            TlsmEvent::Canceled { .. } | TlsmEvent::Failed { .. } => {}
+            TlsmEvent::Canceled { .. } | TlsmEvent::Failed { .. } | TlsmEvent::TimedOut { .. } => {}
         }
 
         (sent_ts, ack_ts, last_fill_ts)
@@ -228,6 +283,7 @@ impl Tlsm {
             TlsmEvent::Failed { .. } => {
                 self.sent_ts.is_none() || matches!(self.state, TlsmState::Filled)
             }
+            TlsmEvent::TimedOut { .. } => self.state != TlsmState::Sent,
         }
     }
 
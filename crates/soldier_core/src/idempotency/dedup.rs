@@ -0,0 +1,107 @@
+//! Content-hash dedup for functionally identical intents (double-click / retry storms).
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::hash::{IntentHashInput, intent_content_hash};
+
+static INTENT_DEDUP_REJECT_TOTAL: AtomicU64 = AtomicU64::new(0);
+static DUPLICATES_SUPPRESSED_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+/// Why an intent/trade was (or was not) suppressed, across both dedup layers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupOutcome {
+    Accepted,
+    /// Trade id already in the durable registry (WS fill replay).
+    DuplicateTradeId,
+    /// Identical intent content inside the dedup window.
+    DuplicateContentHash,
+}
+
+impl DedupOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DedupOutcome::Accepted => "accepted",
+            DedupOutcome::DuplicateTradeId => "duplicate_trade_id",
+            DedupOutcome::DuplicateContentHash => "duplicate_content_hash",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntentDuplicate {
+    pub content_hash: u64,
+    /// When the original intent was accepted.
+    pub accepted_ts_ms: u64,
+}
+
+#[derive(Debug)]
+pub struct IntentDedup {
+    dedup_window_ms: u64,
+    accepted: Mutex<HashMap<u64, u64>>,
+}
+
+impl IntentDedup {
+    pub fn new(dedup_window_ms: u64) -> Self {
+        Self {
+            dedup_window_ms,
+            accepted: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Accepts the intent unless an identical one was accepted less than
+    /// `dedup_window_ms` ago; a rejected repeat does not extend the window.
+    pub fn check(&self, input: &IntentHashInput<'_>, now_ms: u64) -> Result<u64, IntentDuplicate> {
+        let content_hash = intent_content_hash(input);
+        let mut accepted = match self.accepted.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                eprintln!("IntentDedup lock poisoned, recovering");
+                poisoned.into_inner()
+            }
+        };
+        let window_ms = self.dedup_window_ms;
+        accepted.retain(|_, ts_ms| now_ms.saturating_sub(*ts_ms) < window_ms);
+
+        if let Some(&accepted_ts_ms) = accepted.get(&content_hash) {
+            INTENT_DEDUP_REJECT_TOTAL.fetch_add(1, Ordering::Relaxed);
+            eprintln!(
+                "intent_dedup_reject_total content_hash={} accepted_ts_ms={}",
+                content_hash, accepted_ts_ms
+            );
+            record_duplicate_suppressed(DedupOutcome::DuplicateContentHash);
+            return Err(IntentDuplicate {
+                content_hash,
+                accepted_ts_ms,
+            });
+        }
+        accepted.insert(content_hash, now_ms);
+        Ok(content_hash)
+    }
+
+    /// `check` reduced to its [`DedupOutcome`].
+    pub fn check_outcome(&self, input: &IntentHashInput<'_>, now_ms: u64) -> DedupOutcome {
+        match self.check(input, now_ms) {
+            Ok(_) => DedupOutcome::Accepted,
+            Err(_) => DedupOutcome::DuplicateContentHash,
+        }
+    }
+}
+
+/// Counts a suppressed duplicate under `duplicates_suppressed_total`; `Accepted` is a no-op.
+pub fn record_duplicate_suppressed(outcome: DedupOutcome) {
+    if outcome == DedupOutcome::Accepted {
+        return;
+    }
+    DUPLICATES_SUPPRESSED_TOTAL.fetch_add(1, Ordering::Relaxed);
+    eprintln!("duplicates_suppressed_total reason={}", outcome.as_str());
+}
+
+pub fn intent_dedup_reject_total() -> u64 {
+    INTENT_DEDUP_REJECT_TOTAL.load(Ordering::Relaxed)
+}
+
+pub fn duplicates_suppressed_total() -> u64 {
+    DUPLICATES_SUPPRESSED_TOTAL.load(Ordering::Relaxed)
+}
@@ -0,0 +1,138 @@
+//! Two-phase dispatch marker (reserve → confirm) for exactly-once dispatch across retries.
+//!
+//! `begin` marks a key in flight and hands out a token; `confirm` finalizes it. A retry that
+//! calls `begin` while the first attempt is unconfirmed gets `AlreadyInFlight` instead of a
+//! second send. An unconfirmed attempt older than `inflight_ttl_ms` is presumed dead and its
+//! key can be reclaimed by the next `begin`, which invalidates the old token.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static DISPATCH_TOKENS_RECLAIMED_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DispatchToken {
+    pub key: u64,
+    pub started_ts_ms: u64,
+    attempt: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchBeginError {
+    AlreadyInFlight { started_ts_ms: u64 },
+    AlreadyConfirmed { confirmed_ts_ms: u64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchConfirmError {
+    /// The token's attempt was reclaimed (or never issued); the holder must not assume the
+    /// dispatch is final.
+    StaleToken,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MarkerState {
+    InFlight { attempt: u64, started_ts_ms: u64 },
+    Confirmed { confirmed_ts_ms: u64 },
+}
+
+#[derive(Debug)]
+struct MarkerBook {
+    entries: HashMap<u64, MarkerState>,
+    next_attempt: u64,
+}
+
+#[derive(Debug)]
+pub struct DispatchMarker {
+    inflight_ttl_ms: u64,
+    /// Confirmed keys are remembered this long so late retries still see `AlreadyConfirmed`.
+    confirmed_retention_ms: u64,
+    book: Mutex<MarkerBook>,
+}
+
+impl DispatchMarker {
+    pub fn new(inflight_ttl_ms: u64, confirmed_retention_ms: u64) -> Self {
+        Self {
+            inflight_ttl_ms,
+            confirmed_retention_ms,
+            book: Mutex::new(MarkerBook {
+                entries: HashMap::new(),
+                next_attempt: 0,
+            }),
+        }
+    }
+
+    pub fn begin(&self, key: u64, now_ms: u64) -> Result<DispatchToken, DispatchBeginError> {
+        let mut book = self.lock();
+        let retention_ms = self.confirmed_retention_ms;
+        book.entries.retain(|_, state| match *state {
+            MarkerState::Confirmed { confirmed_ts_ms } => {
+                now_ms.saturating_sub(confirmed_ts_ms) < retention_ms
+            }
+            MarkerState::InFlight { .. } => true,
+        });
+
+        match book.entries.get(&key).copied() {
+            Some(MarkerState::Confirmed { confirmed_ts_ms }) => {
+                return Err(DispatchBeginError::AlreadyConfirmed { confirmed_ts_ms });
+            }
+            Some(MarkerState::InFlight { started_ts_ms, .. }) => {
+                if now_ms.saturating_sub(started_ts_ms) < self.inflight_ttl_ms {
+                    return Err(DispatchBeginError::AlreadyInFlight { started_ts_ms });
+                }
+                let total = DISPATCH_TOKENS_RECLAIMED_TOTAL.fetch_add(1, Ordering::Relaxed) + 1;
+                eprintln!(
+                    "dispatch_tokens_reclaimed_total={total} key={key} started_ts_ms={started_ts_ms}"
+                );
+            }
+            None => {}
+        }
+
+        book.next_attempt += 1;
+        let attempt = book.next_attempt;
+        book.entries.insert(
+            key,
+            MarkerState::InFlight {
+                attempt,
+                started_ts_ms: now_ms,
+            },
+        );
+        Ok(DispatchToken {
+            key,
+            started_ts_ms: now_ms,
+            attempt,
+        })
+    }
+
+    /// Finalizes `token`; fails if its attempt has since been reclaimed.
+    pub fn confirm(&self, token: &DispatchToken, now_ms: u64) -> Result<(), DispatchConfirmError> {
+        let mut book = self.lock();
+        match book.entries.get(&token.key) {
+            Some(MarkerState::InFlight { attempt, .. }) if *attempt == token.attempt => {
+                book.entries.insert(
+                    token.key,
+                    MarkerState::Confirmed {
+                        confirmed_ts_ms: now_ms,
+                    },
+                );
+                Ok(())
+            }
+            _ => Err(DispatchConfirmError::StaleToken),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MarkerBook> {
+        match self.book.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                eprintln!("DispatchMarker lock poisoned, recovering");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+pub fn dispatch_tokens_reclaimed_total() -> u64 {
+    DISPATCH_TOKENS_RECLAIMED_TOTAL.load(Ordering::Relaxed)
+}
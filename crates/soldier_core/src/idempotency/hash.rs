@@ -26,6 +26,17 @@ pub fn intent_hash(input: &IntentHashInput<'_>) -> u64 {
     xxhash64(&buf)
 }
 
+/// Hash of the economic content only (instrument, side, quantized qty/price), ignoring
+/// `group_id`/`leg_idx` so resubmissions under a fresh group still collide.
+pub fn intent_content_hash(input: &IntentHashInput<'_>) -> u64 {
+    let mut buf = Vec::with_capacity(48);
+    write_str(&mut buf, input.instrument_id);
+    write_u8(&mut buf, side_code(input.side));
+    write_i64(&mut buf, input.quantized.qty_steps);
+    write_i64(&mut buf, input.quantized.price_ticks);
+    xxhash64(&buf)
+}
+
 fn side_code(side: Side) -> u8 {
     match side {
         Side::Buy => 0,
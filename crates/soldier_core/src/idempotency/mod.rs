@@ -1,3 +1,13 @@
+pub mod dedup;
+pub mod dispatch_marker;
 pub mod hash;
 
-pub use hash::{IntentHashInput, intent_hash};
+pub use dedup::{
+    DedupOutcome, IntentDedup, IntentDuplicate, duplicates_suppressed_total,
+    intent_dedup_reject_total, record_duplicate_suppressed,
+};
+pub use dispatch_marker::{
+    DispatchBeginError, DispatchConfirmError, DispatchMarker, DispatchToken,
+    dispatch_tokens_reclaimed_total,
+};
+pub use hash::{IntentHashInput, intent_content_hash, intent_hash};
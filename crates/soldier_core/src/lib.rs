@@ -1,7 +1,9 @@
 //! Core execution and risk logic for the StoicTrader system.
 
+pub mod analytics;
 pub mod execution;
 pub mod idempotency;
+mod json;
 pub mod recovery;
 pub mod risk;
 pub mod venue;
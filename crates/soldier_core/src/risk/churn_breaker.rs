@@ -1,14 +1,14 @@
+//! Churn circuit breaker per CONTRACT.md §1.2.2
+//! Prevents death-by-fees when strategy repeatedly legs + flattens
+//!
+//! Rule: >2 flattens in 5m => 15m blacklist blocks opens for that key
+//!
+//! Thread-safety: All methods use interior mutability (Mutex) for safe concurrent access
+
 use std::collections::HashMap;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
-/// Churn circuit breaker per CONTRACT.md §1.2.2
-/// Prevents death-by-fees when strategy repeatedly legs + flattens
-///
-/// Rule: >2 flattens in 5m => 15m blacklist blocks opens for that key
-///
-/// Thread-safety: All methods use interior mutability (Mutex) for safe concurrent access
-
 const FLATTEN_WINDOW: Duration = Duration::from_secs(5 * 60);
 const FLATTEN_TRIP_COUNT: usize = 2; // >2 means 3 or more
 const BLACKLIST_DURATION: Duration = Duration::from_secs(15 * 60);
@@ -19,6 +19,17 @@ pub struct ChurnKey {
     pub structure_fingerprint: String,
 }
 
+/// Wall-clock form of the breaker state, for a supervisor to persist across restarts.
+/// Keys are sorted so the export is deterministic.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChurnBreakerSnapshot {
+    /// Flatten timestamps (epoch ms) per key, oldest first
+    pub flatten_ts_ms: Vec<(ChurnKey, Vec<u64>)>,
+    /// Blacklist expiry (epoch ms) per key
+    pub blocked_until_ms: Vec<(ChurnKey, u64)>,
+    pub trip_count: u64,
+}
+
 #[derive(Debug, Clone)]
 struct FlattenEvent {
     timestamp: Instant,
@@ -42,7 +53,22 @@ pub struct ChurnBreaker {
 #[derive(Debug, Clone, PartialEq)]
 pub enum ChurnBreakerDecision {
     Allow,
-    Reject { reason: String, trip_count: u64 },
+    Reject {
+        reason: String,
+        trip_count: u64,
+        /// Milliseconds until the blacklist for this key lapses and opens are permitted again.
+        retry_after_ms: Option<u64>,
+    },
+}
+
+impl ChurnBreakerDecision {
+    /// Time until the next OPEN would be permitted; `None` when already allowed.
+    pub fn retry_after_ms(&self) -> Option<u64> {
+        match self {
+            ChurnBreakerDecision::Allow => None,
+            ChurnBreakerDecision::Reject { retry_after_ms, .. } => *retry_after_ms,
+        }
+    }
 }
 
 impl ChurnBreaker {
@@ -111,19 +137,110 @@ impl ChurnBreaker {
 
         // Check if this key is blacklisted
         if let Some(entry) = state.blacklist.get(key) {
-            let remaining_secs = entry.blocked_until.saturating_duration_since(now).as_secs();
+            let remaining = entry.blocked_until.saturating_duration_since(now);
+            // Round up so a caller sleeping retry_after_ms never wakes inside the blacklist.
+            let retry_after_ms = remaining.as_nanos().div_ceil(1_000_000) as u64;
             ChurnBreakerDecision::Reject {
                 reason: format!(
                     "ChurnBreakerActive: blacklisted for {}s remaining",
-                    remaining_secs
+                    remaining.as_secs()
                 ),
                 trip_count: state.trip_counter,
+                retry_after_ms: Some(retry_after_ms),
             }
         } else {
             ChurnBreakerDecision::Allow
         }
     }
 
+    /// Export the sliding window and blacklist; `now`/`now_ms` are the same instant on the
+    /// monotonic and wall clocks.
+    pub fn export_state(&self, now: Instant, now_ms: u64) -> ChurnBreakerSnapshot {
+        let state = self.lock_state();
+        let to_ms = |at: Instant| {
+            if at >= now {
+                now_ms.saturating_add(at.duration_since(now).as_millis() as u64)
+            } else {
+                now_ms.saturating_sub(now.duration_since(at).as_millis() as u64)
+            }
+        };
+
+        let mut flatten_ts_ms: Vec<(ChurnKey, Vec<u64>)> = state
+            .flatten_history
+            .iter()
+            .map(|(key, events)| {
+                let mut ts: Vec<u64> = events.iter().map(|e| to_ms(e.timestamp)).collect();
+                ts.sort_unstable();
+                (key.clone(), ts)
+            })
+            .collect();
+        let mut blocked_until_ms: Vec<(ChurnKey, u64)> = state
+            .blacklist
+            .iter()
+            .map(|(key, entry)| (key.clone(), to_ms(entry.blocked_until)))
+            .collect();
+        flatten_ts_ms.sort_by(|(a, _), (b, _)| key_order(a, b));
+        blocked_until_ms.sort_by(|(a, _), (b, _)| key_order(a, b));
+
+        ChurnBreakerSnapshot {
+            flatten_ts_ms,
+            blocked_until_ms,
+            trip_count: state.trip_counter,
+        }
+    }
+
+    /// Restore an exported state, replacing the current one. Flattens older than the 5m
+    /// window and lapsed blacklist entries (relative to `now_ms`) are discarded. In-window
+    /// flattens that predate the monotonic clock (restart just after boot) or are future-dated
+    /// (wall-clock skew) are clamped to `now` so they still count.
+    pub fn import_state(&self, snapshot: &ChurnBreakerSnapshot, now: Instant, now_ms: u64) {
+        let mut state = self.lock_state();
+        let window_ms = FLATTEN_WINDOW.as_millis() as u64;
+
+        let mut flatten_history = HashMap::new();
+        for (key, timestamps) in &snapshot.flatten_ts_ms {
+            let events: Vec<FlattenEvent> = timestamps
+                .iter()
+                .map(|&ts_ms| now_ms.saturating_sub(ts_ms))
+                .filter(|&age_ms| age_ms <= window_ms)
+                .map(|age_ms| FlattenEvent {
+                    timestamp: now
+                        .checked_sub(Duration::from_millis(age_ms))
+                        .unwrap_or(now),
+                })
+                .collect();
+            if !events.is_empty() {
+                flatten_history.insert(key.clone(), events);
+            }
+        }
+
+        let blacklist = snapshot
+            .blocked_until_ms
+            .iter()
+            .filter(|(_, blocked_until_ms)| *blocked_until_ms > now_ms)
+            .map(|(key, blocked_until_ms)| {
+                let blocked_until = now + Duration::from_millis(blocked_until_ms - now_ms);
+                (key.clone(), BlacklistEntry { blocked_until })
+            })
+            .collect();
+
+        *state = ChurnBreakerState {
+            flatten_history,
+            blacklist,
+            trip_counter: snapshot.trip_count,
+        };
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, ChurnBreakerState> {
+        match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                eprintln!("churn_breaker lock poisoned, recovering");
+                poisoned.into_inner()
+            }
+        }
+    }
+
     /// Get total trip count (for churn_breaker_trip_total metric)
     /// Thread-safe: uses interior mutability
     pub fn trip_count(&self) -> u64 {
@@ -138,6 +255,10 @@ impl ChurnBreaker {
     }
 }
 
+fn key_order(a: &ChurnKey, b: &ChurnKey) -> std::cmp::Ordering {
+    (&a.strategy_id, &a.structure_fingerprint).cmp(&(&b.strategy_id, &b.structure_fingerprint))
+}
+
 impl Default for ChurnBreaker {
     fn default() -> Self {
         Self::new()
@@ -158,7 +279,7 @@ mod tests {
     #[test]
     fn test_churn_breaker_allows_opens_when_inactive() {
         // GIVEN: churn breaker inactive
-        let mut breaker = ChurnBreaker::new();
+        let breaker = ChurnBreaker::new();
         let key = test_key("strat1", "BTC-PERP-delta0.5");
         let now = Instant::now();
 
@@ -172,7 +293,7 @@ mod tests {
     #[test]
     fn test_churn_breaker_blacklists_after_three_flattens() {
         // GIVEN: 3 flattens in 5m window
-        let mut breaker = ChurnBreaker::new();
+        let breaker = ChurnBreaker::new();
         let key = test_key("strat1", "BTC-PERP-delta0.5");
         let now = Instant::now();
 
@@ -185,7 +306,9 @@ mod tests {
 
         // THEN: the open is blocked
         match decision {
-            ChurnBreakerDecision::Reject { reason, trip_count } => {
+            ChurnBreakerDecision::Reject {
+                reason, trip_count, ..
+            } => {
                 assert!(reason.contains("ChurnBreakerActive"));
                 assert_eq!(trip_count, 1);
             }
@@ -196,7 +319,7 @@ mod tests {
     #[test]
     fn test_churn_breaker_enforces_15m_blacklist_ttl() {
         // GIVEN: 3 flattens triggering blacklist
-        let mut breaker = ChurnBreaker::new();
+        let breaker = ChurnBreaker::new();
         let key = test_key("strat1", "BTC-PERP-delta0.5");
         let now = Instant::now();
 
@@ -222,7 +345,7 @@ mod tests {
     #[test]
     fn test_churn_breaker_prunes_old_flatten_events() {
         // GIVEN: 2 flattens within window, 1 outside
-        let mut breaker = ChurnBreaker::new();
+        let breaker = ChurnBreaker::new();
         let key = test_key("strat1", "BTC-PERP-delta0.5");
         let now = Instant::now();
 
@@ -242,7 +365,7 @@ mod tests {
     #[test]
     fn test_churn_breaker_isolates_keys() {
         // GIVEN: key1 trips, key2 doesn't
-        let mut breaker = ChurnBreaker::new();
+        let breaker = ChurnBreaker::new();
         let key1 = test_key("strat1", "BTC-PERP-delta0.5");
         let key2 = test_key("strat2", "ETH-PERP-delta0.3");
         let now = Instant::now();
@@ -264,7 +387,7 @@ mod tests {
     #[test]
     fn test_churn_breaker_trip_counter_increments() {
         // GIVEN: multiple trips across different keys
-        let mut breaker = ChurnBreaker::new();
+        let breaker = ChurnBreaker::new();
         let key1 = test_key("strat1", "BTC-PERP");
         let key2 = test_key("strat2", "ETH-PERP");
         let now = Instant::now();
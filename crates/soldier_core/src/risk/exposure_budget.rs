@@ -11,11 +11,15 @@
 //!   - `corr(BTC,ETH)=0.8`, `corr(BTC,alts)=0.6`, `corr(ETH,alts)=0.6`
 //! - Gate new opens if portfolio exposure breaches limits even if single-instrument gates pass
 //! - Rejections for portfolio breach MUST use `Rejected(GlobalExposureBudgetExceeded)`
+//! - Optional hysteresis: once current exposure breaches the limit, opens stay blocked until
+//!   it drops below `portfolio_delta_exit_limit_usd`. The latch follows current (filled +
+//!   pending) exposure only; a rejected candidate's projection never sets it.
 //!
 //! # Integration Rule
 //! The Global Budget must be checked using **current + pending** exposure (see §1.4.2.1).
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Instrument exposure in USD
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -26,8 +30,20 @@ pub struct InstrumentExposure {
 /// Portfolio-level exposure budget configuration
 #[derive(Debug, Clone)]
 pub struct GlobalBudgetConfig {
-    /// Maximum portfolio delta exposure in USD
+    /// Maximum portfolio delta exposure in USD (enter limit: breaching it blocks new opens)
     pub portfolio_delta_limit_usd: f64,
+    /// Once breached, opens stay blocked until portfolio delta drops below this exit limit.
+    /// Clamped to `portfolio_delta_limit_usd`; `None` disables hysteresis.
+    pub portfolio_delta_exit_limit_usd: Option<f64>,
+}
+
+/// Hysteresis band that governed a budget decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalBudgetBand {
+    /// Budget not breached; `portfolio_delta_limit_usd` applies
+    Enter,
+    /// Budget latched after a breach; `portfolio_delta_exit_limit_usd` applies
+    Exit,
 }
 
 /// Result of global budget evaluation
@@ -39,6 +55,7 @@ pub enum GlobalBudgetResult {
     GlobalExposureBudgetExceeded {
         portfolio_delta_after: f64,
         limit: f64,
+        band: GlobalBudgetBand,
     },
 }
 
@@ -78,12 +95,16 @@ impl CorrelationBucket {
 /// Global exposure budget evaluator
 pub struct GlobalExposureBudget {
     config: GlobalBudgetConfig,
+    breached: AtomicBool,
 }
 
 impl GlobalExposureBudget {
     /// Create a new global budget evaluator
     pub fn new(config: GlobalBudgetConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            breached: AtomicBool::new(false),
+        }
     }
 
     /// Evaluate if a new trade would breach the portfolio budget
@@ -102,6 +123,8 @@ impl GlobalExposureBudget {
         new_instrument: &str,
         new_delta_usd: f64,
     ) -> GlobalBudgetResult {
+        self.update_latch(current_exposures);
+
         // Build portfolio exposure after adding new trade
         let mut exposures_after = current_exposures.clone();
         exposures_after
@@ -114,17 +137,69 @@ impl GlobalExposureBudget {
         // Compute correlation-aware portfolio delta
         let portfolio_delta = self.compute_portfolio_delta(&exposures_after);
 
-        // Check against limit
-        if portfolio_delta.abs() > self.config.portfolio_delta_limit_usd {
+        let enter_limit = self.config.portfolio_delta_limit_usd;
+        if portfolio_delta.abs() > enter_limit {
+            return GlobalBudgetResult::GlobalExposureBudgetExceeded {
+                portfolio_delta_after: portfolio_delta,
+                limit: enter_limit,
+                band: GlobalBudgetBand::Enter,
+            };
+        }
+
+        // Latched (including the call that set it): only trades below the exit band pass
+        let exit_limit = self.exit_limit();
+        if self.breached.load(Ordering::Relaxed) && portfolio_delta.abs() >= exit_limit {
             GlobalBudgetResult::GlobalExposureBudgetExceeded {
                 portfolio_delta_after: portfolio_delta,
-                limit: self.config.portfolio_delta_limit_usd,
+                limit: exit_limit,
+                band: GlobalBudgetBand::Exit,
             }
         } else {
             GlobalBudgetResult::Pass
         }
     }
 
+    /// Latches when current exposure exceeds the enter limit and releases once it drops
+    /// below the exit limit; a no-op without hysteresis configured.
+    fn update_latch(&self, current_exposures: &HashMap<String, InstrumentExposure>) {
+        if self.config.portfolio_delta_exit_limit_usd.is_none() {
+            return;
+        }
+        let current_delta = self.compute_portfolio_delta(current_exposures).abs();
+        if self.breached.load(Ordering::Relaxed) {
+            if current_delta < self.exit_limit() {
+                self.breached.store(false, Ordering::Relaxed);
+            }
+        } else if current_delta > self.config.portfolio_delta_limit_usd {
+            self.breached.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Whether the enter/exit hysteresis latch is currently set
+    pub fn is_breached(&self) -> bool {
+        self.breached.load(Ordering::Relaxed)
+    }
+
+    /// Room left under the active limit (exit limit while latched) for `exposures`, in USD;
+    /// negative when already over. Read-only: does not move the latch.
+    pub fn headroom_usd(&self, exposures: &HashMap<String, InstrumentExposure>) -> f64 {
+        let limit = if self.is_breached() {
+            self.exit_limit()
+        } else {
+            self.config.portfolio_delta_limit_usd
+        };
+        limit - self.compute_portfolio_delta(exposures).abs()
+    }
+
+    /// Exit limit, never looser than the enter limit
+    fn exit_limit(&self) -> f64 {
+        let enter_limit = self.config.portfolio_delta_limit_usd;
+        match self.config.portfolio_delta_exit_limit_usd {
+            Some(exit_limit) if exit_limit.is_finite() => exit_limit.min(enter_limit),
+            _ => enter_limit,
+        }
+    }
+
     /// Compute correlation-aware portfolio delta from per-instrument exposures
     ///
     /// Uses correlation buckets:
@@ -226,6 +301,7 @@ mod tests {
     fn test_single_instrument_within_limit() {
         let config = GlobalBudgetConfig {
             portfolio_delta_limit_usd: 10000.0,
+            portfolio_delta_exit_limit_usd: None,
         };
         let budget = GlobalExposureBudget::new(config);
 
@@ -239,6 +315,7 @@ mod tests {
     fn test_single_instrument_exceeds_limit() {
         let config = GlobalBudgetConfig {
             portfolio_delta_limit_usd: 10000.0,
+            portfolio_delta_exit_limit_usd: None,
         };
         let budget = GlobalExposureBudget::new(config);
 
@@ -249,6 +326,7 @@ mod tests {
             GlobalBudgetResult::GlobalExposureBudgetExceeded {
                 portfolio_delta_after,
                 limit,
+                ..
             } => {
                 assert!(portfolio_delta_after.abs() > 10000.0);
                 assert_eq!(limit, 10000.0);
@@ -1,6 +1,8 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use super::RiskState;
+use crate::execution::QuantizedFields;
+use crate::venue::{InstrumentKind, InstrumentMetadata};
 
 pub const FEE_CACHE_SOFT_S_DEFAULT: u64 = 300;
 pub const FEE_CACHE_HARD_S_DEFAULT: u64 = 900;
@@ -33,19 +35,159 @@ pub struct FeeModelSnapshot {
     pub fee_tier: u64,
     pub maker_fee_rate: f64,
     pub taker_fee_rate: f64,
+    /// Settlement/delivery fee charged on option positions held into expiry, in bps of the
+    /// settled notional. Not part of the account summary; supplied from venue fee config.
+    /// `None` for books that never cost options (perp-only); [`expiry_cost`] then refuses to
+    /// cost an option rather than assume zero.
+    pub settlement_fee_bps: Option<f64>,
     pub fee_model_cached_at_ts_ms: Option<u64>,
 }
 
+/// Required fee-model fields absent (or unusable) in a venue payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeModelSchemaError {
+    MissingFeeTier,
+    MissingMakerFeeRate,
+    MissingTakerFeeRate,
+    MissingSettlementFeeBps,
+    NonFiniteFeeRate,
+    NonFiniteSettlementFee,
+}
+
+impl FeeModelSnapshot {
+    /// Builds a snapshot from venue fields, refusing partial data rather than defaulting a
+    /// missing tier (which would under-estimate fees). The settlement fee is only needed to
+    /// cost options, so it may be absent, but a supplied one must be finite.
+    pub fn from_fields(
+        fee_tier: Option<u64>,
+        maker_fee_rate: Option<f64>,
+        taker_fee_rate: Option<f64>,
+        settlement_fee_bps: Option<f64>,
+        fee_model_cached_at_ts_ms: Option<u64>,
+    ) -> Result<Self, FeeModelSchemaError> {
+        let fee_tier = fee_tier.ok_or(FeeModelSchemaError::MissingFeeTier)?;
+        let maker_fee_rate = maker_fee_rate.ok_or(FeeModelSchemaError::MissingMakerFeeRate)?;
+        let taker_fee_rate = taker_fee_rate.ok_or(FeeModelSchemaError::MissingTakerFeeRate)?;
+        if !maker_fee_rate.is_finite() || !taker_fee_rate.is_finite() {
+            return Err(FeeModelSchemaError::NonFiniteFeeRate);
+        }
+        if settlement_fee_bps.is_some_and(|bps| !bps.is_finite()) {
+            return Err(FeeModelSchemaError::NonFiniteSettlementFee);
+        }
+        Ok(Self {
+            fee_tier,
+            maker_fee_rate,
+            taker_fee_rate,
+            settlement_fee_bps,
+            fee_model_cached_at_ts_ms,
+        })
+    }
+
+    /// Qty-weighted maker/taker rate in bps for a fill that was partly maker, partly taker.
+    /// Zero total qty (nothing filled) yields `0.0`.
+    pub fn blended_fee_bps(&self, maker_qty: f64, taker_qty: f64) -> f64 {
+        let (maker_qty, taker_qty) = (maker_qty.abs(), taker_qty.abs());
+        let total_qty = maker_qty + taker_qty;
+        if total_qty <= 0.0 {
+            return 0.0;
+        }
+        let blended_rate =
+            (maker_qty * self.maker_fee_rate + taker_qty * self.taker_fee_rate) / total_qty;
+        blended_rate * 10_000.0
+    }
+
+    /// Expected fee for `quantized` at the maker or taker rate; see [`Self::fee_for_notional`].
+    pub fn order_cost(
+        &self,
+        quantized: &QuantizedFields,
+        contract_multiplier: f64,
+        is_maker: bool,
+    ) -> Option<OrderCost> {
+        self.fee_for_notional(
+            quantized.qty_q * quantized.limit_price_q * contract_multiplier,
+            is_maker,
+        )
+    }
+
+    /// Expected fee for an order of `notional_usd` at the maker or taker rate. `fee_bps` is
+    /// relative to the notional; a finite zero notional costs nothing (`0.0`/`0.0`). Returns
+    /// `None` when the notional or the fee is not finite so callers fail closed instead of
+    /// trading free.
+    pub fn fee_for_notional(&self, notional_usd: f64, is_maker: bool) -> Option<OrderCost> {
+        let notional_usd = notional_usd.abs();
+        if !notional_usd.is_finite() {
+            return None;
+        }
+        if notional_usd == 0.0 {
+            return Some(OrderCost {
+                fee_usd: 0.0,
+                fee_bps: 0.0,
+            });
+        }
+        let rate = if is_maker {
+            self.maker_fee_rate
+        } else {
+            self.taker_fee_rate
+        };
+        let cost = OrderCost {
+            fee_usd: notional_usd * rate,
+            fee_bps: rate * 10_000.0,
+        };
+        (cost.fee_usd.is_finite() && cost.fee_bps.is_finite()).then_some(cost)
+    }
+}
+
+/// Expected settlement drag in USD for `position_qty` of `instrument` held into expiry at
+/// `settlement_price`. Only options settle with a fee; every other kind costs `0.0`. This is
+/// a holding cost and is deliberately not part of the per-trade [`FeeModelSnapshot::order_cost`].
+/// A missing or non-finite settlement fee on an option is an error rather than a free expiry.
+pub fn expiry_cost(
+    snapshot: &FeeModelSnapshot,
+    position_qty: f64,
+    settlement_price: f64,
+    instrument: &InstrumentMetadata,
+) -> Result<f64, FeeModelSchemaError> {
+    if instrument.instrument_kind != InstrumentKind::Option {
+        return Ok(0.0);
+    }
+    let settlement_fee_bps = snapshot
+        .settlement_fee_bps
+        .ok_or(FeeModelSchemaError::MissingSettlementFeeBps)?;
+    let cost = (position_qty * settlement_price * instrument.contract_multiplier).abs()
+        * settlement_fee_bps
+        / 10_000.0;
+    if !cost.is_finite() {
+        return Err(FeeModelSchemaError::NonFiniteSettlementFee);
+    }
+    Ok(cost)
+}
+
+/// All-in fee for one order; negative values are maker rebates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderCost {
+    pub fee_usd: f64,
+    pub fee_bps: f64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct FeeStalenessDecision {
     pub cache_age_s: f64,
     pub fee_rate_effective: f64,
     pub risk_state: RiskState,
+    cost_multiplier: f64,
     soft_stale: bool,
     hard_stale: bool,
 }
 
 impl FeeStalenessDecision {
+    /// Pads `cost` by the same soft-stale buffer applied to `fee_rate_effective`.
+    pub fn apply_to(self, cost: OrderCost) -> OrderCost {
+        OrderCost {
+            fee_usd: cost.fee_usd * self.cost_multiplier,
+            fee_bps: cost.fee_bps * self.cost_multiplier,
+        }
+    }
+
     pub fn is_soft_stale(self) -> bool {
         self.soft_stale
     }
@@ -83,11 +225,12 @@ pub fn evaluate_fee_staleness(
 
     let hard_stale = age_s > hard_s;
     let soft_stale = !hard_stale && age_s > config.fee_cache_soft_s as f64;
-    let fee_rate_effective = if soft_stale {
-        fee_rate * (1.0 + config.fee_stale_buffer)
+    let cost_multiplier = if soft_stale {
+        1.0 + config.fee_stale_buffer
     } else {
-        fee_rate
+        1.0
     };
+    let fee_rate_effective = fee_rate * cost_multiplier;
 
     let risk_state = if hard_stale {
         RiskState::Degraded
@@ -99,6 +242,7 @@ pub fn evaluate_fee_staleness(
         cache_age_s: age_s,
         fee_rate_effective,
         risk_state,
+        cost_multiplier,
         soft_stale,
         hard_stale,
     }
@@ -156,6 +300,24 @@ impl FeeModelCache {
         self.last_poll_ms = Some(now_ms);
     }
 
+    /// Applies a loaded snapshot. A schema failure counts as a refresh failure and drops the
+    /// cached timestamp, so the cache evaluates hard-stale until the next complete snapshot.
+    pub fn load_snapshot(
+        &mut self,
+        snapshot: Result<FeeModelSnapshot, FeeModelSchemaError>,
+        now_ms: u64,
+    ) {
+        match snapshot {
+            Ok(snapshot) => self.apply_snapshot(snapshot, now_ms),
+            Err(err) => {
+                record_fee_model_refresh_fail();
+                eprintln!("fee_model_refresh_fail_total reason=schema error={err:?}");
+                self.fee_model_cached_at_ts_ms = None;
+                self.last_poll_ms = Some(now_ms);
+            }
+        }
+    }
+
     pub fn fee_tier(&self) -> u64 {
         self.fee_tier
     }
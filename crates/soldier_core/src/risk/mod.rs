@@ -3,19 +3,25 @@ pub mod exposure_budget;
 pub mod fees;
 pub mod inventory_skew;
 pub mod margin_gate;
+pub mod net_delta;
 pub mod pending_exposure;
+pub mod pre_dispatch;
+pub mod profile;
 pub mod self_impact_guard;
+pub mod snapshot;
 pub mod state;
 
-pub use churn_breaker::{ChurnBreaker, ChurnBreakerDecision, ChurnKey};
+pub use churn_breaker::{ChurnBreaker, ChurnBreakerDecision, ChurnBreakerSnapshot, ChurnKey};
 pub use exposure_budget::{
-    GlobalBudgetConfig, GlobalBudgetResult, GlobalExposureBudget, InstrumentExposure,
+    GlobalBudgetBand, GlobalBudgetConfig, GlobalBudgetResult, GlobalExposureBudget,
+    InstrumentExposure,
 };
 pub use fees::{
     FEE_CACHE_HARD_S_DEFAULT, FEE_CACHE_SOFT_S_DEFAULT, FEE_MODEL_POLL_INTERVAL_MS,
-    FEE_MODEL_POLL_INTERVAL_S, FEE_STALE_BUFFER_DEFAULT, FeeModelCache, FeeModelSnapshot,
-    FeeStalenessConfig, FeeStalenessDecision, evaluate_fee_staleness, fee_model_cache_age_s,
-    fee_model_refresh_fail_total, record_fee_model_refresh_fail,
+    FEE_MODEL_POLL_INTERVAL_S, FEE_STALE_BUFFER_DEFAULT, FeeModelCache, FeeModelSchemaError,
+    FeeModelSnapshot, FeeStalenessConfig, FeeStalenessDecision, OrderCost, evaluate_fee_staleness,
+    expiry_cost, fee_model_cache_age_s, fee_model_refresh_fail_total,
+    record_fee_model_refresh_fail,
 };
 pub use inventory_skew::{
     IntentSide, InventorySkewConfig, InventorySkewEvaluation, evaluate_inventory_skew,
@@ -24,9 +30,16 @@ pub use margin_gate::{
     MarginConfig, MarginGateResult, MarginModeRecommendation, MarginSnapshot,
     compute_margin_mode_recommendation, evaluate_margin_gate_for_open,
 };
-pub use pending_exposure::{DeltaContracts, PendingExposureTracker, ReservationId, ReserveResult};
+pub use net_delta::{OpenOrder, project_net_delta};
+pub use pending_exposure::{
+    BudgetScope, DeltaContracts, GroupReserveReject, PendingExposureTracker, ReservationId,
+    ReserveResult,
+};
+pub use pre_dispatch::{RiskContext, RiskDecision, RiskIntent, RiskReason, pre_dispatch_check};
+pub use profile::{EnforcedProfile, ProfileParseError, enforced_profile_parse_error_total};
 pub use self_impact_guard::{
     LatchReason, SelfImpactConfig, SelfImpactEvaluation, SelfImpactGuard, SelfImpactKey,
-    TradeAggregates,
+    TradeAggregates, trade_feed_latch,
 };
+pub use snapshot::RiskSnapshot;
 pub use state::{PolicyGuard, RiskState, TradingMode};
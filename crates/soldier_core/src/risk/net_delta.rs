@@ -0,0 +1,40 @@
+//! Projected Net Delta
+//!
+//! Single source for the signed delta an instrument would carry if everything in flight
+//! completed: realized + pending reservations (§1.4.2.1) + resting open orders.
+//! Feeds `evaluate_inventory_skew` and the global exposure budget.
+
+use super::{DeltaContracts, IntentSide, PendingExposureTracker};
+
+/// A resting order that has not yet filled
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenOrder {
+    pub instrument_id: String,
+    pub side: IntentSide,
+    /// Unfilled delta impact in contracts (unsigned; `side` gives the sign)
+    pub remaining_delta: DeltaContracts,
+}
+
+impl OpenOrder {
+    fn signed_delta(&self) -> DeltaContracts {
+        match self.side {
+            IntentSide::Buy => self.remaining_delta.abs(),
+            IntentSide::Sell => -self.remaining_delta.abs(),
+        }
+    }
+}
+
+/// realized + signed pending reservations + signed resting orders for `instrument_id`.
+pub fn project_net_delta(
+    instrument_id: &str,
+    realized: DeltaContracts,
+    pending: &PendingExposureTracker,
+    open_orders: &[OpenOrder],
+) -> DeltaContracts {
+    let resting: DeltaContracts = open_orders
+        .iter()
+        .filter(|order| order.instrument_id == instrument_id)
+        .map(OpenOrder::signed_delta)
+        .sum();
+    realized + pending.get_pending_signed_delta(instrument_id) + resting
+}
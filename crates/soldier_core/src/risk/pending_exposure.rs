@@ -14,7 +14,7 @@
 //!      - If reservation would breach limits → reject with `PendingExposureBudgetExceeded`
 //!   3. On terminal outcome (Filled/Rejected/Canceled) → release reservation
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 
 /// Unique identifier for a reservation (intent ID or group ID)
@@ -35,6 +35,24 @@ pub enum ReserveResult {
     },
 }
 
+/// Which budget a group leg would breach
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetScope {
+    Instrument,
+    Global,
+}
+
+/// First leg of a group that failed; nothing from the group was reserved
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupReserveReject {
+    pub leg_idx: usize,
+    pub reservation_id: ReservationId,
+    pub instrument_id: String,
+    pub scope: BudgetScope,
+    pub requested: DeltaContracts,
+    pub available: DeltaContracts,
+}
+
 /// Per-instrument pending exposure tracker
 #[derive(Debug, Clone)]
 struct InstrumentPending {
@@ -91,8 +109,7 @@ impl InstrumentPending {
 pub struct PendingExposureTracker {
     /// Per-instrument pending exposure
     instruments: Arc<Mutex<HashMap<String, InstrumentPending>>>,
-    /// Global pending delta limit (optional, reserved for future global budget check)
-    #[allow(dead_code)]
+    /// Global pending delta limit (optional; enforced by `reserve_group`)
     global_limit: Option<DeltaContracts>,
 }
 
@@ -156,6 +173,70 @@ impl PendingExposureTracker {
         ReserveResult::Reserved
     }
 
+    /// Record a reservation without a budget check, for orders already live at the venue
+    /// (restart recovery): their exposure exists whether or not it fits the budget.
+    pub fn restore_reservation(
+        &self,
+        reservation_id: ReservationId,
+        instrument_id: &str,
+        delta_impact: DeltaContracts,
+    ) {
+        let mut instruments = self.instruments.lock().unwrap();
+        instruments
+            .entry(instrument_id.to_string())
+            .or_insert_with(|| InstrumentPending::new(None))
+            .reserve(reservation_id, delta_impact);
+    }
+
+    /// Reserve every leg of a combo atomically: legs are applied in order to a scratch copy
+    /// against per-instrument and global budgets, and committed only if all of them fit.
+    ///
+    /// # Arguments
+    /// * `reservations` - `(reservation_id, instrument_id, delta_impact_est, current_delta)` per leg
+    pub fn reserve_group(
+        &self,
+        reservations: &[(ReservationId, &str, DeltaContracts, DeltaContracts)],
+    ) -> Result<(), GroupReserveReject> {
+        let mut instruments = self.instruments.lock().unwrap();
+        let mut scratch = instruments.clone();
+
+        for (leg_idx, (reservation_id, instrument_id, delta_impact_est, current_delta)) in
+            reservations.iter().enumerate()
+        {
+            let reject = |scope, available: DeltaContracts| GroupReserveReject {
+                leg_idx,
+                reservation_id: reservation_id.clone(),
+                instrument_id: instrument_id.to_string(),
+                scope,
+                requested: delta_impact_est.abs(),
+                available: available.max(0.0),
+            };
+
+            let inst = scratch
+                .entry(instrument_id.to_string())
+                .or_insert_with(|| InstrumentPending::new(None));
+            if !inst.can_reserve(*delta_impact_est, *current_delta) {
+                let available = inst.delta_limit.unwrap_or(0.0).abs()
+                    - current_delta.abs()
+                    - inst.pending_delta.abs();
+                return Err(reject(BudgetScope::Instrument, available));
+            }
+            inst.reserve(reservation_id.clone(), *delta_impact_est);
+
+            if let Some(global_limit) = self.global_limit {
+                let global_pending: DeltaContracts =
+                    scratch.values().map(|inst| inst.pending_delta).sum();
+                if global_pending > global_limit.abs() {
+                    let available = global_limit.abs() - (global_pending - delta_impact_est.abs());
+                    return Err(reject(BudgetScope::Global, available));
+                }
+            }
+        }
+
+        *instruments = scratch;
+        Ok(())
+    }
+
     /// Release a reservation when intent reaches terminal state
     ///
     /// # Arguments
@@ -183,6 +264,24 @@ impl PendingExposureTracker {
             .unwrap_or(0.0)
     }
 
+    /// Signed sum of active reservations for an instrument (`get_pending_delta` is absolute)
+    pub fn get_pending_signed_delta(&self, instrument_id: &str) -> DeltaContracts {
+        let instruments = self.instruments.lock().unwrap();
+        instruments
+            .get(instrument_id)
+            .map(|inst| inst.reservations.values().sum())
+            .unwrap_or(0.0)
+    }
+
+    /// Absolute pending delta per registered instrument, keyed in instrument order
+    pub fn pending_deltas(&self) -> BTreeMap<String, DeltaContracts> {
+        let instruments = self.instruments.lock().unwrap();
+        instruments
+            .iter()
+            .map(|(instrument_id, inst)| (instrument_id.clone(), inst.pending_delta))
+            .collect()
+    }
+
     /// Get total global pending delta across all instruments
     pub fn get_global_pending_delta(&self) -> DeltaContracts {
         let instruments = self.instruments.lock().unwrap();
@@ -0,0 +1,210 @@
+//! Combined Pre-Dispatch Risk Check
+//!
+//! Risk-layer analogue of `build_order_intent`: runs the OPEN-side risk guards in a fixed
+//! order and folds them into one `RiskDecision`.
+//!
+//! # Order
+//! 1. ChurnBreaker (§1.2.2)
+//! 2. SelfImpactGuard (§1.2.3)
+//! 3. InventorySkew (§1.4.2): may tighten/loosen `min_edge_usd` and bias the limit by ticks
+//! 4. GlobalExposureBudget (§1.4.2.2), on current + pending exposure
+//! 5. PendingExposure reservation (§1.4.2.1)
+//!
+//! The first hard reject short-circuits, so later guards are not consulted and no reservation
+//! is taken. The reservation runs last because it is the only step that must be undone.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use super::{
+    ChurnBreaker, ChurnBreakerDecision, ChurnKey, DeltaContracts, GlobalBudgetResult,
+    GlobalExposureBudget, InstrumentExposure, IntentSide, InventorySkewConfig, LatchReason,
+    PendingExposureTracker, ReserveResult, RiskState, SelfImpactConfig, SelfImpactGuard,
+    SelfImpactKey, TradeAggregates, evaluate_inventory_skew,
+};
+
+/// The OPEN intent being checked
+#[derive(Debug, Clone)]
+pub struct RiskIntent<'a> {
+    /// Reservation id for PendingExposure (intent/group id)
+    pub reservation_id: &'a str,
+    pub instrument_id: &'a str,
+    pub side: IntentSide,
+    pub churn_key: &'a ChurnKey,
+    pub self_impact_key: &'a SelfImpactKey,
+    /// Estimated delta impact in contracts (PendingExposure)
+    pub delta_impact: DeltaContracts,
+    /// Delta USD added to the portfolio (GlobalExposureBudget)
+    pub delta_usd: f64,
+    /// Current realized delta for the instrument
+    pub current_delta: DeltaContracts,
+    /// Per-instrument delta limit; `None` fails closed in InventorySkew
+    pub delta_limit: Option<DeltaContracts>,
+    /// `min_edge_usd` from the Net Edge Gate, before skew adjustment
+    pub min_edge_usd: f64,
+    pub tick_size_usd: f64,
+}
+
+/// Shared guard state and inputs for one evaluation
+pub struct RiskContext<'a> {
+    pub churn_breaker: &'a ChurnBreaker,
+    pub self_impact_guard: &'a SelfImpactGuard,
+    pub self_impact_config: SelfImpactConfig,
+    pub trade_aggregates: TradeAggregates,
+    pub inventory_skew_config: InventorySkewConfig,
+    pub global_budget: &'a GlobalExposureBudget,
+    /// Current + pending exposure per instrument (§1.4.2.1)
+    pub portfolio_exposures: &'a HashMap<String, InstrumentExposure>,
+    pub pending_exposure: &'a PendingExposureTracker,
+    pub now_ms: u64,
+    pub now: Instant,
+}
+
+/// Why a check rejected, or (for `InventorySkewAdjusted`) what it changed
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskReason {
+    ChurnBreakerActive {
+        retry_after_ms: Option<u64>,
+    },
+    SelfImpactLatched(LatchReason),
+    FeedbackLoopGuardActive(String),
+    InventorySkew(String),
+    /// Non-blocking: the edge requirement/limit bias InventorySkew applied
+    InventorySkewAdjusted {
+        adjusted_min_edge_usd: f64,
+        bias_ticks: i32,
+    },
+    GlobalExposureBudgetExceeded {
+        portfolio_delta_after: f64,
+        limit: f64,
+    },
+    PendingExposureBudgetExceeded {
+        requested: DeltaContracts,
+        available: DeltaContracts,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskDecision {
+    pub allowed: bool,
+    pub risk_state: RiskState,
+    /// Reasons in check order; a rejected decision ends with its hard reject
+    pub reasons: Vec<RiskReason>,
+    /// Skew-adjusted `min_edge_usd` to feed back into the Net Edge Gate
+    pub adjusted_min_edge_usd: Option<f64>,
+    /// Ticks to bias the limit price away from the touch
+    pub bias_ticks: i32,
+}
+
+impl RiskDecision {
+    fn reject(mut self, reason: RiskReason, risk_state: RiskState) -> Self {
+        self.reasons.push(reason);
+        self.allowed = false;
+        self.risk_state = risk_state;
+        self
+    }
+}
+
+/// Runs the pre-dispatch risk guards in order; see the module docs.
+pub fn pre_dispatch_check(intent: &RiskIntent<'_>, ctx: &RiskContext<'_>) -> RiskDecision {
+    let decision = RiskDecision {
+        allowed: true,
+        risk_state: RiskState::Healthy,
+        reasons: Vec::new(),
+        adjusted_min_edge_usd: None,
+        bias_ticks: 0,
+    };
+
+    if let ChurnBreakerDecision::Reject { retry_after_ms, .. } =
+        ctx.churn_breaker.evaluate_open(intent.churn_key, ctx.now)
+    {
+        return decision.reject(
+            RiskReason::ChurnBreakerActive { retry_after_ms },
+            RiskState::Healthy,
+        );
+    }
+
+    let self_impact = ctx.self_impact_guard.evaluate_open(
+        intent.self_impact_key,
+        ctx.trade_aggregates,
+        ctx.now_ms,
+        ctx.now,
+        ctx.self_impact_config,
+    );
+    if !self_impact.allowed {
+        let reason = match (self_impact.latch_reason, self_impact.reject_reason) {
+            (Some(latch), _) => RiskReason::SelfImpactLatched(latch),
+            (None, Some(reason)) => RiskReason::FeedbackLoopGuardActive(reason),
+            (None, None) => RiskReason::FeedbackLoopGuardActive("FeedbackLoopGuardActive".into()),
+        };
+        return decision.reject(reason, self_impact.risk_state);
+    }
+
+    let pending_delta = ctx.pending_exposure.get_pending_delta(intent.instrument_id);
+    let skew = evaluate_inventory_skew(
+        intent.current_delta,
+        pending_delta,
+        intent.delta_limit,
+        intent.side,
+        intent.min_edge_usd,
+        intent.tick_size_usd,
+        &ctx.inventory_skew_config,
+    );
+    if !skew.allowed {
+        let reason = skew
+            .reject_reason
+            .unwrap_or_else(|| "InventorySkew".to_string());
+        return decision.reject(RiskReason::InventorySkew(reason), skew.risk_state);
+    }
+    let mut decision = RiskDecision {
+        adjusted_min_edge_usd: skew.adjusted_min_edge_usd,
+        bias_ticks: skew.bias_ticks,
+        ..decision
+    };
+    if let Some(adjusted_min_edge_usd) = skew.adjusted_min_edge_usd
+        && (adjusted_min_edge_usd != intent.min_edge_usd || skew.bias_ticks != 0)
+    {
+        decision.reasons.push(RiskReason::InventorySkewAdjusted {
+            adjusted_min_edge_usd,
+            bias_ticks: skew.bias_ticks,
+        });
+    }
+
+    if let GlobalBudgetResult::GlobalExposureBudgetExceeded {
+        portfolio_delta_after,
+        limit,
+        ..
+    } = ctx.global_budget.evaluate(
+        ctx.portfolio_exposures,
+        intent.instrument_id,
+        intent.delta_usd,
+    ) {
+        return decision.reject(
+            RiskReason::GlobalExposureBudgetExceeded {
+                portfolio_delta_after,
+                limit,
+            },
+            RiskState::Healthy,
+        );
+    }
+
+    if let ReserveResult::BudgetExceeded {
+        requested,
+        available,
+    } = ctx.pending_exposure.reserve(
+        intent.reservation_id.to_string(),
+        intent.instrument_id,
+        intent.delta_impact,
+        intent.current_delta,
+    ) {
+        return decision.reject(
+            RiskReason::PendingExposureBudgetExceeded {
+                requested,
+                available,
+            },
+            RiskState::Healthy,
+        );
+    }
+
+    decision
+}
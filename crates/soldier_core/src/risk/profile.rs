@@ -0,0 +1,65 @@
+//! Enforced contract profile (CONTRACT §0.Z.7).
+//!
+//! The profile arrives as a config string. Parsing is exact and case-sensitive; a typo must
+//! never silently fall back to CSP, which would switch off GOP enforcement (EvidenceGuard).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static ENFORCED_PROFILE_PARSE_ERROR_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnforcedProfile {
+    Csp,
+    Gop,
+    /// CSP + GOP; the most-enforced profile.
+    Full,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileParseError {
+    pub input: String,
+}
+
+impl EnforcedProfile {
+    /// Accepts exactly `CSP`, `GOP` or `FULL`.
+    pub fn parse(value: &str) -> Result<Self, ProfileParseError> {
+        match value {
+            "CSP" => Ok(EnforcedProfile::Csp),
+            "GOP" => Ok(EnforcedProfile::Gop),
+            "FULL" => Ok(EnforcedProfile::Full),
+            _ => Err(ProfileParseError {
+                input: value.to_string(),
+            }),
+        }
+    }
+
+    /// Boundary parse for status/policy wiring: an unparseable value fails closed to `Full`
+    /// and bumps `enforced_profile_parse_error_total`.
+    pub fn parse_fail_closed(value: &str) -> Self {
+        Self::parse(value).unwrap_or_else(|err| {
+            let total = ENFORCED_PROFILE_PARSE_ERROR_TOTAL.fetch_add(1, Ordering::Relaxed) + 1;
+            eprintln!(
+                "enforced_profile_parse_error_total={total} input={:?}",
+                err.input
+            );
+            EnforcedProfile::Full
+        })
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EnforcedProfile::Csp => "CSP",
+            EnforcedProfile::Gop => "GOP",
+            EnforcedProfile::Full => "FULL",
+        }
+    }
+
+    /// Whether GOP subsystems (EvidenceGuard, snapshots) are enforced.
+    pub fn enforces_gop(self) -> bool {
+        !matches!(self, EnforcedProfile::Csp)
+    }
+}
+
+pub fn enforced_profile_parse_error_total() -> u64 {
+    ENFORCED_PROFILE_PARSE_ERROR_TOTAL.load(Ordering::Relaxed)
+}
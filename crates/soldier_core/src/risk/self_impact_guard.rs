@@ -1,15 +1,15 @@
-use std::collections::HashMap;
+//! Self-Impact Feedback Loop Guard per CONTRACT.md §1.2.3
+//! Prevents the bot from reacting to its own impact (echo chamber)
+//!
+//! Rule: Stale trade feed => Degraded + latch blocks opens
+//! Rule: self_fraction/notional trip => reject with cooldown
+
+use std::collections::{HashMap, VecDeque};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 use super::RiskState;
 
-/// Self-Impact Feedback Loop Guard per CONTRACT.md §1.2.3
-/// Prevents the bot from reacting to its own impact (echo chamber)
-///
-/// Rule: Stale trade feed => Degraded + latch blocks opens
-/// Rule: self_fraction/notional trip => reject with cooldown
-
 const FLOAT_EPSILON: f64 = 1e-9;
 const MIN_PUBLIC_VOLUME_USD: f64 = 1000.0; // Minimum public volume for fraction calculation
 
@@ -67,9 +67,36 @@ struct CooldownEntry {
     blocked_until: Instant,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct TradeContribution {
+    ts_ms: u64,
+    notional_usd: f64,
+    is_self: bool,
+}
+
 struct SelfImpactGuardState {
     cooldown_map: HashMap<SelfImpactKey, CooldownEntry>,
     trip_counter: u64, // For self_impact_trip_total metric
+    trades: VecDeque<TradeContribution>,
+    last_trade_ts_ms: Option<u64>,
+}
+
+/// Latch implied by the trade feed alone: a missing or stale feed (older than
+/// `public_trade_feed_max_age_ms`) latches `WsTradesGapReconcileRequired`.
+pub fn trade_feed_latch(
+    aggregates: TradeAggregates,
+    now_ms: u64,
+    config: SelfImpactConfig,
+) -> Option<LatchReason> {
+    match aggregates.public_trades_last_update_ts_ms {
+        None => Some(LatchReason::WsTradesGapReconcileRequired),
+        Some(last_update_ts_ms)
+            if now_ms.saturating_sub(last_update_ts_ms) > config.public_trade_feed_max_age_ms =>
+        {
+            Some(LatchReason::WsTradesGapReconcileRequired)
+        }
+        Some(_) => None,
+    }
 }
 
 /// Thread-safety: All methods use interior mutability (Mutex) for safe concurrent access
@@ -83,6 +110,8 @@ impl SelfImpactGuard {
             state: Mutex::new(SelfImpactGuardState {
                 cooldown_map: HashMap::new(),
                 trip_counter: 0,
+                trades: VecDeque::new(),
+                last_trade_ts_ms: None,
             }),
         }
     }
@@ -98,13 +127,7 @@ impl SelfImpactGuard {
         now_instant: Instant,
         config: SelfImpactConfig,
     ) -> SelfImpactEvaluation {
-        let mut state = match self.state.lock() {
-            Ok(guard) => guard,
-            Err(poisoned) => {
-                eprintln!("self_impact_guard lock poisoned, recovering");
-                poisoned.into_inner()
-            }
-        };
+        let mut state = self.lock_state();
 
         // Prune expired cooldowns
         state
@@ -112,27 +135,13 @@ impl SelfImpactGuard {
             .retain(|_k, entry| now_instant < entry.blocked_until);
 
         // Step 1: Check trade feed freshness (CONTRACT.md §1.2.3 freshness precondition)
-        match aggregates.public_trades_last_update_ts_ms {
-            None => {
-                // Missing trade feed => Degraded + latch
-                return SelfImpactEvaluation {
-                    allowed: false,
-                    latch_reason: Some(LatchReason::WsTradesGapReconcileRequired),
-                    reject_reason: None,
-                    risk_state: RiskState::Degraded,
-                };
-            }
-            Some(last_update_ts_ms) => {
-                if now_ms.saturating_sub(last_update_ts_ms) > config.public_trade_feed_max_age_ms {
-                    // Stale trade feed => Degraded + latch
-                    return SelfImpactEvaluation {
-                        allowed: false,
-                        latch_reason: Some(LatchReason::WsTradesGapReconcileRequired),
-                        reject_reason: None,
-                        risk_state: RiskState::Degraded,
-                    };
-                }
-            }
+        if let Some(latch_reason) = trade_feed_latch(aggregates, now_ms, config) {
+            return SelfImpactEvaluation {
+                allowed: false,
+                latch_reason: Some(latch_reason),
+                reject_reason: None,
+                risk_state: RiskState::Degraded,
+            };
         }
 
         // Step 2: Feed is fresh, check if key is in cooldown
@@ -197,17 +206,76 @@ impl SelfImpactGuard {
         }
     }
 
+    /// Record a public trade print. `is_self` marks prints attributed to our own fills;
+    /// those count toward both public and self notional.
+    /// Thread-safe: uses interior mutability
+    pub fn record_trade(&self, ts_ms: u64, notional_usd: f64, is_self: bool) {
+        let mut state = self.lock_state();
+        if notional_usd.is_finite() && notional_usd > 0.0 {
+            state.trades.push_back(TradeContribution {
+                ts_ms,
+                notional_usd,
+                is_self,
+            });
+        }
+        state.last_trade_ts_ms = Some(state.last_trade_ts_ms.map_or(ts_ms, |ts| ts.max(ts_ms)));
+    }
+
+    /// Windowed aggregates over `feedback_loop_window_s` ending at `now_ms`.
+    /// Contributions older than the window are pruned.
+    /// Thread-safe: uses interior mutability
+    pub fn current_aggregates(&self, now_ms: u64, config: SelfImpactConfig) -> TradeAggregates {
+        let mut state = self.lock_state();
+        let window_ms = config.feedback_loop_window_s.saturating_mul(1000);
+        state
+            .trades
+            .retain(|trade| now_ms.saturating_sub(trade.ts_ms) < window_ms);
+
+        let mut aggregates = TradeAggregates {
+            public_notional_usd: 0.0,
+            self_notional_usd: 0.0,
+            public_trades_last_update_ts_ms: state.last_trade_ts_ms,
+        };
+        for trade in state.trades.iter().filter(|trade| trade.ts_ms <= now_ms) {
+            aggregates.public_notional_usd += trade.notional_usd;
+            if trade.is_self {
+                aggregates.self_notional_usd += trade.notional_usd;
+            }
+        }
+        aggregates
+    }
+
+    /// Keys still in feedback-loop cooldown at `now_instant`, sorted. Read-only: expired
+    /// entries are skipped, not pruned.
+    pub fn active_cooldown_keys(&self, now_instant: Instant) -> Vec<SelfImpactKey> {
+        let state = self.lock_state();
+        let mut keys: Vec<SelfImpactKey> = state
+            .cooldown_map
+            .iter()
+            .filter(|(_, entry)| now_instant < entry.blocked_until)
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.sort_by(|a, b| {
+            (&a.strategy_id, &a.structure_fingerprint)
+                .cmp(&(&b.strategy_id, &b.structure_fingerprint))
+        });
+        keys
+    }
+
     /// Get total trip count (for self_impact_trip_total metric)
     /// Thread-safe: uses interior mutability
     pub fn trip_count(&self) -> u64 {
-        let state = match self.state.lock() {
+        self.lock_state().trip_counter
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, SelfImpactGuardState> {
+        match self.state.lock() {
             Ok(guard) => guard,
             Err(poisoned) => {
                 eprintln!("self_impact_guard lock poisoned, recovering");
                 poisoned.into_inner()
             }
-        };
-        state.trip_counter
+        }
     }
 }
 
@@ -0,0 +1,98 @@
+//! Consolidated risk snapshot for `/status` and truth capsules.
+//!
+//! `RiskSnapshot::capture` only reads the guards in a `RiskContext`: no pruning, no latch
+//! transitions and no reservations, so capturing never changes a later `pre_dispatch_check`.
+
+use std::collections::BTreeMap;
+
+use super::{DeltaContracts, LatchReason, RiskContext, SelfImpactKey, trade_feed_latch};
+use crate::json::{json_number, json_string};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskSnapshot {
+    pub now_ms: u64,
+    /// Absolute pending delta per registered instrument
+    pub pending_deltas: BTreeMap<String, DeltaContracts>,
+    pub global_pending_delta: DeltaContracts,
+    /// ChurnBreaker keys still blacklisted at `now_ms`
+    pub churn_blocked_keys: usize,
+    pub churn_trip_count: u64,
+    /// Trade-feed latch as `SelfImpactGuard::evaluate_open` would see it
+    pub self_impact_latch: Option<LatchReason>,
+    /// Keys in feedback-loop cooldown at `now`, sorted
+    pub self_impact_cooldowns: Vec<SelfImpactKey>,
+    pub self_impact_trip_count: u64,
+    pub global_budget_breached: bool,
+    /// `GlobalExposureBudget::headroom_usd` on current + pending exposure
+    pub budget_headroom_usd: f64,
+}
+
+impl RiskSnapshot {
+    pub fn capture(ctx: &RiskContext<'_>) -> Self {
+        let churn = ctx.churn_breaker.export_state(ctx.now, ctx.now_ms);
+        Self {
+            now_ms: ctx.now_ms,
+            pending_deltas: ctx.pending_exposure.pending_deltas(),
+            global_pending_delta: ctx.pending_exposure.get_global_pending_delta(),
+            churn_blocked_keys: churn
+                .blocked_until_ms
+                .iter()
+                .filter(|(_, until_ms)| *until_ms > ctx.now_ms)
+                .count(),
+            churn_trip_count: churn.trip_count,
+            self_impact_latch: trade_feed_latch(
+                ctx.trade_aggregates,
+                ctx.now_ms,
+                ctx.self_impact_config,
+            ),
+            self_impact_cooldowns: ctx.self_impact_guard.active_cooldown_keys(ctx.now),
+            self_impact_trip_count: ctx.self_impact_guard.trip_count(),
+            global_budget_breached: ctx.global_budget.is_breached(),
+            budget_headroom_usd: ctx.global_budget.headroom_usd(ctx.portfolio_exposures),
+        }
+    }
+
+    /// Compact JSON with sorted keys; non-finite numbers encode as `null`.
+    pub fn to_json(&self) -> String {
+        let pending_deltas = self
+            .pending_deltas
+            .iter()
+            .map(|(instrument_id, delta)| {
+                format!("{}:{}", json_string(instrument_id), json_number(*delta))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let self_impact_cooldowns = self
+            .self_impact_cooldowns
+            .iter()
+            .map(|key| {
+                format!(
+                    "{{\"strategy_id\":{},\"structure_fingerprint\":{}}}",
+                    json_string(&key.strategy_id),
+                    json_string(&key.structure_fingerprint)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let self_impact_latch = match self.self_impact_latch {
+            Some(reason) => json_string(&format!("{reason:?}")),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\"budget_headroom_usd\":{},\"churn_blocked_keys\":{},\"churn_trip_count\":{},\
+             \"global_budget_breached\":{},\"global_pending_delta\":{},\"now_ms\":{},\
+             \"pending_deltas\":{{{}}},\"self_impact_cooldowns\":[{}],\"self_impact_latch\":{},\
+             \"self_impact_trip_count\":{}}}",
+            json_number(self.budget_headroom_usd),
+            self.churn_blocked_keys,
+            self.churn_trip_count,
+            self.global_budget_breached,
+            json_number(self.global_pending_delta),
+            self.now_ms,
+            pending_deltas,
+            self_impact_cooldowns,
+            self_impact_latch,
+            self.self_impact_trip_count
+        )
+    }
+}
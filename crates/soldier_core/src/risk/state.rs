@@ -36,6 +36,18 @@ impl TradingMode {
     pub fn is_trading_allowed(self) -> bool {
         self.allows_open()
     }
+
+    /// Whether an intent with this venue `reduce_only` flag may proceed. Per CONTRACT,
+    /// `reduce_only != Some(true)` classifies as OPEN, so ReduceOnly only permits
+    /// `Some(true)`; Kill permits nothing.
+    pub fn permits_intent(self, reduce_only: Option<bool>) -> bool {
+        let is_open = reduce_only != Some(true);
+        match self {
+            TradingMode::Active => true,
+            TradingMode::ReduceOnly => !is_open,
+            TradingMode::Kill => false,
+        }
+    }
 }
 
 pub struct PolicyGuard;
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::fmt;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
 use crate::risk::RiskState;
@@ -10,6 +11,7 @@ static INSTRUMENT_CACHE_HITS_TOTAL: AtomicU64 = AtomicU64::new(0);
 static INSTRUMENT_CACHE_AGE_MS: AtomicU64 = AtomicU64::new(0);
 static INSTRUMENT_CACHE_REFRESH_ERRORS_TOTAL: AtomicU64 = AtomicU64::new(0);
 static LAST_TTL_BREACH: Mutex<Option<InstrumentCacheTtlBreach>> = Mutex::new(None);
+static MONOTONIC_EPOCH: OnceLock<Instant> = OnceLock::new();
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct InstrumentCacheTtlBreach {
@@ -21,7 +23,7 @@ pub struct InstrumentCacheTtlBreach {
 #[derive(Debug, Clone)]
 struct InstrumentCacheEntry<T> {
     value: T,
-    updated_at: Instant,
+    updated_at_ms: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,10 +32,25 @@ pub struct CacheRead<'a, T> {
     pub risk_state: RiskState,
 }
 
-#[derive(Debug)]
+/// Invoked with the instrument id and new value when a cached entry is replaced by a
+/// different value via [`InstrumentCache::insert_notify_on_change`], so derived caches
+/// (e.g. quantization) can recompute.
+pub type RefreshCallback<T> = Box<dyn Fn(&str, &T) + Send + Sync>;
+
 pub struct InstrumentCache<T> {
     ttl: Duration,
     entries: HashMap<String, InstrumentCacheEntry<T>>,
+    on_refresh: Option<RefreshCallback<T>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for InstrumentCache<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstrumentCache")
+            .field("ttl", &self.ttl)
+            .field("entries", &self.entries)
+            .field("on_refresh", &self.on_refresh.is_some())
+            .finish()
+    }
 }
 
 impl<T> InstrumentCache<T> {
@@ -41,38 +58,47 @@ impl<T> InstrumentCache<T> {
         Self {
             ttl,
             entries: HashMap::new(),
+            on_refresh: None,
         }
     }
 
+    /// [`Self::insert_at`] on the process-monotonic clock read by [`Self::get`].
     pub fn insert(&mut self, instrument: impl Into<String>, metadata: T) {
-        self.insert_with_instant(instrument, metadata, Instant::now());
+        self.insert_at(instrument, metadata, monotonic_ms());
     }
 
-    pub fn insert_with_instant(
-        &mut self,
-        instrument: impl Into<String>,
-        metadata: T,
-        updated_at: Instant,
-    ) {
+    /// Stores `metadata` for `instrument` without consulting the refresh callback.
+    pub fn insert_at(&mut self, instrument: impl Into<String>, metadata: T, updated_at_ms: u64) {
         self.entries.insert(
             instrument.into(),
             InstrumentCacheEntry {
                 value: metadata,
-                updated_at,
+                updated_at_ms,
             },
         );
     }
 
+    /// Only [`Self::insert_notify_on_change`] and its `_at` variant invoke the callback.
+    pub fn set_on_refresh(&mut self, callback: RefreshCallback<T>) {
+        self.on_refresh = Some(callback);
+    }
+
+    /// [`Self::get_at`] on the process-monotonic clock shared with [`Self::insert`]; do not
+    /// mix with entries stamped by `insert_at` on another clock.
     pub fn get(&self, instrument: &str) -> Option<CacheRead<'_, T>> {
-        self.get_with_instant(instrument, Instant::now())
+        self.get_at(instrument, monotonic_ms())
     }
 
-    pub fn get_with_instant(&self, instrument: &str, now: Instant) -> Option<CacheRead<'_, T>> {
+    /// Reads `instrument` as of `now_ms`; only an age strictly beyond the TTL degrades. A
+    /// `now_ms` before the entry's stamp (clock stepped backwards) cannot prove freshness and
+    /// also degrades.
+    pub fn get_at(&self, instrument: &str, now_ms: u64) -> Option<CacheRead<'_, T>> {
         let entry = self.entries.get(instrument)?;
         INSTRUMENT_CACHE_HITS_TOTAL.fetch_add(1, Ordering::Relaxed);
-        let age = now.saturating_duration_since(entry.updated_at);
+        let age = Duration::from_millis(now_ms.saturating_sub(entry.updated_at_ms));
         INSTRUMENT_CACHE_AGE_MS.store(age.as_millis() as u64, Ordering::Relaxed);
-        if age > self.ttl {
+        let clock_went_backwards = now_ms < entry.updated_at_ms;
+        if clock_went_backwards || age > self.ttl {
             record_stale(instrument, age, self.ttl);
             Some(CacheRead {
                 metadata: &entry.value,
@@ -91,6 +117,40 @@ impl<T> InstrumentCache<T> {
     }
 }
 
+impl<T: PartialEq> InstrumentCache<T> {
+    /// [`Self::insert_notify_on_change_at`] on the process-monotonic clock read by [`Self::get`].
+    pub fn insert_notify_on_change(&mut self, instrument: impl Into<String>, metadata: T) {
+        self.insert_notify_on_change_at(instrument, metadata, monotonic_ms());
+    }
+
+    /// [`Self::insert_at`] that also fires the refresh callback when an existing entry is
+    /// replaced by a different value; first inserts and no-op refreshes stay silent.
+    pub fn insert_notify_on_change_at(
+        &mut self,
+        instrument: impl Into<String>,
+        metadata: T,
+        updated_at_ms: u64,
+    ) {
+        let instrument = instrument.into();
+        let changed = self
+            .entries
+            .get(&instrument)
+            .is_some_and(|entry| entry.value != metadata);
+        if changed && let Some(callback) = self.on_refresh.as_ref() {
+            callback(&instrument, &metadata);
+        }
+        self.insert_at(instrument, metadata, updated_at_ms);
+    }
+}
+
+/// Milliseconds since the first call in this process; immune to wall-clock steps.
+fn monotonic_ms() -> u64 {
+    MONOTONIC_EPOCH
+        .get_or_init(Instant::now)
+        .elapsed()
+        .as_millis() as u64
+}
+
 pub fn instrument_cache_stale_total() -> u64 {
     INSTRUMENT_CACHE_STALE_TOTAL.load(Ordering::Relaxed)
 }
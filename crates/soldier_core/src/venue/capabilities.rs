@@ -7,6 +7,14 @@ pub const ENABLE_LINKED_ORDERS_FOR_BOT: &str = "ENABLE_LINKED_ORDERS_FOR_BOT";
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct VenueCapabilities {
     pub linked_orders_supported: bool,
+    /// Venue hard limits; `None` means the venue imposes no limit we model.
+    pub max_orders_per_sec: Option<u32>,
+    pub max_cancel_batch: Option<u32>,
+    pub max_mass_quote_legs: Option<u32>,
+    /// Order types beyond plain limit; unsupported unless the venue says otherwise.
+    pub market_orders_supported: bool,
+    pub stop_market_orders_supported: bool,
+    pub stop_limit_orders_supported: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -37,6 +45,15 @@ impl VenueCapabilities {
             }
         }
     }
+
+    /// Effective per-tick cancel batch: the configured `cancel_open_batch_max`, capped by the
+    /// venue's `max_cancel_batch` when it is tighter.
+    pub fn cancel_batch_limit(self, cancel_open_batch_max: u32) -> u32 {
+        match self.max_cancel_batch {
+            Some(venue_max) => cancel_open_batch_max.min(venue_max),
+            None => cancel_open_batch_max,
+        }
+    }
 }
 
 fn env_flag_enabled(key: &str) -> bool {
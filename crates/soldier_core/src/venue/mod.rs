@@ -1,14 +1,18 @@
 pub mod cache;
 pub mod capabilities;
+pub mod symbol;
 pub mod types;
 
 pub use cache::{
-    CacheRead, InstrumentCache, InstrumentCacheTtlBreach, instrument_cache_age_s,
+    CacheRead, InstrumentCache, InstrumentCacheTtlBreach, RefreshCallback, instrument_cache_age_s,
     instrument_cache_hits_total, instrument_cache_refresh_errors_total,
     instrument_cache_stale_total, record_instrument_cache_refresh_error,
     take_instrument_cache_ttl_breach,
 };
 pub use capabilities::{ENABLE_LINKED_ORDERS_FOR_BOT, FeatureFlags, VenueCapabilities};
+pub use symbol::{
+    OptionType, ParsedSymbol, SymbolExpiry, SymbolKind, SymbolParseError, parse_deribit_symbol,
+};
 pub use types::{
     DeribitInstrumentKind, DeribitSettlementPeriod, InstrumentKind, InstrumentMetadata,
 };
@@ -0,0 +1,127 @@
+//! Deribit instrument-name parsing.
+//!
+//! Forms: `BTC-PERPETUAL`, `BTC_USDC-PERPETUAL`, `BTC-27JUN25` (dated future) and
+//! `BTC-27JUN25-80000-C` (option; fractional strikes use `d`, e.g. `0d625`).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolExpiry {
+    pub day: u8,
+    pub month: u8,
+    pub year: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolKind {
+    Perpetual,
+    Future {
+        expiry: SymbolExpiry,
+    },
+    Option {
+        expiry: SymbolExpiry,
+        strike: f64,
+        option_type: OptionType,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedSymbol {
+    pub base_currency: String,
+    /// Set for linear instruments (`BTC_USDC-...`); `None` for inverse.
+    pub quote_currency: Option<String>,
+    pub kind: SymbolKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolParseError {
+    MissingCurrency,
+    InvalidExpiry,
+    InvalidStrike,
+    InvalidOptionType,
+    UnexpectedSegmentCount,
+}
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+pub fn parse_deribit_symbol(symbol: &str) -> Result<ParsedSymbol, SymbolParseError> {
+    let segments: Vec<&str> = symbol.split('-').collect();
+    let (base_currency, quote_currency) = parse_currency(segments[0])?;
+
+    let kind = match segments.as_slice() {
+        [_, "PERPETUAL"] => SymbolKind::Perpetual,
+        [_, expiry] => SymbolKind::Future {
+            expiry: parse_expiry(expiry)?,
+        },
+        [_, expiry, strike, option_type] => SymbolKind::Option {
+            expiry: parse_expiry(expiry)?,
+            strike: parse_strike(strike)?,
+            option_type: match *option_type {
+                "C" => OptionType::Call,
+                "P" => OptionType::Put,
+                _ => return Err(SymbolParseError::InvalidOptionType),
+            },
+        },
+        _ => return Err(SymbolParseError::UnexpectedSegmentCount),
+    };
+
+    Ok(ParsedSymbol {
+        base_currency,
+        quote_currency,
+        kind,
+    })
+}
+
+fn parse_currency(segment: &str) -> Result<(String, Option<String>), SymbolParseError> {
+    let is_currency = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric());
+    match segment.split_once('_') {
+        Some((base, quote)) if is_currency(base) && is_currency(quote) => {
+            Ok((base.to_string(), Some(quote.to_string())))
+        }
+        None if is_currency(segment) => Ok((segment.to_string(), None)),
+        _ => Err(SymbolParseError::MissingCurrency),
+    }
+}
+
+/// `DMMMYY` or `DDMMMYY`, e.g. `5JUL24` / `27JUN25`.
+fn parse_expiry(segment: &str) -> Result<SymbolExpiry, SymbolParseError> {
+    if !segment.is_ascii() || !(6..=7).contains(&segment.len()) {
+        return Err(SymbolParseError::InvalidExpiry);
+    }
+    let (day, rest) = segment.split_at(segment.len() - 5);
+    let (month, year) = rest.split_at(3);
+    let day: u8 = day.parse().map_err(|_| SymbolParseError::InvalidExpiry)?;
+    let year: u16 = year.parse().map_err(|_| SymbolParseError::InvalidExpiry)?;
+    let month = MONTHS
+        .iter()
+        .position(|m| *m == month)
+        .ok_or(SymbolParseError::InvalidExpiry)?;
+    if !(1..=31).contains(&day) {
+        return Err(SymbolParseError::InvalidExpiry);
+    }
+    Ok(SymbolExpiry {
+        day,
+        month: month as u8 + 1,
+        year: 2000 + year,
+    })
+}
+
+fn parse_strike(segment: &str) -> Result<f64, SymbolParseError> {
+    if segment.is_empty() || !segment.chars().all(|c| c.is_ascii_digit() || c == 'd') {
+        return Err(SymbolParseError::InvalidStrike);
+    }
+    let strike: f64 = segment
+        .replacen('d', ".", 1)
+        .parse()
+        .map_err(|_| SymbolParseError::InvalidStrike)?;
+    if strike <= 0.0 {
+        return Err(SymbolParseError::InvalidStrike);
+    }
+    Ok(strike)
+}
@@ -13,6 +13,8 @@ pub struct InstrumentMetadata {
     pub amount_step: f64,
     pub min_amount: f64,
     pub contract_multiplier: f64,
+    /// Expiry/settlement time; `None` for perpetuals.
+    pub expiration_ts_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -74,6 +76,25 @@ impl InstrumentMetadata {
             amount_step,
             min_amount,
             contract_multiplier,
+            expiration_ts_ms: None,
+        }
+    }
+
+    pub fn with_expiration_ts_ms(self, expiration_ts_ms: u64) -> Self {
+        Self {
+            expiration_ts_ms: Some(expiration_ts_ms),
+            ..self
+        }
+    }
+
+    /// False once `now_ms` is within `pre_settlement_cutoff_ms` of expiry (or past it).
+    /// Instruments without an expiry are always tradable.
+    pub fn is_tradable_at(&self, now_ms: u64, pre_settlement_cutoff_ms: u64) -> bool {
+        match self.expiration_ts_ms {
+            Some(expiration_ts_ms) => {
+                now_ms.saturating_add(pre_settlement_cutoff_ms) < expiration_ts_ms
+            }
+            None => true,
         }
     }
 }
@@ -0,0 +1,23 @@
+use soldier_core::execution::{OrderIntent, OrderType};
+use soldier_core::venue::InstrumentKind;
+
+/// A plain perpetual `Limit` with every optional field unset; fixtures set only what the test
+/// needs.
+pub fn perp_limit_intent() -> OrderIntent {
+    OrderIntent {
+        instrument_kind: InstrumentKind::Perpetual,
+        order_type: OrderType::Limit,
+        trigger: None,
+        trigger_price: None,
+        linked_order_type: None,
+        notional: None,
+        side: None,
+        limit_price: None,
+        reduce_only: None,
+        instrument_tradable: None,
+        reference_price: None,
+        instrument_id: None,
+        open_order_count: None,
+        trigger_reference: None,
+    }
+}
@@ -1,8 +1,10 @@
 #[allow(dead_code)]
 fn emit_execution_metric_line(_metric_name: &str, _tail_fields: &str) {}
 
+#[allow(dead_code)]
 #[path = "../src/execution/atomic_group_executor.rs"]
 mod atomic_group_executor;
+#[allow(dead_code)]
 #[path = "../src/execution/group.rs"]
 mod group;
 
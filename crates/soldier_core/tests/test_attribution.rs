@@ -0,0 +1,91 @@
+use soldier_core::analytics::{Attribution, AttributionError};
+use soldier_core::execution::Side;
+
+const EPS: f64 = 1e-9;
+
+#[test]
+fn test_round_trip_realized_pnl_net_of_fees() {
+    let mut attribution = Attribution::new();
+    attribution
+        .record_fill("BTC-PERP", Side::Buy, 1.0, 100.0, 0.5)
+        .expect("buy fill");
+    attribution
+        .record_fill("BTC-PERP", Side::Buy, 1.0, 110.0, 0.5)
+        .expect("buy fill");
+    assert!((attribution.avg_price("BTC-PERP") - 105.0).abs() < EPS);
+
+    attribution
+        .record_fill("BTC-PERP", Side::Sell, 2.0, 120.0, 1.0)
+        .expect("sell fill");
+
+    // 2 * (120 - 105) = 30 gross, 2.0 fees
+    assert!((attribution.realized_pnl("BTC-PERP") - 30.0).abs() < EPS);
+    assert!((attribution.fees_paid("BTC-PERP") - 2.0).abs() < EPS);
+    let net = attribution.realized_pnl("BTC-PERP") - attribution.fees_paid("BTC-PERP");
+    assert!((net - 28.0).abs() < EPS);
+    assert_eq!(attribution.position("BTC-PERP"), 0.0);
+
+    // Other instruments are untouched
+    assert_eq!(attribution.realized_pnl("ETH-PERP"), 0.0);
+    assert_eq!(attribution.fees_paid("ETH-PERP"), 0.0);
+}
+
+#[test]
+fn test_position_flip_through_zero() {
+    let mut attribution = Attribution::new();
+    attribution
+        .record_fill("ETH-PERP", Side::Buy, 2.0, 100.0, 0.0)
+        .expect("open long");
+
+    // Sell 5: closes 2 long at +10 each, opens 3 short at 110
+    attribution
+        .record_fill("ETH-PERP", Side::Sell, 5.0, 110.0, 0.0)
+        .expect("flip to short");
+    assert!((attribution.realized_pnl("ETH-PERP") - 20.0).abs() < EPS);
+    assert!((attribution.position("ETH-PERP") + 3.0).abs() < EPS);
+    assert!((attribution.avg_price("ETH-PERP") - 110.0).abs() < EPS);
+
+    // Buy back 3 at 100: short gains 3 * 10
+    attribution
+        .record_fill("ETH-PERP", Side::Buy, 3.0, 100.0, 0.0)
+        .expect("cover short");
+    assert!((attribution.realized_pnl("ETH-PERP") - 50.0).abs() < EPS);
+    assert_eq!(attribution.position("ETH-PERP"), 0.0);
+}
+
+#[test]
+fn test_float_residue_close_leaves_position_flat() {
+    let mut attribution = Attribution::new();
+    attribution
+        .record_fill("BTC-PERP", Side::Buy, 0.1, 100.0, 0.0)
+        .expect("buy fill");
+    attribution
+        .record_fill("BTC-PERP", Side::Buy, 0.2, 100.0, 0.0)
+        .expect("buy fill");
+
+    // 0.1 + 0.2 != 0.3 in f64; closing 0.3 must still read flat
+    attribution
+        .record_fill("BTC-PERP", Side::Sell, 0.3, 110.0, 0.0)
+        .expect("close fill");
+    assert_eq!(attribution.position("BTC-PERP"), 0.0);
+    assert_eq!(attribution.avg_price("BTC-PERP"), 0.0);
+    assert!((attribution.realized_pnl("BTC-PERP") - 3.0).abs() < EPS);
+
+    // Reopening starts a fresh average rather than blending with residue
+    attribution
+        .record_fill("BTC-PERP", Side::Sell, 1.0, 120.0, 0.0)
+        .expect("open short");
+    assert!((attribution.position("BTC-PERP") + 1.0).abs() < EPS);
+    assert!((attribution.avg_price("BTC-PERP") - 120.0).abs() < EPS);
+    assert!((attribution.realized_pnl("BTC-PERP") - 3.0).abs() < EPS);
+}
+
+#[test]
+fn test_invalid_fill_rejected() {
+    let mut attribution = Attribution::new();
+    let err = attribution
+        .record_fill("BTC-PERP", Side::Buy, f64::NAN, 100.0, 0.0)
+        .expect_err("NaN qty must reject");
+    assert_eq!(err, AttributionError::InvalidFill);
+    assert_eq!(attribution.position("BTC-PERP"), 0.0);
+}
@@ -24,6 +24,7 @@ fn test_oco_not_supported() {
 fn test_oco_supported_when_flags_enabled() {
     let capabilities = VenueCapabilities {
         linked_orders_supported: true,
+        ..VenueCapabilities::default()
     };
     let flags = FeatureFlags {
         enable_linked_orders_for_bot: true,
@@ -46,3 +47,19 @@ fn test_oco_supported_when_flags_enabled() {
         "options never support linked orders",
     );
 }
+
+#[test]
+fn test_cancel_batch_respects_tighter_venue_cap() {
+    let capabilities = VenueCapabilities {
+        max_cancel_batch: Some(20),
+        ..VenueCapabilities::default()
+    };
+
+    assert_eq!(capabilities.cancel_batch_limit(50), 20);
+    assert_eq!(capabilities.cancel_batch_limit(10), 10);
+}
+
+#[test]
+fn test_cancel_batch_uses_config_without_venue_cap() {
+    assert_eq!(VenueCapabilities::default().cancel_batch_limit(50), 50);
+}
@@ -1,4 +1,6 @@
-use soldier_core::risk::churn_breaker::{ChurnBreaker, ChurnBreakerDecision, ChurnKey};
+use soldier_core::risk::churn_breaker::{
+    ChurnBreaker, ChurnBreakerDecision, ChurnBreakerSnapshot, ChurnKey,
+};
 use std::time::{Duration, Instant};
 
 fn test_key(strategy_id: &str, fingerprint: &str) -> ChurnKey {
@@ -12,7 +14,7 @@ fn test_key(strategy_id: &str, fingerprint: &str) -> ChurnKey {
 #[test]
 fn test_churn_breaker_blacklists_after_three_flattens_in_5m() {
     // GIVEN: 3 EmergencyFlattenGroup triggers for the same key within 5 minutes
-    let mut breaker = ChurnBreaker::new();
+    let breaker = ChurnBreaker::new();
     let key = test_key("delta_neutral_btc", "BTC-PERP-delta0.5-legs2");
     let now = Instant::now();
 
@@ -25,7 +27,9 @@ fn test_churn_breaker_blacklists_after_three_flattens_in_5m() {
 
     // THEN: the 4th attempt is rejected and logged (ChurnBreakerTrip), with blacklist TTL enforced
     match decision {
-        ChurnBreakerDecision::Reject { reason, trip_count } => {
+        ChurnBreakerDecision::Reject {
+            reason, trip_count, ..
+        } => {
             assert!(
                 reason.contains("ChurnBreakerActive"),
                 "Expected ChurnBreakerActive in reason, got: {}",
@@ -41,7 +45,7 @@ fn test_churn_breaker_blacklists_after_three_flattens_in_5m() {
 #[test]
 fn test_churn_breaker_enforces_15m_ttl() {
     // GIVEN: 3 flattens triggering blacklist
-    let mut breaker = ChurnBreaker::new();
+    let breaker = ChurnBreaker::new();
     let key = test_key("strategy1", "fingerprint1");
     let now = Instant::now();
 
@@ -72,7 +76,7 @@ fn test_churn_breaker_enforces_15m_ttl() {
 #[test]
 fn test_churn_breaker_allows_opens_when_inactive() {
     // GIVEN: churn breaker inactive (no flattens recorded)
-    let mut breaker = ChurnBreaker::new();
+    let breaker = ChurnBreaker::new();
     let key = test_key("strategy1", "fingerprint1");
     let now = Instant::now();
 
@@ -87,7 +91,7 @@ fn test_churn_breaker_allows_opens_when_inactive() {
 #[test]
 fn test_churn_breaker_blocks_opens_for_blacklisted_keys() {
     // GIVEN: blacklist active (3 flattens)
-    let mut breaker = ChurnBreaker::new();
+    let breaker = ChurnBreaker::new();
     let key = test_key("strategy1", "fingerprint1");
     let now = Instant::now();
 
@@ -106,7 +110,7 @@ fn test_churn_breaker_blocks_opens_for_blacklisted_keys() {
 #[test]
 fn test_churn_breaker_prunes_old_events() {
     // GIVEN: 2 flattens within window, 1 outside
-    let mut breaker = ChurnBreaker::new();
+    let breaker = ChurnBreaker::new();
     let key = test_key("strategy1", "fingerprint1");
     let now = Instant::now();
 
@@ -127,7 +131,7 @@ fn test_churn_breaker_prunes_old_events() {
 #[test]
 fn test_churn_breaker_isolates_keys() {
     // GIVEN: key1 trips, key2 doesn't
-    let mut breaker = ChurnBreaker::new();
+    let breaker = ChurnBreaker::new();
     let key1 = test_key("strat1", "BTC-PERP");
     let key2 = test_key("strat2", "ETH-PERP");
     let now = Instant::now();
@@ -150,7 +154,7 @@ fn test_churn_breaker_isolates_keys() {
 #[test]
 fn test_churn_breaker_trip_counter() {
     // GIVEN: multiple trips across different keys
-    let mut breaker = ChurnBreaker::new();
+    let breaker = ChurnBreaker::new();
     let key1 = test_key("strat1", "BTC-PERP");
     let key2 = test_key("strat2", "ETH-PERP");
     let now = Instant::now();
@@ -169,3 +173,122 @@ fn test_churn_breaker_trip_counter() {
     breaker.record_flatten(key2.clone(), now + Duration::from_secs(300));
     assert_eq!(breaker.trip_count(), 2);
 }
+
+/// Reject exposes retry_after_ms so callers can schedule re-quotes instead of spinning
+#[test]
+fn test_churn_breaker_reports_retry_after_ms() {
+    let breaker = ChurnBreaker::new();
+    let key = test_key("strategy1", "fingerprint1");
+    let now = Instant::now();
+
+    breaker.record_flatten(key.clone(), now);
+    breaker.record_flatten(key.clone(), now + Duration::from_secs(60));
+    // Third flatten at 2m trips the breaker: blocked until 17m
+    let trip_at = now + Duration::from_secs(120);
+    breaker.record_flatten(key.clone(), trip_at);
+
+    let decision = breaker.evaluate_open(&key, trip_at);
+    match decision {
+        ChurnBreakerDecision::Reject { retry_after_ms, .. } => {
+            assert_eq!(retry_after_ms, Some(15 * 60 * 1000));
+        }
+        _ => panic!("Expected Reject, got {:?}", decision),
+    }
+
+    let decision =
+        breaker.evaluate_open(&key, trip_at + Duration::from_millis(10 * 60 * 1000 + 250));
+    assert_eq!(decision.retry_after_ms(), Some(5 * 60 * 1000 - 250));
+
+    // Just after the blacklist clears: allowed with no retry hint
+    let decision = breaker.evaluate_open(
+        &key,
+        trip_at + Duration::from_secs(15 * 60) + Duration::from_millis(1),
+    );
+    assert_eq!(decision, ChurnBreakerDecision::Allow);
+    assert_eq!(decision.retry_after_ms(), None);
+}
+
+#[test]
+fn test_churn_breaker_state_survives_restart_within_window() {
+    let key = test_key("delta_neutral_btc", "BTC-PERP-delta0.5-legs2");
+    let start_ms = 1_700_000_000_000u64;
+    let before_restart = ChurnBreaker::new();
+    let t0 = Instant::now();
+    before_restart.record_flatten(key.clone(), t0);
+    before_restart.record_flatten(key.clone(), t0 + Duration::from_secs(240));
+
+    // Exported mid-window, 4m after the first flatten.
+    let snapshot = before_restart.export_state(t0 + Duration::from_secs(240), start_ms + 240_000);
+    assert_eq!(
+        snapshot,
+        ChurnBreakerSnapshot {
+            flatten_ts_ms: vec![(key.clone(), vec![start_ms, start_ms + 240_000])],
+            blocked_until_ms: vec![],
+            trip_count: 0,
+        }
+    );
+
+    // Restart at 6m: the first flatten has aged out, the second still counts.
+    let after_restart = ChurnBreaker::new();
+    let restart = Instant::now();
+    after_restart.import_state(&snapshot, restart, start_ms + 360_000);
+    let restored = after_restart.export_state(restart, start_ms + 360_000);
+    assert_eq!(
+        restored.flatten_ts_ms,
+        vec![(key.clone(), vec![start_ms + 240_000])]
+    );
+
+    after_restart.record_flatten(key.clone(), restart + Duration::from_secs(10));
+    assert_eq!(
+        after_restart.evaluate_open(&key, restart + Duration::from_secs(11)),
+        ChurnBreakerDecision::Allow
+    );
+    after_restart.record_flatten(key.clone(), restart + Duration::from_secs(20));
+    assert!(matches!(
+        after_restart.evaluate_open(&key, restart + Duration::from_secs(21)),
+        ChurnBreakerDecision::Reject { .. }
+    ));
+}
+
+/// Earliest `Instant` the platform can represent from here: a stand-in for a process that
+/// restarted moments after boot, where backdating by any in-window age underflows.
+fn earliest_instant() -> Instant {
+    let base = Instant::now();
+    let (mut lo, mut hi) = (0u64, u64::MAX);
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        if base.checked_sub(Duration::from_secs(mid)).is_some() {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    base - Duration::from_secs(lo)
+}
+
+#[test]
+fn test_churn_breaker_import_just_after_boot_keeps_window() {
+    let key = test_key("delta_neutral_btc", "BTC-PERP-delta0.5-legs2");
+    let now_ms = 1_700_000_000_000u64;
+    let snapshot = ChurnBreakerSnapshot {
+        // One flatten a minute ago, one future-dated by wall-clock skew.
+        flatten_ts_ms: vec![(key.clone(), vec![now_ms - 60_000, now_ms + 5_000])],
+        blocked_until_ms: vec![],
+        trip_count: 0,
+    };
+
+    let boot = earliest_instant();
+    let breaker = ChurnBreaker::new();
+    breaker.import_state(&snapshot, boot, now_ms);
+    assert_eq!(
+        breaker.export_state(boot, now_ms).flatten_ts_ms,
+        vec![(key.clone(), vec![now_ms, now_ms])]
+    );
+
+    // Both restored flattens count: the next one trips the breaker.
+    breaker.record_flatten(key.clone(), boot + Duration::from_secs(1));
+    assert!(matches!(
+        breaker.evaluate_open(&key, boot + Duration::from_secs(2)),
+        ChurnBreakerDecision::Reject { .. }
+    ));
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use soldier_core::execution::{
+    ClampPolicy, ClampRejectReason, IntentNotional, OrderIntent, Side, clamp_intent_size,
+};
+
+fn sized_intent(qty: f64) -> OrderIntent {
+    OrderIntent {
+        notional: Some(IntentNotional {
+            qty,
+            price: 100.0,
+            contract_multiplier: 1.0,
+        }),
+        side: Some(Side::Buy),
+        limit_price: Some(100.0),
+        ..common::perp_limit_intent()
+    }
+}
+
+#[test]
+fn test_clamp_intent_size_within_limits_is_unchanged() {
+    let intent = sized_intent(5.0);
+    let clamped = clamp_intent_size(intent.clone(), 10.0, 8.0, ClampPolicy::RejectOnExceed)
+        .expect("within limits");
+    assert_eq!(clamped, intent);
+}
+
+#[test]
+fn test_clamp_intent_size_reduces_to_tightest_limit() {
+    let clamped =
+        clamp_intent_size(sized_intent(12.0), 10.0, 8.0, ClampPolicy::ClampToMax).expect("clamped");
+    assert_eq!(clamped.notional.map(|n| n.qty), Some(8.0));
+
+    let clamped =
+        clamp_intent_size(sized_intent(12.0), 6.0, 8.0, ClampPolicy::ClampToMax).expect("clamped");
+    assert_eq!(clamped.notional.map(|n| n.qty), Some(6.0));
+
+    let err = clamp_intent_size(sized_intent(12.0), 10.0, 0.0, ClampPolicy::ClampToMax)
+        .expect_err("no budget left");
+    assert_eq!(err.reason, ClampRejectReason::ClampedToZero);
+}
+
+#[test]
+fn test_clamp_intent_size_rejects_on_exceed_policy() {
+    let err = clamp_intent_size(sized_intent(12.0), 10.0, 20.0, ClampPolicy::RejectOnExceed)
+        .expect_err("exceeds venue max");
+    assert_eq!(err.reason, ClampRejectReason::ExceedsVenueMax);
+    assert_eq!(err.requested_qty, Some(12.0));
+    assert_eq!(err.max_allowed_qty, 10.0);
+
+    let err = clamp_intent_size(sized_intent(12.0), 20.0, 10.0, ClampPolicy::RejectOnExceed)
+        .expect_err("exceeds budget");
+    assert_eq!(err.reason, ClampRejectReason::ExceedsBudget);
+}
@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+use soldier_core::analytics::{DecisionDiff, DecisionDiffKind, TruthCapsule, replay_decisions};
+use soldier_core::risk::TradingMode;
+
+fn capsule(id: &str, risk_state: Option<&str>, trading_mode: Option<&str>) -> TruthCapsule {
+    TruthCapsule {
+        decision_snapshot_id: id.to_string(),
+        ts_ms: 1_000,
+        inputs: risk_state
+            .map(|state| BTreeMap::from([("risk_state".to_string(), state.to_string())]))
+            .unwrap_or_default(),
+        outputs: trading_mode
+            .map(|mode| BTreeMap::from([("trading_mode".to_string(), mode.to_string())]))
+            .unwrap_or_default(),
+    }
+}
+
+#[test]
+fn test_replay_decisions_identical_capsules_produce_no_diffs() {
+    let capsules = vec![
+        capsule("snap-1", Some("Healthy"), Some("Active")),
+        capsule("snap-2", Some("Degraded"), Some("ReduceOnly")),
+        capsule("snap-3", Some("Maintenance"), Some("ReduceOnly")),
+        capsule("snap-4", Some("Kill"), Some("Kill")),
+        // No recorded mode: not a mode decision, nothing to compare.
+        capsule("snap-5", Some("Healthy"), None),
+    ];
+    assert!(replay_decisions(&capsules).is_empty());
+}
+
+#[test]
+fn test_replay_decisions_flags_wrong_recorded_mode() {
+    let capsules = vec![
+        capsule("snap-1", Some("Healthy"), Some("Active")),
+        capsule("snap-2", Some("Degraded"), Some("Active")),
+        capsule("snap-3", None, Some("Active")),
+        capsule("snap-4", Some("Unknown"), Some("Active")),
+    ];
+    assert_eq!(
+        replay_decisions(&capsules),
+        vec![
+            DecisionDiff {
+                decision_snapshot_id: "snap-2".to_string(),
+                ts_ms: 1_000,
+                kind: DecisionDiffKind::ModeMismatch {
+                    recorded: "Active".to_string(),
+                    recomputed: TradingMode::ReduceOnly,
+                },
+            },
+            DecisionDiff {
+                decision_snapshot_id: "snap-3".to_string(),
+                ts_ms: 1_000,
+                kind: DecisionDiffKind::Unreplayable {
+                    input: "risk_state"
+                },
+            },
+            DecisionDiff {
+                decision_snapshot_id: "snap-4".to_string(),
+                ts_ms: 1_000,
+                kind: DecisionDiffKind::Unreplayable {
+                    input: "risk_state"
+                },
+            },
+        ]
+    );
+}
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use soldier_core::analytics::{
+    DecisionSnapshot, DecisionSnapshotError, DecisionSnapshotWriter, FileDecisionSnapshotWriter,
+    InMemoryDecisionSnapshotWriter, coverage_pct,
+};
+use soldier_core::execution::{L2BookLevel, L2BookSnapshot};
+
+fn temp_snapshot_path(test_name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock")
+        .as_nanos();
+    path.push(format!(
+        "soldier_core_{}_{}_{}.snap",
+        test_name,
+        std::process::id(),
+        nanos
+    ));
+    path
+}
+
+fn snapshot(id: &str) -> DecisionSnapshot {
+    let book = L2BookSnapshot {
+        bids: vec![
+            L2BookLevel {
+                price: 100.0,
+                qty: 1.0,
+            },
+            L2BookLevel {
+                price: 99.5,
+                qty: 2.0,
+            },
+            L2BookLevel {
+                price: 99.0,
+                qty: 3.0,
+            },
+        ],
+        asks: vec![L2BookLevel {
+            price: 100.5,
+            qty: 4.0,
+        }],
+        ts_ms: 1_000,
+    };
+    DecisionSnapshot::from_book(id, "BTC-PERP", &book, 2)
+}
+
+#[test]
+fn test_decision_snapshot_successful_writes_keep_error_counter_at_zero() {
+    let memory = InMemoryDecisionSnapshotWriter::new();
+    memory.write(&snapshot("snap-1")).expect("memory write");
+    memory.write(&snapshot("snap-2")).expect("memory write");
+    assert_eq!(memory.write_errors(), 0);
+    assert_eq!(memory.snapshots().len(), 2);
+    assert_eq!(memory.snapshots()[0].bids.len(), 2, "top-N truncation");
+
+    let file = FileDecisionSnapshotWriter::new(temp_snapshot_path("ok"));
+    file.write(&snapshot("snap-1")).expect("file write");
+    file.write(&snapshot("snap-2")).expect("file write");
+    assert_eq!(file.write_errors(), 0);
+    let contents = std::fs::read_to_string(file.path()).expect("read snapshots");
+    assert_eq!(
+        contents.lines().collect::<Vec<_>>(),
+        vec![
+            "snap-1|BTC-PERP|1000|100@1,99.5@2|100.5@4",
+            "snap-2|BTC-PERP|1000|100@1,99.5@2|100.5@4",
+        ]
+    );
+}
+
+#[test]
+fn test_decision_snapshot_failing_sink_increments_write_errors() {
+    let memory = InMemoryDecisionSnapshotWriter::with_capacity(1);
+    memory.write(&snapshot("snap-1")).expect("first write fits");
+    assert_eq!(
+        memory.write(&snapshot("snap-2")),
+        Err(DecisionSnapshotError::SinkFull)
+    );
+    assert_eq!(memory.write_errors(), 1);
+    assert_eq!(memory.snapshots().len(), 1);
+
+    // A directory cannot be opened for append.
+    let file = FileDecisionSnapshotWriter::new(std::env::temp_dir());
+    assert!(matches!(
+        file.write(&snapshot("snap-1")),
+        Err(DecisionSnapshotError::Io(_))
+    ));
+    assert_eq!(file.write_errors(), 1);
+}
+
+const HOUR_MS: u64 = 3_600_000;
+const INTERVAL_MS: u64 = 60_000;
+
+#[test]
+fn test_coverage_pct_full_window_is_one() {
+    let now_ms = 10 * HOUR_MS;
+    let snapshots: Vec<u64> = (0..60).map(|i| now_ms - i * INTERVAL_MS).collect();
+    assert!((coverage_pct(&snapshots, 1, now_ms, INTERVAL_MS) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_coverage_pct_half_gap_is_half() {
+    let now_ms = 10 * HOUR_MS;
+    // Only the most recent 30 minutes are covered, with a burst of duplicates per slot.
+    let snapshots: Vec<u64> = (0..30)
+        .flat_map(|i| {
+            let ts = now_ms - i * INTERVAL_MS;
+            [ts, ts - 1, ts - 2]
+        })
+        .collect();
+    assert!((coverage_pct(&snapshots, 1, now_ms, INTERVAL_MS) - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_coverage_pct_empty_window_is_zero() {
+    let now_ms = 10 * HOUR_MS;
+    assert_eq!(coverage_pct(&[], 1, now_ms, INTERVAL_MS), 0.0);
+    // Snapshots outside the window (too old or in the future) do not count.
+    let outside = [now_ms - 2 * HOUR_MS, now_ms + INTERVAL_MS];
+    assert_eq!(coverage_pct(&outside, 1, now_ms, INTERVAL_MS), 0.0);
+    assert_eq!(coverage_pct(&[now_ms], 1, now_ms, 0), 0.0);
+}
@@ -1,7 +1,7 @@
 use soldier_core::execution::{
-    DispatchMetrics, IntentClassification, OrderSize, RejectReason,
+    DispatchMetrics, IntentClassification, OrderSize, RejectReason, Side,
     map_order_size_to_deribit_amount, map_order_size_to_deribit_amount_with_metrics,
-    reduce_only_from_intent_classification,
+    reduce_only_from_intent_and_position, reduce_only_from_intent_classification,
 };
 use soldier_core::risk::RiskState;
 use soldier_core::venue::InstrumentKind;
@@ -163,6 +163,31 @@ fn test_reduce_only_flag_set_by_intent_classification() {
     );
 }
 
+#[test]
+fn test_reduce_only_requires_intent_to_shrink_position() {
+    let close = IntentClassification::Close;
+    assert_eq!(
+        reduce_only_from_intent_and_position(close, Side::Sell, 2.0),
+        Some(true)
+    );
+    assert_eq!(
+        reduce_only_from_intent_and_position(close, Side::Buy, -2.0),
+        Some(true)
+    );
+    assert_eq!(
+        reduce_only_from_intent_and_position(close, Side::Buy, 2.0),
+        None
+    );
+    assert_eq!(
+        reduce_only_from_intent_and_position(close, Side::Sell, 0.0),
+        None
+    );
+    assert_eq!(
+        reduce_only_from_intent_and_position(IntentClassification::Open, Side::Sell, 2.0),
+        None
+    );
+}
+
 #[test]
 fn derives_contracts_when_missing_in_order_size() {
     let index_price = 50_000.0;
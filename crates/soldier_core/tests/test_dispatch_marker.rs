@@ -0,0 +1,59 @@
+use soldier_core::idempotency::{
+    DispatchBeginError, DispatchConfirmError, DispatchMarker, dispatch_tokens_reclaimed_total,
+};
+
+const TTL_MS: u64 = 5_000;
+const RETENTION_MS: u64 = 60_000;
+
+#[test]
+fn test_dispatch_marker_begin_confirm_happy_path() {
+    let marker = DispatchMarker::new(TTL_MS, RETENTION_MS);
+    let token = marker.begin(42, 1_000).expect("begin");
+    assert_eq!(token.key, 42);
+    marker.confirm(&token, 1_200).expect("confirm");
+
+    // A late retry of a confirmed dispatch must not re-send.
+    assert_eq!(
+        marker.begin(42, 2_000),
+        Err(DispatchBeginError::AlreadyConfirmed {
+            confirmed_ts_ms: 1_200
+        })
+    );
+    // Other keys are independent.
+    assert!(marker.begin(43, 2_000).is_ok());
+}
+
+#[test]
+fn test_dispatch_marker_second_begin_before_confirm_is_in_flight() {
+    let marker = DispatchMarker::new(TTL_MS, RETENTION_MS);
+    let token = marker.begin(7, 1_000).expect("begin");
+    assert_eq!(
+        marker.begin(7, 1_000 + TTL_MS - 1),
+        Err(DispatchBeginError::AlreadyInFlight {
+            started_ts_ms: 1_000
+        })
+    );
+    marker
+        .confirm(&token, 2_000)
+        .expect("original attempt still confirms");
+}
+
+#[test]
+fn test_dispatch_marker_reclaims_stale_token() {
+    let marker = DispatchMarker::new(TTL_MS, RETENTION_MS);
+    let stale = marker.begin(9, 1_000).expect("begin");
+
+    let before = dispatch_tokens_reclaimed_total();
+    let fresh = marker
+        .begin(9, 1_000 + TTL_MS)
+        .expect("stale attempt reclaimed");
+    assert!(dispatch_tokens_reclaimed_total() > before);
+    assert_ne!(fresh, stale);
+
+    // The reclaimed token can no longer finalize the key.
+    assert_eq!(
+        marker.confirm(&stale, 7_000),
+        Err(DispatchConfirmError::StaleToken)
+    );
+    marker.confirm(&fresh, 7_000).expect("fresh token confirms");
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use soldier_core::execution::{
+    BuildOrderIntentContext, BuildOrderIntentOutcome, BuildOrderIntentRejectReason,
+    InstrumentQuantization, IntentClassification, L2BookLevel, L2BookSnapshot, LiquidityGateConfig,
+    LiquidityGateRejectReason, NetEdgeRejectReason, OrderTypeGuardConfig, OrderTypeRejectReason,
+    QuantizeRejectReason, RecordIntentOutcome, Side, build_order_intent_dry_run,
+    expected_slippage_bps_samples, liquidity_gate_reject_total, net_edge_reject_total,
+    preflight_reject_total, quantization_reject_too_small_total,
+};
+use soldier_core::risk::{FeeModelSnapshot, FeeStalenessConfig, RiskState};
+
+// Every test in this binary reads process-wide counters, so it stays the only one here.
+
+fn open_context() -> BuildOrderIntentContext {
+    let now_ms = 1_000;
+    BuildOrderIntentContext {
+        classification: IntentClassification::Open,
+        side: Side::Buy,
+        raw_qty: 1.2,
+        raw_limit_price: 100.1,
+        quantization: InstrumentQuantization {
+            tick_size: 0.5,
+            amount_step: 0.1,
+            min_amount: 0.1,
+        },
+        fee_model: FeeModelSnapshot {
+            fee_tier: 1,
+            maker_fee_rate: 0.0002,
+            taker_fee_rate: 0.0005,
+            settlement_fee_bps: None,
+            fee_model_cached_at_ts_ms: Some(now_ms),
+        },
+        fee_staleness_config: FeeStalenessConfig::default(),
+        is_maker: false,
+        l2_snapshot: Some(L2BookSnapshot {
+            bids: vec![L2BookLevel {
+                price: 99.5,
+                qty: 10.0,
+            }],
+            asks: vec![L2BookLevel {
+                price: 100.0,
+                qty: 10.0,
+            }],
+            ts_ms: now_ms,
+        }),
+        liquidity_config: LiquidityGateConfig::default(),
+        now_ms,
+        gross_edge_usd: 10.0,
+        min_edge_usd: 1.0,
+        fair_price: 100.0,
+        risk_state: RiskState::Healthy,
+        record_outcome: RecordIntentOutcome::Recorded,
+        observers: None,
+    }
+}
+
+fn gate_counters() -> Vec<u64> {
+    vec![
+        preflight_reject_total(OrderTypeRejectReason::NotionalMissing),
+        quantization_reject_too_small_total(),
+        liquidity_gate_reject_total(LiquidityGateRejectReason::LiquidityGateNoL2),
+        liquidity_gate_reject_total(LiquidityGateRejectReason::ExpectedSlippageTooHigh),
+        expected_slippage_bps_samples(),
+        net_edge_reject_total(NetEdgeRejectReason::NetEdgeTooLow),
+        net_edge_reject_total(NetEdgeRejectReason::NetEdgeInputMissing),
+    ]
+}
+
+#[test]
+fn test_dry_run_leaves_gate_counters_unchanged() {
+    let before = gate_counters();
+    let intent = common::perp_limit_intent();
+
+    let notional_bound = OrderTypeGuardConfig {
+        min_notional_usd: Some(1.0),
+        ..OrderTypeGuardConfig::default()
+    };
+    assert_eq!(
+        build_order_intent_dry_run(&open_context(), intent.clone(), notional_bound),
+        BuildOrderIntentOutcome::Rejected(BuildOrderIntentRejectReason::Preflight(
+            OrderTypeRejectReason::NotionalMissing
+        ))
+    );
+
+    let too_small = BuildOrderIntentContext {
+        raw_qty: 0.05,
+        ..open_context()
+    };
+    assert_eq!(
+        build_order_intent_dry_run(&too_small, intent.clone(), OrderTypeGuardConfig::default()),
+        BuildOrderIntentOutcome::Rejected(BuildOrderIntentRejectReason::Quantize(
+            QuantizeRejectReason::TooSmallAfterQuantization
+        ))
+    );
+
+    let no_l2 = BuildOrderIntentContext {
+        l2_snapshot: None,
+        ..open_context()
+    };
+    assert_eq!(
+        build_order_intent_dry_run(&no_l2, intent.clone(), OrderTypeGuardConfig::default()),
+        BuildOrderIntentOutcome::Rejected(BuildOrderIntentRejectReason::LiquidityGate(
+            LiquidityGateRejectReason::LiquidityGateNoL2
+        ))
+    );
+
+    let thin_edge = BuildOrderIntentContext {
+        gross_edge_usd: 0.5,
+        ..open_context()
+    };
+    assert_eq!(
+        build_order_intent_dry_run(&thin_edge, intent.clone(), OrderTypeGuardConfig::default()),
+        BuildOrderIntentOutcome::Rejected(BuildOrderIntentRejectReason::NetEdge(
+            NetEdgeRejectReason::NetEdgeTooLow
+        ))
+    );
+
+    assert_eq!(
+        build_order_intent_dry_run(&open_context(), intent, OrderTypeGuardConfig::default()),
+        BuildOrderIntentOutcome::Allowed
+    );
+
+    assert_eq!(gate_counters(), before);
+}
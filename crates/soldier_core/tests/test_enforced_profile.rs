@@ -0,0 +1,33 @@
+use soldier_core::risk::{EnforcedProfile, ProfileParseError, enforced_profile_parse_error_total};
+
+#[test]
+fn test_enforced_profile_parses_exact_values() {
+    for (input, expected) in [
+        ("CSP", EnforcedProfile::Csp),
+        ("GOP", EnforcedProfile::Gop),
+        ("FULL", EnforcedProfile::Full),
+    ] {
+        assert_eq!(EnforcedProfile::parse(input), Ok(expected));
+        assert_eq!(expected.as_str(), input);
+    }
+    assert!(!EnforcedProfile::Csp.enforces_gop());
+    assert!(EnforcedProfile::Gop.enforces_gop());
+}
+
+#[test]
+fn test_enforced_profile_typo_fails_closed() {
+    for typo in ["gop", "FUL", " CSP", ""] {
+        assert_eq!(
+            EnforcedProfile::parse(typo),
+            Err(ProfileParseError {
+                input: typo.to_string(),
+            })
+        );
+    }
+
+    let before = enforced_profile_parse_error_total();
+    let profile = EnforcedProfile::parse_fail_closed("gop");
+    assert_eq!(profile, EnforcedProfile::Full, "never degrade to CSP");
+    assert!(profile.enforces_gop());
+    assert!(enforced_profile_parse_error_total() > before);
+}
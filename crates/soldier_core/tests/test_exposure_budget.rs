@@ -4,7 +4,8 @@
 //! trades from stacking into unsafe portfolio exposure.
 
 use soldier_core::risk::{
-    GlobalBudgetConfig, GlobalBudgetResult, GlobalExposureBudget, InstrumentExposure,
+    GlobalBudgetBand, GlobalBudgetConfig, GlobalBudgetResult, GlobalExposureBudget,
+    InstrumentExposure,
 };
 use std::collections::HashMap;
 
@@ -17,6 +18,7 @@ use std::collections::HashMap;
 fn test_at_226_btc_eth_correlation_breach() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -41,6 +43,7 @@ fn test_at_226_btc_eth_correlation_breach() {
         GlobalBudgetResult::GlobalExposureBudgetExceeded {
             portfolio_delta_after,
             limit,
+            ..
         } => {
             assert!(portfolio_delta_after > limit, "Expected breach");
             assert_eq!(limit, 10000.0);
@@ -60,6 +63,7 @@ fn test_at_226_btc_eth_correlation_breach() {
 fn test_at_911_portfolio_breach_rejection() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -82,6 +86,7 @@ fn test_at_911_portfolio_breach_rejection() {
         GlobalBudgetResult::GlobalExposureBudgetExceeded {
             portfolio_delta_after,
             limit,
+            ..
         } => {
             assert!(portfolio_delta_after > limit);
             assert_eq!(limit, 10000.0);
@@ -102,6 +107,7 @@ fn test_at_911_portfolio_breach_rejection() {
 fn test_at_929_current_plus_pending_exposure() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -141,6 +147,7 @@ fn test_at_929_current_plus_pending_exposure() {
 fn test_btc_alone_within_limit() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -155,6 +162,7 @@ fn test_btc_alone_within_limit() {
 fn test_btc_alone_exceeds_limit() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -172,6 +180,7 @@ fn test_btc_alone_exceeds_limit() {
 fn test_btc_eth_pass_with_low_impact() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -196,6 +205,7 @@ fn test_btc_eth_pass_with_low_impact() {
 fn test_three_instrument_portfolio() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -223,6 +233,7 @@ fn test_three_instrument_portfolio() {
         GlobalBudgetResult::GlobalExposureBudgetExceeded {
             portfolio_delta_after,
             limit,
+            ..
         } => {
             assert!(portfolio_delta_after > limit);
         }
@@ -237,6 +248,7 @@ fn test_three_instrument_portfolio() {
 fn test_empty_portfolio_allows_first_trade() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -251,6 +263,7 @@ fn test_empty_portfolio_allows_first_trade() {
 fn test_negative_delta_short_exposure() {
     let config = GlobalBudgetConfig {
         portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
     };
     let budget = GlobalExposureBudget::new(config);
 
@@ -273,3 +286,114 @@ fn test_negative_delta_short_exposure() {
         }
     }
 }
+
+fn band_of(result: &GlobalBudgetResult) -> Option<GlobalBudgetBand> {
+    match result {
+        GlobalBudgetResult::GlobalExposureBudgetExceeded { band, .. } => Some(*band),
+        GlobalBudgetResult::Pass => None,
+    }
+}
+
+fn btc_exposure(delta_usd: f64) -> HashMap<String, InstrumentExposure> {
+    HashMap::from([("BTC-PERP".to_string(), InstrumentExposure { delta_usd })])
+}
+
+/// Test: hysteresis band keeps opens blocked between exit and enter limits
+///
+/// GIVEN: enter limit 10_000 USD, exit limit 8_000 USD
+/// WHEN: current exposure crosses up through enter, hovers between the bands, then drops
+///       below exit
+/// THEN: block (enter band), still block (exit band), then allow
+#[test]
+fn test_hysteresis_band_prevents_flip_flop_at_limit() {
+    let budget = GlobalExposureBudget::new(GlobalBudgetConfig {
+        portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: Some(8000.0),
+    });
+
+    assert_eq!(
+        budget.evaluate(&btc_exposure(9000.0), "BTC-PERP", 500.0),
+        GlobalBudgetResult::Pass
+    );
+
+    // Fills pushed current exposure over the enter limit: latched.
+    let crossed = budget.evaluate(&btc_exposure(10500.0), "BTC-PERP", 100.0);
+    assert_eq!(band_of(&crossed), Some(GlobalBudgetBand::Enter));
+    assert!(budget.is_breached());
+
+    // Below enter but above exit: still blocked by the latched exit band
+    let hovering = budget.evaluate(&btc_exposure(9000.0), "BTC-PERP", 100.0);
+    match hovering {
+        GlobalBudgetResult::GlobalExposureBudgetExceeded { limit, band, .. } => {
+            assert_eq!(band, GlobalBudgetBand::Exit);
+            assert_eq!(limit, 8000.0);
+        }
+        GlobalBudgetResult::Pass => panic!("expected exit band to keep blocking"),
+    }
+
+    // Released once current exposure drops below exit: back on the enter band
+    assert_eq!(
+        budget.evaluate(&btc_exposure(7500.0), "BTC-PERP", 2000.0),
+        GlobalBudgetResult::Pass
+    );
+    assert!(!budget.is_breached());
+}
+
+/// Test: the breaching call applies the exit band like every later call
+///
+/// GIVEN: enter limit 10_000 USD, exit limit 8_000 USD, current exposure crossing enter
+/// WHEN: a de-risking trade landing between the bands is evaluated twice with the same inputs
+/// THEN: both calls reject on the exit band
+#[test]
+fn test_breaching_call_and_repeat_agree_between_bands() {
+    let budget = GlobalExposureBudget::new(GlobalBudgetConfig {
+        portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: Some(8000.0),
+    });
+    let current = btc_exposure(10500.0);
+
+    let first = budget.evaluate(&current, "BTC-PERP", -1500.0);
+    let second = budget.evaluate(&current, "BTC-PERP", -1500.0);
+    assert_eq!(band_of(&first), Some(GlobalBudgetBand::Exit));
+    assert_eq!(first, second);
+}
+
+/// Test: a rejected oversized proposal does not latch the budget
+///
+/// GIVEN: hysteresis configured and current exposure well inside the limit
+/// WHEN: an oversized proposal is rejected, then an acceptable one is evaluated
+/// THEN: the second proposal passes on the enter band
+#[test]
+fn test_rejected_proposal_does_not_latch_hysteresis() {
+    let budget = GlobalExposureBudget::new(GlobalBudgetConfig {
+        portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: Some(8000.0),
+    });
+    let current = btc_exposure(5000.0);
+
+    let oversized = budget.evaluate(&current, "BTC-PERP", 50000.0);
+    assert_eq!(band_of(&oversized), Some(GlobalBudgetBand::Enter));
+    assert!(!budget.is_breached());
+
+    assert_eq!(
+        budget.evaluate(&current, "BTC-PERP", 4000.0),
+        GlobalBudgetResult::Pass
+    );
+}
+
+/// Test: without an exit limit the budget flips exactly at the enter limit
+#[test]
+fn test_no_exit_limit_disables_hysteresis() {
+    let budget = GlobalExposureBudget::new(GlobalBudgetConfig {
+        portfolio_delta_limit_usd: 10000.0,
+        portfolio_delta_exit_limit_usd: None,
+    });
+    let exposures = HashMap::new();
+
+    let crossed = budget.evaluate(&exposures, "BTC-PERP", 10500.0);
+    assert_eq!(band_of(&crossed), Some(GlobalBudgetBand::Enter));
+    assert_eq!(
+        budget.evaluate(&exposures, "BTC-PERP", 9500.0),
+        GlobalBudgetResult::Pass
+    );
+}
@@ -1,9 +1,12 @@
 use std::sync::Mutex;
 
+use soldier_core::execution::QuantizedFields;
 use soldier_core::risk::{
-    FEE_MODEL_POLL_INTERVAL_MS, FeeModelCache, FeeModelSnapshot, FeeStalenessConfig, PolicyGuard,
-    RiskState, TradingMode, evaluate_fee_staleness, fee_model_cache_age_s,
+    FEE_MODEL_POLL_INTERVAL_MS, FeeModelCache, FeeModelSchemaError, FeeModelSnapshot,
+    FeeStalenessConfig, OrderCost, PolicyGuard, RiskState, TradingMode, evaluate_fee_staleness,
+    expiry_cost, fee_model_cache_age_s, fee_model_refresh_fail_total,
 };
+use soldier_core::venue::{InstrumentKind, InstrumentMetadata};
 
 static TEST_MUTEX: Mutex<()> = Mutex::new(());
 
@@ -54,6 +57,7 @@ fn test_fee_tier_change_updates_net_edge_within_one_cycle() {
         fee_tier: 1,
         maker_fee_rate: 0.0001,
         taker_fee_rate: 0.0005,
+        settlement_fee_bps: None,
         fee_model_cached_at_ts_ms: Some(start_ms),
     };
     cache.apply_snapshot(initial, start_ms);
@@ -68,6 +72,7 @@ fn test_fee_tier_change_updates_net_edge_within_one_cycle() {
         fee_tier: 2,
         maker_fee_rate: 0.0002,
         taker_fee_rate: 0.0006,
+        settlement_fee_bps: None,
         fee_model_cached_at_ts_ms: Some(next_poll_ms),
     };
     cache.apply_snapshot(updated, next_poll_ms);
@@ -77,3 +82,225 @@ fn test_fee_tier_change_updates_net_edge_within_one_cycle() {
     assert_eq!(decision.risk_state, RiskState::Healthy);
     assert!((decision.fee_rate_effective - updated.taker_fee_rate).abs() < 1e-9);
 }
+
+fn blended_snapshot() -> FeeModelSnapshot {
+    FeeModelSnapshot {
+        fee_tier: 1,
+        maker_fee_rate: 0.0001,
+        taker_fee_rate: 0.0005,
+        settlement_fee_bps: None,
+        fee_model_cached_at_ts_ms: Some(0),
+    }
+}
+
+#[test]
+fn test_blended_fee_bps_pure_maker_and_pure_taker() {
+    let snapshot = blended_snapshot();
+    assert!((snapshot.blended_fee_bps(2.0, 0.0) - 1.0).abs() < 1e-9);
+    assert!((snapshot.blended_fee_bps(0.0, 2.0) - 5.0).abs() < 1e-9);
+    assert_eq!(snapshot.blended_fee_bps(0.0, 0.0), 0.0);
+}
+
+#[test]
+fn test_order_cost_applies_maker_and_taker_rate_to_notional() {
+    let snapshot = blended_snapshot();
+    let order = QuantizedFields {
+        qty_q: 2.0,
+        limit_price_q: 50_000.0,
+    };
+
+    // Notional 2 * 50_000 * 0.5 = 50_000 USD.
+    let maker = snapshot
+        .order_cost(&order, 0.5, true)
+        .expect("finite notional");
+    assert!((maker.fee_usd - 50_000.0 * 0.0001).abs() < 1e-9);
+    assert!((maker.fee_bps - 1.0).abs() < 1e-9);
+
+    let taker = snapshot
+        .order_cost(&order, 0.5, false)
+        .expect("finite notional");
+    assert!((taker.fee_usd - 50_000.0 * 0.0005).abs() < 1e-9);
+    assert!((taker.fee_bps - 5.0).abs() < 1e-9);
+
+    let empty = QuantizedFields {
+        qty_q: 0.0,
+        limit_price_q: 50_000.0,
+    };
+    assert_eq!(
+        snapshot.order_cost(&empty, 0.5, false),
+        Some(OrderCost {
+            fee_usd: 0.0,
+            fee_bps: 0.0,
+        })
+    );
+}
+
+#[test]
+fn test_order_cost_rejects_non_finite_notional() {
+    let snapshot = blended_snapshot();
+    for (qty_q, limit_price_q) in [(f64::NAN, 50_000.0), (1.0, f64::INFINITY), (f64::MAX, 2.0)] {
+        let order = QuantizedFields {
+            qty_q,
+            limit_price_q,
+        };
+        assert_eq!(snapshot.order_cost(&order, 1.0, false), None);
+    }
+}
+
+#[test]
+fn test_fee_for_notional_matches_order_cost() {
+    let snapshot = blended_snapshot();
+    let order = QuantizedFields {
+        qty_q: 2.0,
+        limit_price_q: 50_000.0,
+    };
+    assert_eq!(
+        snapshot.fee_for_notional(-50_000.0, true),
+        snapshot.order_cost(&order, 0.5, true)
+    );
+    assert_eq!(snapshot.fee_for_notional(f64::NAN, true), None);
+}
+
+fn metadata(instrument_kind: InstrumentKind) -> InstrumentMetadata {
+    InstrumentMetadata {
+        instrument_kind,
+        tick_size: 0.0005,
+        amount_step: 0.1,
+        min_amount: 0.1,
+        contract_multiplier: 1.0,
+        expiration_ts_ms: Some(1_767_225_600_000),
+    }
+}
+
+#[test]
+fn test_expiry_cost_applies_only_to_held_options() {
+    let snapshot = FeeModelSnapshot {
+        settlement_fee_bps: Some(1.5),
+        ..blended_snapshot()
+    };
+
+    // 10 options settling at 60_000 USD: 600_000 USD notional at 1.5 bps.
+    let option = metadata(InstrumentKind::Option);
+    let cost = expiry_cost(&snapshot, -10.0, 60_000.0, &option).expect("finite fee");
+    assert!((cost - 90.0).abs() < 1e-9);
+
+    let perp = metadata(InstrumentKind::Perpetual);
+    assert_eq!(expiry_cost(&snapshot, 10.0, 60_000.0, &perp), Ok(0.0));
+    assert_eq!(expiry_cost(&snapshot, 0.0, 60_000.0, &option), Ok(0.0));
+
+    // Per-trade costing ignores the settlement fee.
+    let order = QuantizedFields {
+        qty_q: 10.0,
+        limit_price_q: 60_000.0,
+    };
+    assert_eq!(
+        snapshot.order_cost(&order, 1.0, false),
+        blended_snapshot().order_cost(&order, 1.0, false)
+    );
+}
+
+#[test]
+fn test_settlement_fee_non_finite_is_rejected() {
+    let now_ms = 1_700_000_000_000u64;
+    assert_eq!(
+        FeeModelSnapshot::from_fields(
+            Some(2),
+            Some(0.0001),
+            Some(0.0005),
+            Some(f64::NAN),
+            Some(now_ms)
+        ),
+        Err(FeeModelSchemaError::NonFiniteSettlementFee)
+    );
+
+    // A snapshot built field-by-field cannot slip a NaN fee through as a free expiry.
+    let snapshot = FeeModelSnapshot {
+        settlement_fee_bps: Some(f64::NAN),
+        ..blended_snapshot()
+    };
+    let option = metadata(InstrumentKind::Option);
+    assert_eq!(
+        expiry_cost(&snapshot, 10.0, 60_000.0, &option),
+        Err(FeeModelSchemaError::NonFiniteSettlementFee)
+    );
+}
+
+#[test]
+fn test_perp_only_snapshot_without_settlement_fee_is_trusted() {
+    let _guard = TEST_MUTEX.lock().expect("fee cache test mutex");
+    let config = FeeStalenessConfig::default();
+    let now_ms = 1_700_000_000_000u64;
+    let before = fee_model_refresh_fail_total();
+    let mut cache = FeeModelCache::new();
+
+    let snapshot =
+        FeeModelSnapshot::from_fields(Some(2), Some(0.0001), Some(0.0005), None, Some(now_ms));
+    assert!(snapshot.is_ok());
+    cache.load_snapshot(snapshot, now_ms);
+
+    assert_eq!(fee_model_refresh_fail_total(), before);
+    let decision = cache.effective_fee_rate(now_ms, config, false);
+    assert!(!decision.is_hard_stale());
+    assert_eq!(decision.risk_state, RiskState::Healthy);
+
+    // Perps never need the settlement fee; costing an option expiry without one fails.
+    let snapshot = snapshot.expect("perp-only snapshot");
+    let perp = metadata(InstrumentKind::Perpetual);
+    assert_eq!(expiry_cost(&snapshot, 10.0, 60_000.0, &perp), Ok(0.0));
+    let option = metadata(InstrumentKind::Option);
+    assert_eq!(
+        expiry_cost(&snapshot, 10.0, 60_000.0, &option),
+        Err(FeeModelSchemaError::MissingSettlementFeeBps)
+    );
+}
+
+#[test]
+fn test_blended_fee_bps_weights_by_fill_qty() {
+    let snapshot = blended_snapshot();
+    // 70% maker at 1 bps, 30% taker at 5 bps.
+    let expected = 0.7 * 1.0 + 0.3 * 5.0;
+    assert!((snapshot.blended_fee_bps(7.0, 3.0) - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_fee_model_complete_snapshot_is_trusted() {
+    let _guard = TEST_MUTEX.lock().expect("fee cache test mutex");
+    let config = FeeStalenessConfig::default();
+    let now_ms = 1_700_000_000_000u64;
+    let before = fee_model_refresh_fail_total();
+    let mut cache = FeeModelCache::new();
+
+    cache.load_snapshot(
+        FeeModelSnapshot::from_fields(Some(2), Some(0.0001), Some(0.0005), Some(0.0), Some(now_ms)),
+        now_ms,
+    );
+
+    assert_eq!(fee_model_refresh_fail_total(), before);
+    assert_eq!(cache.taker_fee_rate(), 0.0005);
+    let decision = cache.effective_fee_rate(now_ms, config, false);
+    assert!(!decision.is_hard_stale());
+    assert_eq!(decision.risk_state, RiskState::Healthy);
+}
+
+#[test]
+fn test_fee_model_snapshot_missing_taker_tier_is_hard_stale() {
+    let _guard = TEST_MUTEX.lock().expect("fee cache test mutex");
+    let config = FeeStalenessConfig::default();
+    let now_ms = 1_700_000_000_000u64;
+    let mut cache = FeeModelCache::new();
+    cache.load_snapshot(
+        FeeModelSnapshot::from_fields(Some(2), Some(0.0001), Some(0.0005), Some(0.0), Some(now_ms)),
+        now_ms,
+    );
+    let before = fee_model_refresh_fail_total();
+
+    let partial =
+        FeeModelSnapshot::from_fields(Some(2), Some(0.0001), None, Some(0.0), Some(now_ms));
+    assert_eq!(partial, Err(FeeModelSchemaError::MissingTakerFeeRate));
+    cache.load_snapshot(partial, now_ms + 1_000);
+
+    assert_eq!(fee_model_refresh_fail_total(), before + 1);
+    let decision = cache.effective_fee_rate(now_ms + 1_000, config, false);
+    assert!(decision.is_hard_stale());
+    assert_eq!(decision.risk_state, RiskState::Degraded);
+}
@@ -1,25 +1,21 @@
+mod common;
+
 use std::sync::atomic::Ordering;
 
 use soldier_core::execution::{
     BuildOrderIntentContext, BuildOrderIntentObservers, BuildOrderIntentOutcome,
-    BuildOrderIntentRejectReason, DispatchStep, GateStep, InstrumentQuantization,
-    IntentClassification, L2BookLevel, L2BookSnapshot, LiquidityGateConfig,
-    LiquidityGateRejectReason, NetEdgeRejectReason, OrderIntent, OrderType, OrderTypeGuardConfig,
+    BuildOrderIntentRejectReason, DispatchStep, GateSequenceResult, GateStep,
+    InstrumentQuantization, IntentClassification, L2BookLevel, L2BookSnapshot, LiquidityGateConfig,
+    LiquidityGateRejectReason, NetEdgeRejectReason, OrderIntent, OrderTypeGuardConfig,
     QuantizeRejectReason, RecordIntentOutcome, Side, build_order_intent,
+    build_order_intent_dry_run, render_dispatch_trace, render_gate_trace,
     take_build_order_intent_outcome, take_dispatch_trace, take_gate_sequence_trace,
     with_build_order_intent_context,
 };
 use soldier_core::risk::{FeeModelSnapshot, FeeStalenessConfig, RiskState};
-use soldier_core::venue::InstrumentKind;
 
 fn base_intent() -> OrderIntent {
-    OrderIntent {
-        instrument_kind: InstrumentKind::Perpetual,
-        order_type: OrderType::Limit,
-        trigger: None,
-        trigger_price: None,
-        linked_order_type: None,
-    }
+    common::perp_limit_intent()
 }
 
 fn sample_book(now_ms: u64) -> L2BookSnapshot {
@@ -52,6 +48,7 @@ fn context_for_open(observers: BuildOrderIntentObservers) -> BuildOrderIntentCon
             fee_tier: 1,
             maker_fee_rate: 0.0002,
             taker_fee_rate: 0.0005,
+            settlement_fee_bps: None,
             fee_model_cached_at_ts_ms: Some(now_ms),
         },
         fee_staleness_config: FeeStalenessConfig::default(),
@@ -157,6 +154,12 @@ fn test_gate_ordering_constraints() {
     assert_eq!(observers.dispatch_total.load(Ordering::Relaxed), 0);
 }
 
+type ContextMutation = fn(BuildOrderIntentContext) -> BuildOrderIntentContext;
+
+fn unchanged(context: BuildOrderIntentContext) -> BuildOrderIntentContext {
+    context
+}
+
 fn with_invalid_quantization(mut context: BuildOrderIntentContext) -> BuildOrderIntentContext {
     context.quantization.tick_size = 0.0;
     context
@@ -172,11 +175,16 @@ fn with_missing_net_edge_input(mut context: BuildOrderIntentContext) -> BuildOrd
     context
 }
 
+fn with_uncostable_fee(mut context: BuildOrderIntentContext) -> BuildOrderIntentContext {
+    context.fee_model.taker_fee_rate = f64::NAN;
+    context
+}
+
 #[test]
 fn test_gate_reject_matrix_stops_before_dispatch() {
     struct Case {
         name: &'static str,
-        mutate: fn(BuildOrderIntentContext) -> BuildOrderIntentContext,
+        mutate: ContextMutation,
         expected_outcome: BuildOrderIntentOutcome,
         expected_trace: Vec<GateStep>,
     }
@@ -221,6 +229,20 @@ fn test_gate_reject_matrix_stops_before_dispatch() {
                 GateStep::NetEdgeGate,
             ],
         },
+        Case {
+            name: "net_edge_fee_uncostable",
+            mutate: with_uncostable_fee,
+            expected_outcome: BuildOrderIntentOutcome::Rejected(
+                BuildOrderIntentRejectReason::NetEdge(NetEdgeRejectReason::NetEdgeInputMissing),
+            ),
+            expected_trace: vec![
+                GateStep::Preflight,
+                GateStep::Quantize,
+                GateStep::FeeCache,
+                GateStep::LiquidityGate,
+                GateStep::NetEdgeGate,
+            ],
+        },
     ];
 
     for case in cases {
@@ -271,3 +293,94 @@ fn test_missing_context_rejects_after_preflight_only() {
     assert_eq!(take_gate_sequence_trace(), vec![GateStep::Preflight]);
     assert!(take_dispatch_trace().is_empty());
 }
+
+#[test]
+fn test_dry_run_matches_live_gate_sequence() {
+    let cases: [(&str, ContextMutation); 2] =
+        [("passing", unchanged), ("liquidity_no_l2", with_missing_l2)];
+
+    for (name, mutate) in cases {
+        let observers = BuildOrderIntentObservers::new();
+        let context = mutate(context_for_open(observers.clone()));
+
+        let dry_outcome =
+            build_order_intent_dry_run(&context, base_intent(), OrderTypeGuardConfig::default());
+        let dry_trace = take_gate_sequence_trace();
+        assert!(
+            take_dispatch_trace().is_empty(),
+            "{name} dry run must not record/dispatch"
+        );
+        assert!(
+            take_build_order_intent_outcome().is_none(),
+            "{name} dry run must not publish an outcome"
+        );
+        assert_eq!(observers.recorded_total.load(Ordering::Relaxed), 0);
+        assert_eq!(observers.dispatch_total.load(Ordering::Relaxed), 0);
+
+        let live_result = with_build_order_intent_context(context, || {
+            build_order_intent(base_intent(), OrderTypeGuardConfig::default())
+        });
+        assert_eq!(
+            live_result.is_ok(),
+            dry_outcome == BuildOrderIntentOutcome::Allowed,
+            "{name} live result"
+        );
+        let live_outcome = take_build_order_intent_outcome().expect("expected outcome");
+        let live_trace = take_gate_sequence_trace();
+
+        assert_eq!(
+            dry_outcome.gate_sequence_result(),
+            live_outcome.gate_sequence_result(),
+            "{name} gate sequence result"
+        );
+        assert_eq!(dry_outcome, live_outcome, "{name} outcome");
+        assert_eq!(dry_trace, live_trace, "{name} trace");
+    }
+
+    let context = with_missing_l2(context_for_open(BuildOrderIntentObservers::new()));
+    assert_eq!(
+        build_order_intent_dry_run(&context, base_intent(), OrderTypeGuardConfig::default())
+            .gate_sequence_result(),
+        GateSequenceResult::Rejected
+    );
+}
+
+#[test]
+fn test_render_gate_trace_lists_gates_in_order_and_marks_reject() {
+    let observers = BuildOrderIntentObservers::new();
+    let allowed = with_build_order_intent_context(context_for_open(observers.clone()), || {
+        build_order_intent(base_intent(), OrderTypeGuardConfig::default())
+    });
+    assert!(allowed.is_ok());
+    let outcome = take_build_order_intent_outcome().expect("expected outcome");
+    assert_eq!(
+        render_gate_trace(&take_gate_sequence_trace(), &outcome),
+        concat!(
+            " 1. Preflight       pass\n",
+            " 2. Quantize        pass\n",
+            " 3. FeeCache        pass\n",
+            " 4. LiquidityGate   pass\n",
+            " 5. NetEdgeGate     pass\n",
+            " 6. Pricer          pass\n",
+        )
+    );
+    assert_eq!(
+        render_dispatch_trace(&take_dispatch_trace(), &outcome),
+        " 1. RecordIntent    pass\n 2. DispatchAttempt pass\n"
+    );
+
+    let rejected =
+        with_build_order_intent_context(with_missing_l2(context_for_open(observers)), || {
+            build_order_intent(base_intent(), OrderTypeGuardConfig::default())
+        });
+    assert!(rejected.is_err());
+    let outcome = take_build_order_intent_outcome().expect("expected outcome");
+    let rendered = render_gate_trace(&take_gate_sequence_trace(), &outcome);
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[..3].iter().all(|line| line.ends_with(" pass")));
+    assert!(
+        lines[3].starts_with(" 4. LiquidityGate   REJECT LiquidityGate("),
+        "{rendered}"
+    );
+}
@@ -1,5 +1,12 @@
-use soldier_core::execution::{InstrumentQuantization, QuantizedSteps, Side};
-use soldier_core::idempotency::{IntentHashInput, intent_hash};
+use soldier_core::execution::{InstrumentQuantization, QuantizedSteps, Side, TlsmIntent, TlsmSide};
+use std::sync::Mutex;
+
+use soldier_core::idempotency::{
+    DedupOutcome, IntentDedup, IntentHashInput, duplicates_suppressed_total, intent_hash,
+    record_duplicate_suppressed,
+};
+
+static TEST_MUTEX: Mutex<()> = Mutex::new(());
 
 #[test]
 fn test_intent_hash_deterministic_from_quantized() {
@@ -124,3 +131,177 @@ fn test_intent_hash_uses_quantized_steps_only() {
 
     assert_ne!(intent_hash(&base), intent_hash(&step_adjusted));
 }
+
+fn dedup_input<'a>(
+    instrument_id: &'a str,
+    qty_steps: i64,
+    group_id: &'a str,
+) -> IntentHashInput<'a> {
+    IntentHashInput {
+        instrument_id,
+        side: Side::Buy,
+        quantized: QuantizedSteps {
+            qty_steps,
+            price_ticks: 201,
+            qty_q: qty_steps as f64 * 0.1,
+            limit_price_q: 100.5,
+        },
+        group_id,
+        leg_idx: 0,
+    }
+}
+
+#[test]
+fn test_intent_dedup_rejects_duplicate_within_window() {
+    let _guard = TEST_MUTEX.lock().expect("dedup test mutex");
+    let dedup = IntentDedup::new(1_000);
+    let original = dedup_input("BTC-PERP", 12, "group-1");
+    dedup
+        .check(&original, 10_000)
+        .expect("first intent accepted");
+
+    // Same economic content resubmitted under a fresh group id
+    let retry = dedup_input("BTC-PERP", 12, "group-2");
+    let dup = dedup
+        .check(&retry, 10_400)
+        .expect_err("duplicate inside window must reject");
+    assert_eq!(dup.accepted_ts_ms, 10_000);
+
+    let again = dedup
+        .check(&retry, 10_900)
+        .expect_err("rejected repeat must not extend the window");
+    assert_eq!(again.accepted_ts_ms, 10_000);
+}
+
+#[test]
+fn test_intent_dedup_accepts_duplicate_after_window() {
+    let dedup = IntentDedup::new(1_000);
+    let input = dedup_input("BTC-PERP", 12, "group-1");
+    dedup.check(&input, 10_000).expect("first intent accepted");
+    dedup
+        .check(&input, 11_000)
+        .expect("repeat after window accepted");
+}
+
+#[test]
+fn test_intent_dedup_accepts_near_identical_intent() {
+    let dedup = IntentDedup::new(1_000);
+    dedup
+        .check(&dedup_input("BTC-PERP", 12, "group-1"), 10_000)
+        .expect("first intent accepted");
+    dedup
+        .check(&dedup_input("BTC-PERP", 13, "group-1"), 10_100)
+        .expect("one step larger is a distinct intent");
+    dedup
+        .check(&dedup_input("ETH-PERP", 12, "group-1"), 10_100)
+        .expect("other instrument is a distinct intent");
+}
+
+#[test]
+fn test_duplicates_suppressed_total_counts_each_suppression_with_cause() {
+    let _guard = TEST_MUTEX.lock().expect("dedup test mutex");
+    let dedup = IntentDedup::new(1_000);
+    let input = dedup_input("BTC-PERP", 12, "group-1");
+    let before = duplicates_suppressed_total();
+
+    assert_eq!(dedup.check_outcome(&input, 10_000), DedupOutcome::Accepted);
+    assert_eq!(duplicates_suppressed_total(), before);
+
+    assert_eq!(
+        dedup.check_outcome(&input, 10_100),
+        DedupOutcome::DuplicateContentHash
+    );
+    assert_eq!(
+        dedup.check_outcome(&input, 10_200),
+        DedupOutcome::DuplicateContentHash
+    );
+    assert_eq!(duplicates_suppressed_total(), before + 2);
+
+    record_duplicate_suppressed(DedupOutcome::DuplicateTradeId);
+    record_duplicate_suppressed(DedupOutcome::Accepted);
+    assert_eq!(duplicates_suppressed_total(), before + 3);
+}
+
+#[test]
+fn test_ledger_intent_hash_matches_canonical_hash() {
+    let quantized = QuantizedSteps {
+        qty_steps: 12,
+        price_ticks: 201,
+        qty_q: 1.2,
+        limit_price_q: 100.5,
+    };
+    let input = IntentHashInput {
+        instrument_id: "BTC-PERP",
+        side: Side::Sell,
+        quantized,
+        group_id: "group-7",
+        leg_idx: 1,
+    };
+    // Same canonical fields, constructed in a different order.
+    let reordered = IntentHashInput {
+        leg_idx: 1,
+        group_id: "group-7",
+        quantized,
+        side: Side::Sell,
+        instrument_id: "BTC-PERP",
+    };
+
+    let tlsm_intent = TlsmIntent::from_hash_input(&input, 1_000);
+    assert_eq!(tlsm_intent.intent_hash, intent_hash(&input));
+    assert_eq!(
+        TlsmIntent::from_hash_input(&reordered, 2_000).intent_hash,
+        tlsm_intent.intent_hash
+    );
+    assert_eq!(tlsm_intent.side, TlsmSide::Sell);
+    assert_eq!(tlsm_intent.qty_steps, Some(12));
+    assert_eq!(tlsm_intent.price_ticks, Some(201));
+}
+
+#[test]
+fn test_ledger_intent_hash_flips_on_material_field_change() {
+    let quantized = QuantizedSteps {
+        qty_steps: 12,
+        price_ticks: 201,
+        qty_q: 1.2,
+        limit_price_q: 100.5,
+    };
+    let base = IntentHashInput {
+        instrument_id: "BTC-PERP",
+        side: Side::Buy,
+        quantized,
+        group_id: "group-7",
+        leg_idx: 0,
+    };
+    let base_hash = TlsmIntent::from_hash_input(&base, 0).intent_hash;
+
+    let variants = [
+        IntentHashInput {
+            instrument_id: "ETH-PERP",
+            ..base.clone()
+        },
+        IntentHashInput {
+            side: Side::Sell,
+            ..base.clone()
+        },
+        IntentHashInput {
+            quantized: QuantizedSteps {
+                qty_steps: 13,
+                ..quantized
+            },
+            ..base.clone()
+        },
+        IntentHashInput {
+            quantized: QuantizedSteps {
+                price_ticks: 202,
+                ..quantized
+            },
+            ..base.clone()
+        },
+    ];
+    for variant in &variants {
+        assert_ne!(
+            TlsmIntent::from_hash_input(variant, 0).intent_hash,
+            base_hash
+        );
+    }
+}
@@ -1,26 +1,28 @@
-use std::sync::Mutex;
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use soldier_core::risk::{PolicyGuard, RiskState, TradingMode};
 use soldier_core::venue::{
-    InstrumentCache, instrument_cache_age_s, instrument_cache_hits_total,
-    instrument_cache_refresh_errors_total, instrument_cache_stale_total,
-    record_instrument_cache_refresh_error, take_instrument_cache_ttl_breach,
+    InstrumentCache, InstrumentKind, InstrumentMetadata, instrument_cache_age_s,
+    instrument_cache_hits_total, instrument_cache_refresh_errors_total,
+    instrument_cache_stale_total, record_instrument_cache_refresh_error,
+    take_instrument_cache_ttl_breach,
 };
 
 static TEST_MUTEX: Mutex<()> = Mutex::new(());
 
+const BASE_MS: u64 = 1_700_000_000_000;
+
 #[test]
 fn test_fresh_instrument_cache_is_healthy() {
     let _guard = TEST_MUTEX.lock().expect("instrument cache test mutex");
     let mut cache = InstrumentCache::new(Duration::from_secs(30));
-    let base = Instant::now();
-    cache.insert_with_instant("BTC-PERP", "metadata", base);
+    let base = BASE_MS;
+    cache.insert_at("BTC-PERP", "metadata", base);
 
     let hits_before = instrument_cache_hits_total();
-    let read = cache
-        .get_with_instant("BTC-PERP", base + Duration::from_secs(5))
-        .expect("cache hit");
+    let read = cache.get_at("BTC-PERP", base + 5_000).expect("cache hit");
     let hits_after = instrument_cache_hits_total();
     let age_s = instrument_cache_age_s();
 
@@ -35,13 +37,13 @@ fn test_instrument_cache_ttl_boundary_is_healthy() {
     let _guard = TEST_MUTEX.lock().expect("instrument cache test mutex");
     let ttl = Duration::from_secs(10);
     let mut cache = InstrumentCache::new(ttl);
-    let base = Instant::now();
-    cache.insert_with_instant("BTC-BOUNDARY", "metadata", base);
+    let base = BASE_MS;
+    cache.insert_at("BTC-BOUNDARY", "metadata", base);
 
     let hits_before = instrument_cache_hits_total();
     let stale_before = instrument_cache_stale_total();
     let read = cache
-        .get_with_instant("BTC-BOUNDARY", base + ttl)
+        .get_at("BTC-BOUNDARY", base + ttl.as_millis() as u64)
         .expect("cache hit");
     let hits_after = instrument_cache_hits_total();
     let stale_after = instrument_cache_stale_total();
@@ -59,15 +61,13 @@ fn test_stale_instrument_cache_sets_degraded() {
     let _guard = TEST_MUTEX.lock().expect("instrument cache test mutex");
     let ttl = Duration::from_secs(10);
     let mut cache = InstrumentCache::new(ttl);
-    let base = Instant::now();
-    cache.insert_with_instant("ETH-PERP", "stale", base);
+    let base = BASE_MS;
+    cache.insert_at("ETH-PERP", "stale", base);
     let _ = take_instrument_cache_ttl_breach();
 
     let hits_before = instrument_cache_hits_total();
     let before = instrument_cache_stale_total();
-    let read = cache
-        .get_with_instant("ETH-PERP", base + Duration::from_secs(30))
-        .expect("cache hit");
+    let read = cache.get_at("ETH-PERP", base + 30_000).expect("cache hit");
     let after = instrument_cache_stale_total();
     let hits_after = instrument_cache_hits_total();
     let age_s = instrument_cache_age_s();
@@ -88,12 +88,10 @@ fn test_instrument_cache_ttl_blocks_opens_allows_closes() {
     let _guard = TEST_MUTEX.lock().expect("instrument cache test mutex");
     let ttl = Duration::from_secs(10);
     let mut cache = InstrumentCache::new(ttl);
-    let base = Instant::now();
-    cache.insert_with_instant("SOL-PERP", "stale", base);
+    let base = BASE_MS;
+    cache.insert_at("SOL-PERP", "stale", base);
 
-    let read = cache
-        .get_with_instant("SOL-PERP", base + Duration::from_secs(30))
-        .expect("cache hit");
+    let read = cache.get_at("SOL-PERP", base + 30_000).expect("cache hit");
     let mode = PolicyGuard::get_effective_mode(read.risk_state);
 
     assert_eq!(mode, TradingMode::ReduceOnly);
@@ -112,3 +110,76 @@ fn test_instrument_cache_refresh_errors_increment() {
 
     assert_eq!(after, before + 1);
 }
+
+fn perp_metadata(tick_size: f64) -> InstrumentMetadata {
+    InstrumentMetadata {
+        instrument_kind: InstrumentKind::Perpetual,
+        tick_size,
+        amount_step: 10.0,
+        min_amount: 10.0,
+        contract_multiplier: 1.0,
+        expiration_ts_ms: None,
+    }
+}
+
+#[test]
+fn test_instrument_cache_on_refresh_fires_only_on_change() {
+    let _guard = TEST_MUTEX.lock().expect("instrument cache test mutex");
+    let mut cache = InstrumentCache::new(Duration::from_secs(30));
+    let refreshed = Arc::new(AtomicU64::new(0));
+    let last_tick = Arc::new(Mutex::new(None));
+    {
+        let refreshed = Arc::clone(&refreshed);
+        let last_tick = Arc::clone(&last_tick);
+        cache.set_on_refresh(Box::new(
+            move |instrument, metadata: &InstrumentMetadata| {
+                assert_eq!(instrument, "BTC-PERPETUAL");
+                refreshed.fetch_add(1, Ordering::Relaxed);
+                *last_tick.lock().expect("last tick lock") = Some(metadata.tick_size);
+            },
+        ));
+    }
+
+    let base = BASE_MS;
+    cache.insert_notify_on_change_at("BTC-PERPETUAL", perp_metadata(0.5), base);
+    cache.insert_notify_on_change_at("BTC-PERPETUAL", perp_metadata(0.5), base + 1_000);
+    assert_eq!(refreshed.load(Ordering::Relaxed), 0, "no-op refresh");
+
+    cache.insert_notify_on_change_at("BTC-PERPETUAL", perp_metadata(1.0), base + 2_000);
+    assert_eq!(refreshed.load(Ordering::Relaxed), 1);
+    assert_eq!(*last_tick.lock().expect("last tick lock"), Some(1.0));
+
+    cache.insert_at("BTC-PERPETUAL", perp_metadata(0.5), base + 3_000);
+    assert_eq!(
+        refreshed.load(Ordering::Relaxed),
+        1,
+        "plain insert stays silent"
+    );
+}
+
+#[test]
+fn test_instrument_cache_age_is_deterministic_around_ttl() {
+    let _guard = TEST_MUTEX.lock().expect("instrument cache test mutex");
+    let mut cache = InstrumentCache::new(Duration::from_secs(10));
+    cache.insert_at("BTC-PERP", "metadata", BASE_MS);
+
+    for (offset_ms, expected) in [
+        (9_999, RiskState::Healthy),
+        (10_000, RiskState::Healthy),
+        (10_001, RiskState::Degraded),
+    ] {
+        let read = cache
+            .get_at("BTC-PERP", BASE_MS + offset_ms)
+            .expect("cache hit");
+        assert_eq!(read.risk_state, expected, "age {offset_ms}ms");
+        assert!((instrument_cache_age_s() - offset_ms as f64 / 1000.0).abs() < 1e-9);
+    }
+
+    // A clock that steps backwards cannot prove freshness: the entry reads as stale.
+    let stale_before = instrument_cache_stale_total();
+    let read = cache
+        .get_at("BTC-PERP", BASE_MS - 5_000)
+        .expect("cache hit");
+    assert_eq!(read.risk_state, RiskState::Degraded);
+    assert!(instrument_cache_stale_total() > stale_before);
+}
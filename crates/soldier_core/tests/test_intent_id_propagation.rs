@@ -1,13 +1,14 @@
+mod common;
+
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use soldier_core::execution::{
     BuildOrderIntentOutcome, BuildOrderIntentRejectReason, GateSequenceResult, LinkedOrderType,
-    OrderIntent, OrderType, OrderTypeGuardConfig, OrderTypeRejectReason, build_order_intent,
+    OrderIntent, OrderTypeGuardConfig, OrderTypeRejectReason, build_order_intent,
     gate_sequence_total, preflight_reject_total, take_build_order_intent_outcome,
     take_execution_metric_lines, with_intent_trace_ids,
 };
-use soldier_core::venue::InstrumentKind;
 
 const RUN_ID: &str = "run-p1d-001";
 const INTENT_ID: &str = "intent-p1d-001";
@@ -22,11 +23,8 @@ fn evidence_path() -> PathBuf {
 
 fn sample_reject_intent() -> OrderIntent {
     OrderIntent {
-        instrument_kind: InstrumentKind::Perpetual,
-        order_type: OrderType::Limit,
-        trigger: None,
-        trigger_price: None,
         linked_order_type: Some(LinkedOrderType::Oco),
+        ..common::perp_limit_intent()
     }
 }
 
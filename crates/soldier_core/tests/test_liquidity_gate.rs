@@ -1,6 +1,7 @@
 use soldier_core::execution::{
     IntentClassification, L2BookLevel, L2BookSnapshot, LiquidityGateConfig, LiquidityGateIntent,
-    LiquidityGateRejectReason, Side, evaluate_liquidity_gate,
+    LiquidityGateMetrics, LiquidityGateRejectReason, SLIPPAGE_BPS_BUCKETS, Side,
+    evaluate_liquidity_gate, slippage_bps_histogram,
 };
 
 fn snapshot(ts_ms: u64, bids: Vec<L2BookLevel>, asks: Vec<L2BookLevel>) -> L2BookSnapshot {
@@ -296,3 +297,59 @@ fn test_liquidity_gate_non_open_paths_skip_slippage_when_l2_fresh() {
     assert_eq!(hedge.wap, None);
     assert_eq!(hedge.slippage_bps, None);
 }
+
+#[test]
+fn test_slippage_bps_histogram_buckets() {
+    let metrics = LiquidityGateMetrics::new();
+    // 5.0 sits exactly on a bucket bound and must land in that (inclusive) bucket
+    for bps in [0.0, 0.4, 5.0, 5.01, 7.5, 250.0] {
+        metrics.record_expected_slippage(bps);
+    }
+
+    let histogram = metrics.slippage_bps_histogram();
+    assert_eq!(histogram.len(), SLIPPAGE_BPS_BUCKETS.len() + 1);
+    let count_for = |bound: f64| {
+        histogram
+            .iter()
+            .find(|(upper, _)| *upper == bound)
+            .map(|(_, count)| *count)
+            .expect("bucket bound present")
+    };
+    assert_eq!(count_for(0.0), 1);
+    assert_eq!(count_for(1.0), 1);
+    assert_eq!(count_for(2.5), 0);
+    assert_eq!(count_for(5.0), 1);
+    assert_eq!(count_for(10.0), 2);
+    assert_eq!(count_for(f64::INFINITY), 1);
+    assert_eq!(metrics.expected_slippage_samples(), 6);
+
+    metrics.reset_slippage_bps_histogram();
+    assert!(
+        metrics
+            .slippage_bps_histogram()
+            .iter()
+            .all(|(_, count)| *count == 0)
+    );
+}
+
+#[test]
+fn test_liquidity_gate_records_slippage_into_histogram() {
+    let asks = vec![L2BookLevel {
+        price: 100.0,
+        qty: 5.0,
+    }];
+    let book = snapshot(1_000, Vec::new(), asks);
+    let intent = base_intent(
+        IntentClassification::Open,
+        Side::Buy,
+        1.0,
+        Some(&book),
+        1_500,
+    );
+    let zero_bucket = |histogram: Vec<(f64, u64)>| histogram[0].1;
+
+    let before = zero_bucket(slippage_bps_histogram());
+    evaluate_liquidity_gate(&intent, LiquidityGateConfig::default())
+        .expect("single-level fill has zero slippage");
+    assert!(zero_bucket(slippage_bps_histogram()) > before);
+}
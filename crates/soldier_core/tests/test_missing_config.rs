@@ -1,3 +1,5 @@
+mod common;
+
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -7,12 +9,11 @@ use soldier_core::execution::{
     BuildOrderIntentContext, BuildOrderIntentObservers, BuildOrderIntentOutcome,
     BuildOrderIntentRejectReason, InstrumentQuantization, IntentClassification, L2BookLevel,
     L2BookSnapshot, LiquidityGateConfig, LiquidityGateRejectReason, NetEdgeRejectReason,
-    OrderIntent, OrderType, OrderTypeGuardConfig, QuantizeRejectReason, RecordIntentOutcome, Side,
+    OrderIntent, OrderTypeGuardConfig, QuantizeRejectReason, RecordIntentOutcome, Side,
     build_order_intent, take_build_order_intent_outcome, take_dispatch_trace,
     with_build_order_intent_context,
 };
 use soldier_core::risk::{FeeModelSnapshot, FeeStalenessConfig, RiskState};
-use soldier_core::venue::InstrumentKind;
 
 const CONFIG_MISSING_REASON: &str = "CONFIG_MISSING";
 
@@ -46,13 +47,7 @@ struct MissingCase {
 }
 
 fn base_intent() -> OrderIntent {
-    OrderIntent {
-        instrument_kind: InstrumentKind::Perpetual,
-        order_type: OrderType::Limit,
-        trigger: None,
-        trigger_price: None,
-        linked_order_type: None,
-    }
+    common::perp_limit_intent()
 }
 
 fn sample_book(now_ms: u64) -> L2BookSnapshot {
@@ -85,6 +80,7 @@ fn base_context(observers: BuildOrderIntentObservers) -> BuildOrderIntentContext
             fee_tier: 1,
             maker_fee_rate: 0.0002,
             taker_fee_rate: 0.0005,
+            settlement_fee_bps: None,
             fee_model_cached_at_ts_ms: Some(now_ms),
         },
         fee_staleness_config: FeeStalenessConfig::default(),
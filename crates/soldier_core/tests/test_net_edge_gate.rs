@@ -1,6 +1,12 @@
 use soldier_core::execution::{
-    IntentClassification, NetEdgeGateIntent, NetEdgeRejectReason, evaluate_net_edge_gate,
+    ExpectedEdgeIntent, IntentClassification, L2BookLevel, L2BookSnapshot, NetEdgeGateIntent,
+    NetEdgeRejectReason, Side, evaluate_net_edge_gate, expected_net_edge, net_edge_reject_total,
 };
+use soldier_core::risk::FeeModelSnapshot;
+use std::sync::Mutex;
+
+// Serializes tests that move the global net-edge reject counters.
+static TEST_MUTEX: Mutex<()> = Mutex::new(());
 
 fn intent(
     classification: IntentClassification,
@@ -15,11 +21,13 @@ fn intent(
         fee_usd,
         expected_slippage_usd,
         min_edge_usd,
+        notional_usd: None,
     }
 }
 
 #[test]
 fn test_net_edge_gate_blocks_when_fees_plus_slippage() {
+    let _guard = TEST_MUTEX.lock().expect("net edge test mutex");
     let open_intent = intent(
         IntentClassification::Open,
         Some(2.0),
@@ -37,6 +45,7 @@ fn test_net_edge_gate_blocks_when_fees_plus_slippage() {
 
 #[test]
 fn test_net_edge_gate_rejects_low_edge() {
+    let _guard = TEST_MUTEX.lock().expect("net edge test mutex");
     let open_intent = intent(
         IntentClassification::Open,
         Some(1.0),
@@ -54,6 +63,7 @@ fn test_net_edge_gate_rejects_low_edge() {
 
 #[test]
 fn test_net_edge_gate_rejects_missing_inputs() {
+    let _guard = TEST_MUTEX.lock().expect("net edge test mutex");
     let missing_fee = intent(
         IntentClassification::Open,
         Some(1.0),
@@ -109,6 +119,7 @@ fn test_net_edge_gate_rejects_missing_inputs() {
 
 #[test]
 fn test_net_edge_gate_rejects_when_fees_exceed_gross_edge() {
+    let _guard = TEST_MUTEX.lock().expect("net edge test mutex");
     let open_intent = intent(
         IntentClassification::Open,
         Some(1.0),
@@ -124,3 +135,152 @@ fn test_net_edge_gate_rejects_when_fees_exceed_gross_edge() {
     let net_edge = err.net_edge_usd.expect("net edge should be captured");
     assert!((net_edge - (-0.2)).abs() < 1e-9);
 }
+
+#[test]
+fn test_net_edge_gate_reports_net_edge_bps_and_threshold() {
+    let _guard = TEST_MUTEX.lock().expect("net edge test mutex");
+    // net = 10 - 2 - 1 = 7 USD on 10_000 USD notional = 7 bps
+    let passing = NetEdgeGateIntent {
+        notional_usd: Some(10_000.0),
+        ..intent(
+            IntentClassification::Open,
+            Some(10.0),
+            Some(2.0),
+            Some(1.0),
+            Some(5.0),
+        )
+    };
+    let outcome = evaluate_net_edge_gate(&passing).expect("edge above threshold passes");
+    assert!((outcome.net_edge_bps.expect("bps reported") - 7.0).abs() < 1e-9);
+    assert_eq!(outcome.min_edge_usd, Some(5.0));
+
+    // net = 4 - 2 - 1 = 1 USD on 10_000 USD notional = 1 bps, below 5 USD threshold
+    let failing = NetEdgeGateIntent {
+        notional_usd: Some(10_000.0),
+        ..intent(
+            IntentClassification::Open,
+            Some(4.0),
+            Some(2.0),
+            Some(1.0),
+            Some(5.0),
+        )
+    };
+    let err = evaluate_net_edge_gate(&failing).expect_err("edge below threshold rejects");
+    assert_eq!(err.reason, NetEdgeRejectReason::NetEdgeTooLow);
+    assert!((err.net_edge_bps.expect("bps reported") - 1.0).abs() < 1e-9);
+    assert_eq!(err.min_edge_usd, Some(5.0));
+
+    // Without notional the bps view is unavailable but the USD edge still is
+    let no_notional = intent(
+        IntentClassification::Open,
+        Some(10.0),
+        Some(2.0),
+        Some(1.0),
+        Some(5.0),
+    );
+    let outcome = evaluate_net_edge_gate(&no_notional).expect("passes");
+    assert_eq!(outcome.net_edge_bps, None);
+}
+
+#[test]
+fn test_net_edge_reject_total_increments_only_on_reject() {
+    let _guard = TEST_MUTEX.lock().expect("net edge test mutex");
+    let passing = intent(
+        IntentClassification::Open,
+        Some(10.0),
+        Some(2.0),
+        Some(1.0),
+        Some(5.0),
+    );
+    let failing = intent(
+        IntentClassification::Open,
+        Some(4.0),
+        Some(2.0),
+        Some(1.0),
+        Some(5.0),
+    );
+
+    let before = net_edge_reject_total(NetEdgeRejectReason::NetEdgeTooLow);
+    evaluate_net_edge_gate(&passing).expect("passes");
+    assert_eq!(
+        net_edge_reject_total(NetEdgeRejectReason::NetEdgeTooLow),
+        before
+    );
+    evaluate_net_edge_gate(&failing).expect_err("rejects");
+    assert_eq!(
+        net_edge_reject_total(NetEdgeRejectReason::NetEdgeTooLow),
+        before + 1
+    );
+}
+
+fn ask_book(levels: &[(f64, f64)]) -> L2BookSnapshot {
+    L2BookSnapshot {
+        bids: vec![],
+        asks: levels
+            .iter()
+            .map(|&(price, qty)| L2BookLevel { price, qty })
+            .collect(),
+        ts_ms: 1_000,
+    }
+}
+
+fn fee_snapshot() -> FeeModelSnapshot {
+    FeeModelSnapshot {
+        fee_tier: 1,
+        maker_fee_rate: 0.0001,
+        taker_fee_rate: 0.0005,
+        settlement_fee_bps: None,
+        fee_model_cached_at_ts_ms: Some(1_000),
+    }
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+#[test]
+fn test_expected_net_edge_decomposes_for_liquid_book() {
+    let intent = ExpectedEdgeIntent {
+        side: Side::Buy,
+        order_qty: 1.0,
+        fair_price: 101.0,
+        is_maker: false,
+    };
+
+    let edge = expected_net_edge(&intent, &ask_book(&[(100.0, 10.0)]), &fee_snapshot())
+        .expect("liquid book fills");
+
+    assert_close(edge.gross_bps, 100.0);
+    assert_close(edge.slippage_bps, 0.0);
+    assert_close(edge.fee_bps, 5.0);
+    assert_close(edge.net_bps, 95.0);
+    assert_close(
+        edge.net_bps,
+        edge.gross_bps - edge.slippage_bps - edge.fee_bps,
+    );
+}
+
+#[test]
+fn test_expected_net_edge_decomposes_for_thin_book() {
+    let book = ask_book(&[(100.0, 1.0), (101.0, 1.0)]);
+    let intent = ExpectedEdgeIntent {
+        side: Side::Buy,
+        order_qty: 2.0,
+        fair_price: 101.0,
+        is_maker: true,
+    };
+
+    let edge = expected_net_edge(&intent, &book, &fee_snapshot()).expect("thin book fills");
+
+    assert_close(edge.wap, 100.5);
+    assert_close(edge.gross_bps, 100.0);
+    assert_close(edge.slippage_bps, 50.0);
+    assert_close(edge.fee_bps, 1.0);
+    assert_close(edge.net_bps, 49.0);
+
+    let too_large = ExpectedEdgeIntent {
+        order_qty: 3.0,
+        ..intent
+    };
+    assert_eq!(expected_net_edge(&too_large, &book, &fee_snapshot()), None);
+}
@@ -0,0 +1,69 @@
+use soldier_core::execution::{
+    BuildOrderIntentError, IntentNotional, OrderIntentBuilder, OrderType, OrderTypeGuardConfig,
+    Side, TriggerType, preflight_intent,
+};
+use soldier_core::venue::InstrumentKind;
+
+#[test]
+fn test_order_intent_builder_complete_build_succeeds() {
+    let intent = OrderIntentBuilder::new()
+        .price(50_000.0)
+        .qty(0.5)
+        .side(Side::Sell)
+        .instrument_kind(InstrumentKind::LinearFuture)
+        .reduce_only(true)
+        .build()
+        .expect("all required fields set");
+
+    assert_eq!(intent.instrument_kind, InstrumentKind::LinearFuture);
+    assert_eq!(intent.order_type, OrderType::Limit);
+    assert_eq!(intent.side, Some(Side::Sell));
+    assert_eq!(intent.limit_price, Some(50_000.0));
+    assert_eq!(intent.reduce_only, Some(true));
+    assert_eq!(
+        intent.notional,
+        Some(IntentNotional {
+            qty: 0.5,
+            price: 50_000.0,
+            contract_multiplier: 1.0,
+        })
+    );
+    preflight_intent(&intent, OrderTypeGuardConfig::default()).expect("built intent passes");
+}
+
+#[test]
+fn test_order_intent_builder_stop_without_trigger_price_fails() {
+    let builder = OrderIntentBuilder::new()
+        .instrument_kind(InstrumentKind::Perpetual)
+        .side(Side::Buy)
+        .qty(1.0)
+        .price(100.0)
+        .order_type(OrderType::StopLimit);
+
+    assert_eq!(
+        builder.clone().build(),
+        Err(BuildOrderIntentError::MissingField {
+            field: "trigger_price"
+        })
+    );
+
+    let intent = builder
+        .trigger(TriggerType::MarkPrice, 99.0)
+        .build()
+        .expect("stop with trigger builds");
+    assert_eq!(intent.trigger_price, Some(99.0));
+    assert_eq!(intent.limit_price, Some(100.0));
+}
+
+#[test]
+fn test_order_intent_builder_market_has_no_limit_price() {
+    let intent = OrderIntentBuilder::new()
+        .instrument_kind(InstrumentKind::Perpetual)
+        .side(Side::Buy)
+        .qty(1.0)
+        .price(100.0)
+        .order_type(OrderType::Market)
+        .build()
+        .expect("market intent");
+    assert_eq!(intent.limit_price, None);
+}
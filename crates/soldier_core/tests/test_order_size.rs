@@ -1,5 +1,6 @@
 use soldier_core::execution::{
-    OrderSize, RejectReason, contracts_amount_matches, map_order_size_to_deribit_amount,
+    OrderSize, OrderSizeError, RejectReason, contracts_amount_matches,
+    map_order_size_to_deribit_amount,
 };
 use soldier_core::risk::RiskState;
 use soldier_core::venue::InstrumentKind;
@@ -93,3 +94,46 @@ fn rejects_contract_mismatch_in_dispatch_map() {
     let mismatch_delta = err.mismatch_delta.expect("mismatch delta missing");
     assert!((mismatch_delta - 0.1).abs() < 1e-9);
 }
+
+#[test]
+fn test_order_size_rejects_non_finite_inputs() {
+    let cases = [
+        (InstrumentKind::Option, Some(f64::NAN), None, 100_000.0),
+        (
+            InstrumentKind::LinearFuture,
+            Some(f64::INFINITY),
+            None,
+            100_000.0,
+        ),
+        (InstrumentKind::Option, Some(0.3), None, f64::NAN),
+        (InstrumentKind::Option, Some(0.3), None, f64::INFINITY),
+        (InstrumentKind::Perpetual, None, Some(f64::NAN), 100_000.0),
+        (
+            InstrumentKind::InverseFuture,
+            None,
+            Some(f64::NEG_INFINITY),
+            100_000.0,
+        ),
+        // Finite inputs whose product overflows
+        (InstrumentKind::Option, Some(f64::MAX), None, 100_000.0),
+    ];
+
+    for (instrument_kind, qty_coin, qty_usd, index_price) in cases {
+        let err = OrderSize::try_new(instrument_kind, None, qty_coin, qty_usd, index_price)
+            .expect_err("non-finite sizing must reject");
+        assert_eq!(
+            err,
+            OrderSizeError::NonFinite,
+            "kind={instrument_kind:?} qty_coin={qty_coin:?} qty_usd={qty_usd:?} index={index_price}"
+        );
+    }
+}
+
+#[test]
+fn test_contracts_amount_matches_rejects_non_finite() {
+    assert!(!contracts_amount_matches(f64::NAN, 100, 10.0));
+    assert!(!contracts_amount_matches(1_000.0, 100, f64::NAN));
+    assert!(!contracts_amount_matches(f64::INFINITY, 100, 10.0));
+    // i64::MAX contracts at f64::MAX multiplier overflows the expected amount
+    assert!(!contracts_amount_matches(f64::MAX, i64::MAX, f64::MAX));
+}
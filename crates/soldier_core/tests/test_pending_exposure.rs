@@ -2,7 +2,7 @@
 //!
 //! Validates CONTRACT.md §1.4.2.1 acceptance criteria.
 
-use soldier_core::risk::{PendingExposureTracker, ReserveResult};
+use soldier_core::risk::{BudgetScope, PendingExposureTracker, ReserveResult};
 
 /// AT-225: GIVEN 5 concurrent opens with identical current_delta=0
 /// WHEN PendingExposure reservation runs
@@ -222,3 +222,88 @@ fn test_negative_delta_reservations() {
     // Pending should be positive (absolute value)
     assert_eq!(tracker.get_pending_delta("BTC-PERP"), 50.0);
 }
+
+#[test]
+fn test_project_net_delta_sums_realized_pending_and_resting_orders() {
+    use soldier_core::risk::{IntentSide, OpenOrder, project_net_delta};
+
+    let tracker = PendingExposureTracker::new(None);
+    tracker.register_instrument("BTC-PERP".to_string(), Some(100.0));
+    assert_eq!(
+        tracker.reserve("intent-1".to_string(), "BTC-PERP", 3.0, 10.0),
+        ReserveResult::Reserved
+    );
+    assert_eq!(
+        tracker.reserve("intent-2".to_string(), "BTC-PERP", -1.0, 10.0),
+        ReserveResult::Reserved
+    );
+    let open_orders = vec![
+        OpenOrder {
+            instrument_id: "BTC-PERP".to_string(),
+            side: IntentSide::Sell,
+            remaining_delta: 4.0,
+        },
+        OpenOrder {
+            instrument_id: "ETH-PERP".to_string(),
+            side: IntentSide::Buy,
+            remaining_delta: 50.0,
+        },
+    ];
+
+    // 10 long + (3 - 1) pending - 4 resting sell; ETH order is ignored.
+    let projected = project_net_delta("BTC-PERP", 10.0, &tracker, &open_orders);
+    assert_eq!(projected, 8.0);
+    assert_eq!(tracker.get_pending_delta("BTC-PERP"), 4.0);
+}
+
+#[test]
+fn test_reserve_group_commits_all_legs_when_all_fit() {
+    let tracker = PendingExposureTracker::new(Some(100.0));
+    tracker.register_instrument("BTC-PERP".to_string(), Some(50.0));
+    tracker.register_instrument("ETH-PERP".to_string(), Some(50.0));
+
+    tracker
+        .reserve_group(&[
+            ("group-1:0".to_string(), "BTC-PERP", 20.0, 0.0),
+            ("group-1:1".to_string(), "ETH-PERP", -30.0, 0.0),
+        ])
+        .expect("group fits");
+
+    assert_eq!(tracker.get_pending_delta("BTC-PERP"), 20.0);
+    assert_eq!(tracker.get_pending_delta("ETH-PERP"), 30.0);
+    assert_eq!(tracker.get_global_pending_delta(), 50.0);
+}
+
+#[test]
+fn test_reserve_group_commits_nothing_when_a_leg_exceeds_budget() {
+    let tracker = PendingExposureTracker::new(Some(100.0));
+    tracker.register_instrument("BTC-PERP".to_string(), Some(50.0));
+    tracker.register_instrument("ETH-PERP".to_string(), Some(50.0));
+
+    let reject = tracker
+        .reserve_group(&[
+            ("group-1:0".to_string(), "BTC-PERP", 20.0, 0.0),
+            ("group-1:1".to_string(), "ETH-PERP", 45.0, 10.0),
+        ])
+        .expect_err("leg 2 exceeds the ETH budget");
+
+    assert_eq!(reject.leg_idx, 1);
+    assert_eq!(reject.instrument_id, "ETH-PERP");
+    assert_eq!(reject.scope, BudgetScope::Instrument);
+    assert_eq!(reject.requested, 45.0);
+    assert_eq!(reject.available, 40.0);
+    assert_eq!(tracker.get_pending_delta("BTC-PERP"), 0.0);
+    assert_eq!(tracker.get_pending_delta("ETH-PERP"), 0.0);
+
+    let reject = tracker
+        .reserve_group(&[
+            ("group-2:0".to_string(), "BTC-PERP", 50.0, 0.0),
+            ("group-2:1".to_string(), "ETH-PERP", 50.0, 0.0),
+            ("group-2:2".to_string(), "SOL-PERP", 10.0, 0.0),
+        ])
+        .expect_err("leg 3 exceeds the global budget");
+    assert_eq!(reject.leg_idx, 2);
+    assert_eq!(reject.scope, BudgetScope::Global);
+    assert_eq!(reject.available, 0.0);
+    assert_eq!(tracker.get_global_pending_delta(), 0.0);
+}
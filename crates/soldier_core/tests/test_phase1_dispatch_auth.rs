@@ -1,23 +1,18 @@
+mod common;
+
 use std::sync::atomic::Ordering;
 
 use soldier_core::execution::{
     BuildOrderIntentContext, BuildOrderIntentObservers, BuildOrderIntentOutcome,
     BuildOrderIntentRejectReason, InstrumentQuantization, IntentClassification, L2BookLevel,
-    L2BookSnapshot, LiquidityGateConfig, OrderIntent, OrderType, OrderTypeGuardConfig,
-    RecordIntentOutcome, Side, build_order_intent, take_build_order_intent_outcome,
-    take_dispatch_trace, with_build_order_intent_context,
+    L2BookSnapshot, LiquidityGateConfig, OrderIntent, OrderTypeGuardConfig, RecordIntentOutcome,
+    Side, build_order_intent, take_build_order_intent_outcome, take_dispatch_trace,
+    with_build_order_intent_context,
 };
 use soldier_core::risk::{FeeModelSnapshot, FeeStalenessConfig, RiskState};
-use soldier_core::venue::InstrumentKind;
 
 fn base_intent() -> OrderIntent {
-    OrderIntent {
-        instrument_kind: InstrumentKind::Perpetual,
-        order_type: OrderType::Limit,
-        trigger: None,
-        trigger_price: None,
-        linked_order_type: None,
-    }
+    common::perp_limit_intent()
 }
 
 fn sample_book(now_ms: u64) -> L2BookSnapshot {
@@ -54,6 +49,7 @@ fn context_for(
             fee_tier: 1,
             maker_fee_rate: 0.0002,
             taker_fee_rate: 0.0005,
+            settlement_fee_bps: None,
             fee_model_cached_at_ts_ms: Some(now_ms),
         },
         fee_staleness_config: FeeStalenessConfig::default(),
@@ -107,7 +103,7 @@ fn close_hedge_cancel_allowed_when_risk_state_degraded() {
         let observers = BuildOrderIntentObservers::new();
         let result = with_build_order_intent_context(
             context_for(classification, RiskState::Degraded, observers.clone()),
-            || build_order_intent(intent, OrderTypeGuardConfig::default()),
+            || build_order_intent(intent.clone(), OrderTypeGuardConfig::default()),
         );
         assert!(result.is_ok());
         let outcome = take_build_order_intent_outcome().expect("expected outcome");
@@ -1,16 +1,15 @@
+mod common;
+
 use soldier_core::execution::{
-    OrderIntent, OrderType, OrderTypeGuardConfig, PostOnlyIntent, PostOnlyRejectReason,
-    PreflightGuardRejectReason, Side, preflight_intent_with_post_only,
+    OrderIntent, OrderTypeGuardConfig, PostOnlyIntent, PostOnlyRejectReason,
+    PreflightGuardRejectReason, Side, preflight_intent_with_post_only, reprice_post_only,
 };
 use soldier_core::venue::InstrumentKind;
 
 fn base_intent(instrument_kind: InstrumentKind) -> OrderIntent {
     OrderIntent {
         instrument_kind,
-        order_type: OrderType::Limit,
-        trigger: None,
-        trigger_price: None,
-        linked_order_type: None,
+        ..common::perp_limit_intent()
     }
 }
 
@@ -48,3 +47,56 @@ fn test_post_only_non_crossing_allows() {
     preflight_intent_with_post_only(&intent, OrderTypeGuardConfig::default(), &post_only)
         .expect("expected non-crossing post-only to pass");
 }
+
+#[test]
+fn test_reprice_post_only_resolves_on_stable_book() {
+    let intent = PostOnlyIntent {
+        post_only: true,
+        side: Side::Buy,
+        limit_price: 101.0,
+        best_bid: None,
+        best_ask: None,
+    };
+    let mut reads = 0;
+
+    let repriced = reprice_post_only(
+        &intent,
+        || {
+            reads += 1;
+            (Some(100.0), Some(100.5))
+        },
+        0.5,
+        3,
+    )
+    .expect("stable book should reprice");
+
+    assert_eq!(reads, 1, "resolved on the first try");
+    assert_eq!(repriced.limit_price, 100.0);
+    assert_eq!(repriced.best_ask, Some(100.5));
+}
+
+#[test]
+fn test_reprice_post_only_exhausts_attempts_on_crossed_book() {
+    let intent = PostOnlyIntent {
+        post_only: true,
+        side: Side::Sell,
+        limit_price: 99.0,
+        best_bid: None,
+        best_ask: None,
+    };
+    let mut reads = 0;
+
+    let err = reprice_post_only(
+        &intent,
+        || {
+            reads += 1;
+            (Some(101.0), Some(100.0))
+        },
+        0.5,
+        4,
+    )
+    .expect_err("crossed book cannot be priced");
+
+    assert_eq!(reads, 4);
+    assert_eq!(err.reason, PostOnlyRejectReason::PostOnlyWouldCross);
+}
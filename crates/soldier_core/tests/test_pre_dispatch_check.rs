@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use soldier_core::risk::{
+    ChurnBreaker, ChurnKey, GlobalBudgetConfig, GlobalExposureBudget, InstrumentExposure,
+    IntentSide, InventorySkewConfig, LatchReason, PendingExposureTracker, RiskContext, RiskIntent,
+    RiskReason, RiskState, SelfImpactConfig, SelfImpactGuard, SelfImpactKey, TradeAggregates,
+    pre_dispatch_check,
+};
+
+const NOW_MS: u64 = 1_000_000;
+const INSTRUMENT: &str = "BTC-PERP";
+
+struct Fixture {
+    churn_breaker: ChurnBreaker,
+    self_impact_guard: SelfImpactGuard,
+    trade_aggregates: TradeAggregates,
+    global_budget: GlobalExposureBudget,
+    portfolio_exposures: HashMap<String, InstrumentExposure>,
+    pending_exposure: PendingExposureTracker,
+    churn_key: ChurnKey,
+    self_impact_key: SelfImpactKey,
+    now: Instant,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let pending_exposure = PendingExposureTracker::new(None);
+        pending_exposure.register_instrument(INSTRUMENT.to_string(), Some(10.0));
+        Self {
+            churn_breaker: ChurnBreaker::new(),
+            self_impact_guard: SelfImpactGuard::new(),
+            trade_aggregates: TradeAggregates {
+                public_notional_usd: 1_000_000.0,
+                self_notional_usd: 0.0,
+                public_trades_last_update_ts_ms: Some(NOW_MS),
+            },
+            global_budget: budget(1_000_000.0),
+            portfolio_exposures: HashMap::new(),
+            pending_exposure,
+            churn_key: ChurnKey {
+                strategy_id: "strat".to_string(),
+                structure_fingerprint: "BTC-PERP-legs1".to_string(),
+            },
+            self_impact_key: SelfImpactKey {
+                strategy_id: "strat".to_string(),
+                structure_fingerprint: "BTC-PERP-legs1".to_string(),
+            },
+            now: Instant::now(),
+        }
+    }
+
+    fn ctx(&self) -> RiskContext<'_> {
+        RiskContext {
+            churn_breaker: &self.churn_breaker,
+            self_impact_guard: &self.self_impact_guard,
+            self_impact_config: SelfImpactConfig::default(),
+            trade_aggregates: self.trade_aggregates,
+            inventory_skew_config: InventorySkewConfig::default(),
+            global_budget: &self.global_budget,
+            portfolio_exposures: &self.portfolio_exposures,
+            pending_exposure: &self.pending_exposure,
+            now_ms: NOW_MS,
+            now: self.now,
+        }
+    }
+
+    /// Sell 1 contract while long 5 of a 10 limit: risk-reducing, so skew loosens the edge.
+    fn intent(&self) -> RiskIntent<'_> {
+        RiskIntent {
+            reservation_id: "intent-1",
+            instrument_id: INSTRUMENT,
+            side: IntentSide::Sell,
+            churn_key: &self.churn_key,
+            self_impact_key: &self.self_impact_key,
+            delta_impact: 1.0,
+            delta_usd: -500.0,
+            current_delta: 5.0,
+            delta_limit: Some(10.0),
+            min_edge_usd: 10.0,
+            tick_size_usd: 0.5,
+        }
+    }
+}
+
+fn budget(limit_usd: f64) -> GlobalExposureBudget {
+    GlobalExposureBudget::new(GlobalBudgetConfig {
+        portfolio_delta_limit_usd: limit_usd,
+        portfolio_delta_exit_limit_usd: None,
+    })
+}
+
+#[test]
+fn test_pre_dispatch_all_pass_applies_inventory_skew_and_reserves() {
+    let fixture = Fixture::new();
+
+    let decision = pre_dispatch_check(&fixture.intent(), &fixture.ctx());
+
+    assert!(decision.allowed, "{decision:?}");
+    assert_eq!(decision.risk_state, RiskState::Healthy);
+    // bias = 5/10 = 0.5; Sell while long => multiplier 1 - 0.5*0.5 = 0.75.
+    assert_eq!(decision.adjusted_min_edge_usd, Some(7.5));
+    assert_eq!(decision.bias_ticks, 2);
+    assert_eq!(
+        decision.reasons,
+        vec![RiskReason::InventorySkewAdjusted {
+            adjusted_min_edge_usd: 7.5,
+            bias_ticks: 2,
+        }]
+    );
+    assert_eq!(fixture.pending_exposure.get_pending_delta(INSTRUMENT), 1.0);
+}
+
+#[test]
+fn test_pre_dispatch_rejects_on_churn_breaker_without_reserving() {
+    let fixture = Fixture::new();
+    for _ in 0..3 {
+        fixture
+            .churn_breaker
+            .record_flatten(fixture.churn_key.clone(), fixture.now);
+    }
+
+    let decision = pre_dispatch_check(&fixture.intent(), &fixture.ctx());
+
+    assert!(!decision.allowed);
+    assert!(matches!(
+        decision.reasons.as_slice(),
+        [RiskReason::ChurnBreakerActive {
+            retry_after_ms: Some(_)
+        }]
+    ));
+    assert_eq!(decision.adjusted_min_edge_usd, None);
+    assert_eq!(fixture.pending_exposure.get_pending_delta(INSTRUMENT), 0.0);
+}
+
+#[test]
+fn test_pre_dispatch_rejects_on_stale_trade_feed_as_degraded() {
+    let mut fixture = Fixture::new();
+    fixture.trade_aggregates.public_trades_last_update_ts_ms = None;
+
+    let decision = pre_dispatch_check(&fixture.intent(), &fixture.ctx());
+
+    assert!(!decision.allowed);
+    assert_eq!(decision.risk_state, RiskState::Degraded);
+    assert_eq!(
+        decision.reasons,
+        vec![RiskReason::SelfImpactLatched(
+            LatchReason::WsTradesGapReconcileRequired
+        )]
+    );
+}
+
+#[test]
+fn test_pre_dispatch_rejects_on_missing_delta_limit() {
+    let fixture = Fixture::new();
+    let intent = RiskIntent {
+        delta_limit: None,
+        ..fixture.intent()
+    };
+
+    let decision = pre_dispatch_check(&intent, &fixture.ctx());
+
+    assert!(!decision.allowed);
+    assert_eq!(decision.risk_state, RiskState::Degraded);
+    assert_eq!(
+        decision.reasons,
+        vec![RiskReason::InventorySkew(
+            "InventorySkewDeltaLimitMissing".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_pre_dispatch_rejects_on_global_budget_after_skew_adjustment() {
+    let mut fixture = Fixture::new();
+    fixture.global_budget = budget(100.0);
+
+    let decision = pre_dispatch_check(&fixture.intent(), &fixture.ctx());
+
+    assert!(!decision.allowed);
+    assert!(matches!(
+        decision.reasons.as_slice(),
+        [
+            RiskReason::InventorySkewAdjusted { .. },
+            RiskReason::GlobalExposureBudgetExceeded { limit, .. },
+        ] if *limit == 100.0
+    ));
+    assert_eq!(fixture.pending_exposure.get_pending_delta(INSTRUMENT), 0.0);
+}
+
+#[test]
+fn test_pre_dispatch_rejects_on_pending_exposure_budget() {
+    let fixture = Fixture::new();
+    let intent = RiskIntent {
+        delta_impact: 6.0,
+        ..fixture.intent()
+    };
+
+    let decision = pre_dispatch_check(&intent, &fixture.ctx());
+
+    assert!(!decision.allowed);
+    assert_eq!(
+        decision.reasons.last(),
+        Some(&RiskReason::PendingExposureBudgetExceeded {
+            requested: 6.0,
+            available: 5.0,
+        })
+    );
+}
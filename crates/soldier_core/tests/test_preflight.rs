@@ -1,16 +1,17 @@
+mod common;
+
 use soldier_core::execution::{
-    BuildOrderIntentError, LinkedOrderType, OrderIntent, OrderType, OrderTypeGuardConfig,
-    OrderTypeRejectReason, TriggerType, build_order_intent, preflight_intent,
+    BuildOrderIntentError, IntentNotional, LinkedOrderGroup, LinkedOrderType, OpenBudgetReject,
+    OrderIntent, OrderType, OrderTypeGuardConfig, OrderTypeRejectReason, PriceBandReject, Side,
+    TriggerReference, TriggerType, build_order_intent, check_open_order_budget, preflight_intent,
+    preflight_linked_group, preflight_reject_total, price_band_check, select_order_type,
 };
-use soldier_core::venue::InstrumentKind;
+use soldier_core::venue::{FeatureFlags, InstrumentKind, InstrumentMetadata, VenueCapabilities};
 
 fn base_intent(instrument_kind: InstrumentKind) -> OrderIntent {
     OrderIntent {
         instrument_kind,
-        order_type: OrderType::Limit,
-        trigger: None,
-        trigger_price: None,
-        linked_order_type: None,
+        ..common::perp_limit_intent()
     }
 }
 
@@ -162,6 +163,7 @@ fn preflight_linked_order_matrix() {
         let config = OrderTypeGuardConfig {
             linked_orders_supported: case.linked_supported,
             enable_linked_orders_for_bot: case.linked_enabled,
+            ..OrderTypeGuardConfig::default()
         };
         let result = preflight_intent(&intent, config);
         if case.expect_allowed {
@@ -226,9 +228,494 @@ fn preflight_trigger_field_matrix() {
         let intent = OrderIntent {
             trigger: Some(TriggerType::MarkPrice),
             trigger_price: Some(100.0),
+            side: Some(Side::Buy),
+            trigger_reference: Some(TriggerReference {
+                trigger: TriggerType::MarkPrice,
+                reference_price: 99.0,
+                tick_size: 0.5,
+            }),
             ..base_intent(instrument_kind)
         };
         preflight_intent(&intent, OrderTypeGuardConfig::default())
             .expect("non-option trigger fields are allowed by current guard");
     }
 }
+
+fn notional_bounds_config() -> OrderTypeGuardConfig {
+    OrderTypeGuardConfig {
+        min_notional_usd: Some(100.0),
+        max_notional_usd: Some(1_000_000.0),
+        ..OrderTypeGuardConfig::default()
+    }
+}
+
+fn sized_intent(qty: f64, price: f64, contract_multiplier: f64) -> OrderIntent {
+    OrderIntent {
+        notional: Some(IntentNotional {
+            qty,
+            price,
+            contract_multiplier,
+        }),
+        ..base_intent(InstrumentKind::Option)
+    }
+}
+
+#[test]
+fn preflight_rejects_below_min_notional() {
+    // 0.001 * 50_000 * 1 = 50 USD < 100 USD floor
+    let intent = sized_intent(0.001, 50_000.0, 1.0);
+    let before = preflight_reject_total(OrderTypeRejectReason::BelowMinNotional);
+    let err =
+        preflight_intent(&intent, notional_bounds_config()).expect_err("dust order should reject");
+    assert_eq!(err.reason, OrderTypeRejectReason::BelowMinNotional);
+    assert!(preflight_reject_total(OrderTypeRejectReason::BelowMinNotional) > before);
+}
+
+#[test]
+fn preflight_rejects_above_max_notional() {
+    // 25 * 50_000 * 1 = 1.25M USD > 1M USD ceiling
+    let intent = sized_intent(25.0, 50_000.0, 1.0);
+    let err = preflight_intent(&intent, notional_bounds_config())
+        .expect_err("fat-finger order should reject");
+    assert_eq!(err.reason, OrderTypeRejectReason::AboveMaxNotional);
+}
+
+#[test]
+fn preflight_allows_notional_inside_bounds() {
+    // 0.3 * 100_000 * 1 = 30_000 USD
+    let intent = sized_intent(0.3, 100_000.0, 1.0);
+    preflight_intent(&intent, notional_bounds_config()).expect("notional within bounds");
+
+    // Unbounded config ignores notional entirely (including absent sizing)
+    preflight_intent(
+        &base_intent(InstrumentKind::Option),
+        OrderTypeGuardConfig::default(),
+    )
+    .expect("no bounds configured");
+}
+
+#[test]
+fn preflight_notional_missing_fails_closed_when_bounded() {
+    let err = preflight_intent(
+        &base_intent(InstrumentKind::Option),
+        notional_bounds_config(),
+    )
+    .expect_err("unknown notional should reject");
+    assert_eq!(err.reason, OrderTypeRejectReason::NotionalMissing);
+
+    let err = preflight_intent(
+        &sized_intent(f64::NAN, 100.0, 1.0),
+        notional_bounds_config(),
+    )
+    .expect_err("non-finite notional should reject");
+    assert_eq!(err.reason, OrderTypeRejectReason::NotionalMissing);
+}
+
+/// Exit a long: take-profit sell limit at 110, linked stop sell triggering at `stop_trigger`.
+fn sell_oco(stop_trigger: f64) -> LinkedOrderGroup {
+    let primary = OrderIntent {
+        side: Some(Side::Sell),
+        limit_price: Some(110.0),
+        ..base_intent(InstrumentKind::Perpetual)
+    };
+    let stop = OrderIntent {
+        side: Some(Side::Sell),
+        limit_price: Some(stop_trigger - 1.0),
+        trigger: Some(TriggerType::MarkPrice),
+        trigger_price: Some(stop_trigger),
+        trigger_reference: Some(TriggerReference {
+            trigger: TriggerType::MarkPrice,
+            reference_price: 120.0,
+            tick_size: 0.5,
+        }),
+        ..base_intent(InstrumentKind::Perpetual)
+    };
+    LinkedOrderGroup {
+        primary,
+        linked: vec![(LinkedOrderType::Oco, stop)],
+    }
+}
+
+fn linked_venue() -> (VenueCapabilities, FeatureFlags) {
+    (
+        VenueCapabilities {
+            linked_orders_supported: true,
+            ..VenueCapabilities::default()
+        },
+        FeatureFlags {
+            enable_linked_orders_for_bot: true,
+        },
+    )
+}
+
+#[test]
+fn preflight_linked_group_accepts_valid_oco() {
+    let (capabilities, flags) = linked_venue();
+    preflight_linked_group(
+        &sell_oco(95.0),
+        capabilities,
+        flags,
+        OrderTypeGuardConfig::default(),
+    )
+    .expect("stop below take-profit is a valid sell OCO");
+}
+
+#[test]
+fn preflight_linked_group_rejects_stop_on_wrong_side() {
+    let (capabilities, flags) = linked_venue();
+    let before = preflight_reject_total(OrderTypeRejectReason::LinkedOrderTriggerInconsistent);
+    let err = preflight_linked_group(
+        &sell_oco(115.0),
+        capabilities,
+        flags,
+        OrderTypeGuardConfig::default(),
+    )
+    .expect_err("sell stop above the limit would trigger immediately");
+    assert_eq!(
+        err.reason,
+        OrderTypeRejectReason::LinkedOrderTriggerInconsistent
+    );
+    assert!(preflight_reject_total(OrderTypeRejectReason::LinkedOrderTriggerInconsistent) > before);
+
+    let mut missing_side = sell_oco(95.0);
+    missing_side.primary.side = None;
+    let err = preflight_linked_group(
+        &missing_side,
+        capabilities,
+        flags,
+        OrderTypeGuardConfig::default(),
+    )
+    .expect_err("unknown side fails closed");
+    assert_eq!(
+        err.reason,
+        OrderTypeRejectReason::LinkedOrderTriggerInconsistent
+    );
+}
+
+#[test]
+fn preflight_linked_group_rejects_when_venue_forbids_linked() {
+    let (_, flags) = linked_venue();
+    let err = preflight_linked_group(
+        &sell_oco(95.0),
+        VenueCapabilities::default(),
+        flags,
+        OrderTypeGuardConfig::default(),
+    )
+    .expect_err("venue without linked-order support must reject");
+    assert_eq!(err.reason, OrderTypeRejectReason::LinkedOrderTypeForbidden);
+
+    let (capabilities, _) = linked_venue();
+    let err = preflight_linked_group(
+        &sell_oco(95.0),
+        capabilities,
+        FeatureFlags::default(),
+        OrderTypeGuardConfig::default(),
+    )
+    .expect_err("bot flag disabled must reject");
+    assert_eq!(err.reason, OrderTypeRejectReason::LinkedOrderTypeForbidden);
+}
+
+const EXPIRY_MS: u64 = 1_767_225_600_000;
+const SETTLEMENT_CUTOFF_MS: u64 = 15 * 60 * 1000;
+
+fn option_metadata() -> InstrumentMetadata {
+    InstrumentMetadata {
+        instrument_kind: InstrumentKind::Option,
+        tick_size: 0.0005,
+        amount_step: 0.1,
+        min_amount: 0.1,
+        contract_multiplier: 1.0,
+        expiration_ts_ms: None,
+    }
+    .with_expiration_ts_ms(EXPIRY_MS)
+}
+
+fn option_intent_at(now_ms: u64, reduce_only: Option<bool>) -> OrderIntent {
+    OrderIntent {
+        reduce_only,
+        instrument_tradable: Some(option_metadata().is_tradable_at(now_ms, SETTLEMENT_CUTOFF_MS)),
+        ..base_intent(InstrumentKind::Option)
+    }
+}
+
+#[test]
+fn preflight_allows_open_on_live_option() {
+    let now_ms = EXPIRY_MS - 24 * 60 * 60 * 1000;
+    assert!(option_metadata().is_tradable_at(now_ms, SETTLEMENT_CUTOFF_MS));
+    preflight_intent(
+        &option_intent_at(now_ms, None),
+        OrderTypeGuardConfig::default(),
+    )
+    .expect("live option is tradable");
+}
+
+#[test]
+fn preflight_rejects_open_on_expired_or_settling_option() {
+    for now_ms in [EXPIRY_MS + 1, EXPIRY_MS - SETTLEMENT_CUTOFF_MS + 1] {
+        assert!(!option_metadata().is_tradable_at(now_ms, SETTLEMENT_CUTOFF_MS));
+        let err = preflight_intent(
+            &option_intent_at(now_ms, None),
+            OrderTypeGuardConfig::default(),
+        )
+        .expect_err("open on an expired/settling option must reject");
+        assert_eq!(err.reason, OrderTypeRejectReason::InstrumentNotTradable);
+    }
+}
+
+#[test]
+fn preflight_allows_reduce_only_on_expired_option() {
+    preflight_intent(
+        &option_intent_at(EXPIRY_MS + 1, Some(true)),
+        OrderTypeGuardConfig::default(),
+    )
+    .expect("reduce-only close is still allowed");
+}
+
+fn stop_buy(trigger_price: f64) -> OrderIntent {
+    OrderIntent {
+        trigger: Some(TriggerType::MarkPrice),
+        trigger_price: Some(trigger_price),
+        side: Some(Side::Buy),
+        trigger_reference: Some(TriggerReference {
+            trigger: TriggerType::MarkPrice,
+            reference_price: 100.0,
+            tick_size: 0.5,
+        }),
+        ..base_intent(InstrumentKind::Perpetual)
+    }
+}
+
+#[test]
+fn preflight_allows_stop_buy_above_reference_on_grid() {
+    preflight_intent(&stop_buy(101.5), OrderTypeGuardConfig::default())
+        .expect("stop-buy above mark on the tick grid");
+}
+
+#[test]
+fn preflight_rejects_stop_buy_trigger_below_reference() {
+    let before = preflight_reject_total(OrderTypeRejectReason::InvalidTriggerPrice);
+    let err = preflight_intent(&stop_buy(99.5), OrderTypeGuardConfig::default())
+        .expect_err("stop-buy below mark would trigger immediately");
+    assert_eq!(err.reason, OrderTypeRejectReason::InvalidTriggerPrice);
+    assert!(preflight_reject_total(OrderTypeRejectReason::InvalidTriggerPrice) > before);
+
+    let stop_sell = OrderIntent {
+        side: Some(Side::Sell),
+        ..stop_buy(99.5)
+    };
+    preflight_intent(&stop_sell, OrderTypeGuardConfig::default())
+        .expect("stop-sell below mark is valid");
+}
+
+#[test]
+fn preflight_rejects_trigger_without_reference() {
+    let intent = OrderIntent {
+        trigger_reference: None,
+        ..stop_buy(101.5)
+    };
+    let before = preflight_reject_total(OrderTypeRejectReason::InvalidTriggerPrice);
+    let err = preflight_intent(&intent, OrderTypeGuardConfig::default())
+        .expect_err("unverifiable trigger fails closed");
+    assert_eq!(err.reason, OrderTypeRejectReason::InvalidTriggerPrice);
+    assert!(preflight_reject_total(OrderTypeRejectReason::InvalidTriggerPrice) > before);
+}
+
+#[test]
+fn preflight_rejects_off_grid_trigger() {
+    let err = preflight_intent(&stop_buy(101.3), OrderTypeGuardConfig::default())
+        .expect_err("off-grid trigger");
+    assert_eq!(err.reason, OrderTypeRejectReason::InvalidTriggerPrice);
+}
+
+fn banded_limit(limit_price: f64, reference_price: Option<f64>) -> OrderIntent {
+    OrderIntent {
+        side: Some(Side::Buy),
+        limit_price: Some(limit_price),
+        reference_price,
+        ..base_intent(InstrumentKind::Perpetual)
+    }
+}
+
+fn price_band_config() -> OrderTypeGuardConfig {
+    OrderTypeGuardConfig {
+        max_price_deviation_pct: Some(10.0),
+        ..OrderTypeGuardConfig::default()
+    }
+}
+
+#[test]
+fn preflight_allows_limit_price_inside_band() {
+    assert_eq!(price_band_check(105.0, Some(100.0), 10.0), Ok(()));
+    preflight_intent(&banded_limit(95.0, Some(100.0)), price_band_config())
+        .expect("5% from mark is inside a 10% band");
+}
+
+#[test]
+fn preflight_rejects_limit_price_outside_band() {
+    assert!(matches!(
+        price_band_check(150.0, Some(100.0), 10.0),
+        Err(PriceBandReject::OutOfBand { deviation_pct, .. }) if (deviation_pct - 50.0).abs() < 1e-9
+    ));
+
+    let before = preflight_reject_total(OrderTypeRejectReason::PriceOutOfBand);
+    let err = preflight_intent(&banded_limit(150.0, Some(100.0)), price_band_config())
+        .expect_err("50% away with a 10% band");
+    assert_eq!(err.reason, OrderTypeRejectReason::PriceOutOfBand);
+    assert!(preflight_reject_total(OrderTypeRejectReason::PriceOutOfBand) > before);
+
+    // Without a configured band the same intent passes.
+    preflight_intent(
+        &banded_limit(150.0, Some(100.0)),
+        OrderTypeGuardConfig::default(),
+    )
+    .expect("band disabled");
+}
+
+#[test]
+fn preflight_price_band_fails_closed_without_reference() {
+    assert_eq!(
+        price_band_check(100.0, None, 10.0),
+        Err(PriceBandReject::MissingReference)
+    );
+    let before = preflight_reject_total(OrderTypeRejectReason::PriceReferenceMissing);
+    let err = preflight_intent(&banded_limit(100.0, None), price_band_config())
+        .expect_err("no reference price");
+    assert_eq!(err.reason, OrderTypeRejectReason::PriceReferenceMissing);
+    assert!(preflight_reject_total(OrderTypeRejectReason::PriceReferenceMissing) > before);
+}
+
+#[test]
+fn preflight_price_band_fails_closed_without_limit_price() {
+    let unpriced = OrderIntent {
+        limit_price: None,
+        ..banded_limit(100.0, Some(100.0))
+    };
+    let before = preflight_reject_total(OrderTypeRejectReason::LimitPriceMissing);
+    let err = preflight_intent(&unpriced, price_band_config()).expect_err("no limit price");
+    assert_eq!(err.reason, OrderTypeRejectReason::LimitPriceMissing);
+    assert!(preflight_reject_total(OrderTypeRejectReason::LimitPriceMissing) > before);
+
+    // Without a configured band the limit price is not needed here.
+    preflight_intent(&unpriced, OrderTypeGuardConfig::default()).expect("band disabled");
+}
+
+#[test]
+fn select_order_type_passes_supported_type_through() {
+    let capabilities = VenueCapabilities {
+        market_orders_supported: true,
+        ..VenueCapabilities::default()
+    };
+    assert_eq!(
+        select_order_type(OrderType::Market, &capabilities),
+        Ok(OrderType::Market)
+    );
+    assert_eq!(
+        select_order_type(OrderType::Limit, &VenueCapabilities::default()),
+        Ok(OrderType::Limit)
+    );
+}
+
+#[test]
+fn select_order_type_rejects_unsupported_type() {
+    let before = preflight_reject_total(OrderTypeRejectReason::OrderTypeUnsupported);
+    let err = select_order_type(OrderType::StopMarket, &VenueCapabilities::default())
+        .expect_err("stops unsupported");
+    assert_eq!(err.reason, OrderTypeRejectReason::OrderTypeUnsupported);
+    assert!(preflight_reject_total(OrderTypeRejectReason::OrderTypeUnsupported) > before);
+}
+
+#[test]
+fn select_order_type_does_not_substitute_a_supported_type() {
+    let capabilities = VenueCapabilities {
+        stop_limit_orders_supported: true,
+        ..VenueCapabilities::default()
+    };
+    assert_eq!(
+        select_order_type(OrderType::StopLimit, &capabilities),
+        Ok(OrderType::StopLimit)
+    );
+    let err = select_order_type(OrderType::StopMarket, &capabilities)
+        .expect_err("no fallback to stop-limit");
+    assert_eq!(err.reason, OrderTypeRejectReason::OrderTypeUnsupported);
+}
+
+fn open_budget_config() -> OrderTypeGuardConfig {
+    OrderTypeGuardConfig {
+        max_open_orders: Some(3),
+        ..OrderTypeGuardConfig::default()
+    }
+}
+
+fn intent_with_open_count(open_order_count: Option<u32>, reduce_only: Option<bool>) -> OrderIntent {
+    OrderIntent {
+        instrument_id: Some("BTC-PERPETUAL".to_string()),
+        open_order_count,
+        reduce_only,
+        ..base_intent(InstrumentKind::Perpetual)
+    }
+}
+
+#[test]
+fn preflight_allows_open_under_order_budget() {
+    assert_eq!(check_open_order_budget("BTC-PERPETUAL", 2, 3), Ok(()));
+    preflight_intent(&intent_with_open_count(Some(2), None), open_budget_config())
+        .expect("2 resting orders under a cap of 3");
+}
+
+#[test]
+fn preflight_rejects_open_at_order_budget() {
+    assert_eq!(
+        check_open_order_budget("BTC-PERPETUAL", 3, 3),
+        Err(OpenBudgetReject {
+            instrument: "BTC-PERPETUAL".to_string(),
+            current_open_count: 3,
+            max_open_orders: 3,
+        })
+    );
+
+    let before = preflight_reject_total(OrderTypeRejectReason::OpenOrderBudgetExceeded);
+    let err = preflight_intent(&intent_with_open_count(Some(3), None), open_budget_config())
+        .expect_err("cap reached");
+    assert_eq!(err.reason, OrderTypeRejectReason::OpenOrderBudgetExceeded);
+    assert!(preflight_reject_total(OrderTypeRejectReason::OpenOrderBudgetExceeded) > before);
+
+    // An unknown count or instrument fails closed for opens.
+    let err = preflight_intent(&intent_with_open_count(None, None), open_budget_config())
+        .expect_err("count unknown");
+    assert_eq!(err.reason, OrderTypeRejectReason::OpenOrderBudgetExceeded);
+    let unnamed = OrderIntent {
+        instrument_id: None,
+        ..intent_with_open_count(Some(0), None)
+    };
+    let err = preflight_intent(&unnamed, open_budget_config()).expect_err("instrument unknown");
+    assert_eq!(err.reason, OrderTypeRejectReason::OpenOrderBudgetExceeded);
+}
+
+#[test]
+fn preflight_order_budget_is_per_instrument_not_per_kind() {
+    // BTC-PERPETUAL is at its cap; ETH-PERPETUAL, the same kind, still has headroom.
+    let err = preflight_intent(&intent_with_open_count(Some(3), None), open_budget_config())
+        .expect_err("BTC-PERPETUAL at cap");
+    assert_eq!(err.reason, OrderTypeRejectReason::OpenOrderBudgetExceeded);
+
+    let eth = OrderIntent {
+        instrument_id: Some("ETH-PERPETUAL".to_string()),
+        ..intent_with_open_count(Some(1), None)
+    };
+    assert_eq!(eth.instrument_kind, InstrumentKind::Perpetual);
+    preflight_intent(&eth, open_budget_config()).expect("ETH-PERPETUAL under its own cap");
+}
+
+#[test]
+fn preflight_exempts_reduce_only_from_order_budget() {
+    preflight_intent(
+        &intent_with_open_count(Some(3), Some(true)),
+        open_budget_config(),
+    )
+    .expect("reduce-only at the cap");
+    preflight_intent(
+        &intent_with_open_count(Some(10), None),
+        OrderTypeGuardConfig::default(),
+    )
+    .expect("cap disabled");
+}
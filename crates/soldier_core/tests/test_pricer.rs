@@ -1,4 +1,4 @@
-use soldier_core::execution::{PricerIntent, RejectReason, Side, price_ioc_limit};
+use soldier_core::execution::{PricerIntent, RejectReason, Side, TickRounding, price_ioc_limit};
 
 fn intent(
     side: Side,
@@ -15,6 +15,8 @@ fn intent(
         fee_estimate_usd,
         min_edge_usd,
         qty,
+        tick_size: None,
+        tick_rounding: TickRounding::default(),
     }
 }
 
@@ -85,3 +87,56 @@ fn test_pricer_clamps_limit_for_min_edge_sell() {
     );
     assert!(realized_edge + 1e-9 >= open.min_edge_usd);
 }
+
+#[test]
+fn test_pricer_tick_rounding_favors_passive_side() {
+    // Buy: proposed = 100 - 0.5 * (10 - 1) / 1 = 95.5, between 95 and 96 on a 1.0 grid
+    let buy = PricerIntent {
+        tick_size: Some(1.0),
+        ..intent(Side::Buy, 100.0, 10.0, 1.0, 1.0, 1.0)
+    };
+    let outcome = price_ioc_limit(&buy).expect("buy priced");
+    assert_eq!(outcome.limit_price, 95.0, "buy rounds toward cheaper");
+
+    // Sell: proposed = 100 + 4.5 = 104.5, between 104 and 105
+    let sell = PricerIntent {
+        tick_size: Some(1.0),
+        ..intent(Side::Sell, 100.0, 10.0, 1.0, 1.0, 1.0)
+    };
+    let outcome = price_ioc_limit(&sell).expect("sell priced");
+    assert_eq!(outcome.limit_price, 105.0, "sell rounds toward dearer");
+}
+
+#[test]
+fn test_pricer_tick_rounding_override_flips_direction() {
+    let buy = PricerIntent {
+        tick_size: Some(1.0),
+        tick_rounding: TickRounding::Aggressive,
+        ..intent(Side::Buy, 100.0, 10.0, 1.0, 1.0, 1.0)
+    };
+    assert_eq!(price_ioc_limit(&buy).expect("buy priced").limit_price, 96.0);
+
+    let sell = PricerIntent {
+        tick_size: Some(1.0),
+        tick_rounding: TickRounding::Aggressive,
+        ..intent(Side::Sell, 100.0, 10.0, 1.0, 1.0, 1.0)
+    };
+    assert_eq!(
+        price_ioc_limit(&sell).expect("sell priced").limit_price,
+        104.0
+    );
+}
+
+#[test]
+fn test_pricer_aggressive_rounding_never_breaches_min_edge() {
+    // Clamped to max_price_for_min_edge = 100 - (7.5 + 1) = 91.5; rounding up to 92 would
+    // give away min edge, so the passive tick (91) is used instead.
+    let buy = PricerIntent {
+        tick_size: Some(1.0),
+        tick_rounding: TickRounding::Aggressive,
+        ..intent(Side::Buy, 100.0, 10.0, 1.0, 7.5, 1.0)
+    };
+    let outcome = price_ioc_limit(&buy).expect("buy priced");
+    assert_eq!(outcome.max_price_for_min_edge, 91.5);
+    assert_eq!(outcome.limit_price, 91.0);
+}
@@ -1,8 +1,11 @@
 use soldier_core::execution::{
     InstrumentQuantization, QuantizeRejectReason, Side, quantization_reject_too_small_total,
-    quantize_from_metadata,
+    quantize_from_metadata, quantize_group,
 };
 use soldier_core::venue::{InstrumentKind, InstrumentMetadata};
+use std::sync::Mutex;
+
+static TEST_MUTEX: Mutex<()> = Mutex::new(());
 
 #[test]
 fn test_quantization_rounding_buy_sell() {
@@ -35,6 +38,7 @@ fn test_quantization_rounding_buy_sell() {
 
 #[test]
 fn test_rejects_too_small_after_quantization() {
+    let _guard = TEST_MUTEX.lock().expect("quantize test mutex");
     let meta = InstrumentQuantization {
         tick_size: 0.5,
         amount_step: 0.1,
@@ -59,6 +63,7 @@ fn test_missing_metadata_rejects_open() {
         amount_step: 0.1,
         min_amount: 0.2,
         contract_multiplier: 1.0,
+        expiration_ts_ms: None,
     };
 
     let err = quantize_from_metadata(Side::Buy, 1.0, 100.0, &meta)
@@ -214,3 +219,56 @@ fn test_quantize_rounding_matrix_by_side() {
         );
     }
 }
+
+#[test]
+fn test_rejects_price_quantized_to_non_positive() {
+    let _guard = TEST_MUTEX.lock().expect("quantize test mutex");
+    let meta = InstrumentQuantization {
+        tick_size: 0.5,
+        amount_step: 0.1,
+        min_amount: 0.1,
+    };
+
+    let before = quantization_reject_too_small_total();
+    let err = meta
+        .quantize(Side::Buy, 1.0, 0.25)
+        .expect_err("half-tick buy price floors to zero");
+    assert_eq!(err.reason, QuantizeRejectReason::NonPositivePrice);
+    assert_eq!(quantization_reject_too_small_total(), before);
+
+    let ok = meta
+        .quantize(Side::Buy, 1.0, 0.5)
+        .expect("one-tick price should quantize");
+    assert!((ok.limit_price_q - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn test_quantize_group_partitions_per_leg_results() {
+    let _guard = TEST_MUTEX.lock().expect("quantize test mutex");
+    let meta = InstrumentMetadata {
+        instrument_kind: InstrumentKind::Option,
+        tick_size: 0.5,
+        amount_step: 0.1,
+        min_amount: 1.0,
+        contract_multiplier: 1.0,
+        expiration_ts_ms: None,
+    };
+    let legs = [
+        (meta, 1.24, 100.74, Side::Buy),
+        (meta, 0.95, 100.0, Side::Sell),
+    ];
+
+    let before = quantization_reject_too_small_total();
+    let results = quantize_group(&legs);
+    let after = quantization_reject_too_small_total();
+
+    assert_eq!(results.len(), 2);
+    let first = results[0].expect("first leg quantizes");
+    assert!((first.qty_q - 1.2).abs() < 1e-9);
+    assert!((first.limit_price_q - 100.5).abs() < 1e-9);
+    assert_eq!(
+        results[1].expect_err("second leg too small").reason,
+        QuantizeRejectReason::TooSmallAfterQuantization
+    );
+    assert_eq!(after, before + 1, "one bump per failed leg");
+}
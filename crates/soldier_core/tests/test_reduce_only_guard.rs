@@ -0,0 +1,79 @@
+mod common;
+
+use soldier_core::execution::{
+    IntentNotional, OrderIntent, ReduceOnlyReject, Side, enforce_reduce_only,
+};
+use soldier_core::risk::TradingMode;
+
+fn intent(side: Side, qty: f64) -> OrderIntent {
+    OrderIntent {
+        notional: Some(IntentNotional {
+            qty,
+            price: 100.0,
+            contract_multiplier: 1.0,
+        }),
+        side: Some(side),
+        limit_price: Some(100.0),
+        ..common::perp_limit_intent()
+    }
+}
+
+#[test]
+fn test_reduce_only_guard_rejects_open_under_reduce_only() {
+    // Long 10, buying 2 more grows exposure.
+    assert_eq!(
+        enforce_reduce_only(intent(Side::Buy, 2.0), TradingMode::ReduceOnly, 10.0),
+        Err(ReduceOnlyReject::IncreasesExposure {
+            current_position: 10.0,
+            position_after: 12.0,
+        })
+    );
+    // Flat: any order is an open.
+    assert!(matches!(
+        enforce_reduce_only(intent(Side::Sell, 1.0), TradingMode::ReduceOnly, 0.0),
+        Err(ReduceOnlyReject::IncreasesExposure { .. })
+    ));
+    // Even if upstream marked it reduce-only, the position decides.
+    let mislabelled = OrderIntent {
+        reduce_only: Some(true),
+        ..intent(Side::Buy, 2.0)
+    };
+    assert!(enforce_reduce_only(mislabelled, TradingMode::ReduceOnly, 10.0).is_err());
+}
+
+#[test]
+fn test_reduce_only_guard_passes_close_with_flag_set() {
+    let closed = enforce_reduce_only(intent(Side::Sell, 4.0), TradingMode::ReduceOnly, 10.0)
+        .expect("sell reduces a long");
+    assert_eq!(closed.reduce_only, Some(true));
+    assert_eq!(closed.notional, intent(Side::Sell, 4.0).notional);
+
+    let covered = enforce_reduce_only(intent(Side::Buy, 3.0), TradingMode::ReduceOnly, -3.0)
+        .expect("buy covers a short");
+    assert_eq!(covered.reduce_only, Some(true));
+}
+
+#[test]
+fn test_reduce_only_guard_kill_rejects_everything() {
+    assert_eq!(
+        enforce_reduce_only(intent(Side::Sell, 4.0), TradingMode::Kill, 10.0),
+        Err(ReduceOnlyReject::KillMode)
+    );
+}
+
+#[test]
+fn test_reduce_only_guard_active_and_missing_sizing() {
+    let open = intent(Side::Buy, 2.0);
+    assert_eq!(
+        enforce_reduce_only(open.clone(), TradingMode::Active, 10.0),
+        Ok(open.clone())
+    );
+    let no_sizing = OrderIntent {
+        notional: None,
+        ..open
+    };
+    assert_eq!(
+        enforce_reduce_only(no_sizing, TradingMode::ReduceOnly, 10.0),
+        Err(ReduceOnlyReject::SizingMissing)
+    );
+}
@@ -1,24 +1,19 @@
+mod common;
+
 use std::sync::atomic::Ordering;
 
 use soldier_core::execution::{
     BuildOrderIntentContext, BuildOrderIntentError, BuildOrderIntentObservers,
     BuildOrderIntentOutcome, BuildOrderIntentRejectReason, InstrumentQuantization,
     IntentClassification, L2BookLevel, L2BookSnapshot, LinkedOrderType, LiquidityGateConfig,
-    OrderIntent, OrderType, OrderTypeGuardConfig, OrderTypeRejectReason, QuantizeRejectReason,
+    OrderIntent, OrderTypeGuardConfig, OrderTypeRejectReason, QuantizeRejectReason,
     RecordIntentOutcome, Side, build_order_intent, take_build_order_intent_outcome,
     take_dispatch_trace, with_build_order_intent_context,
 };
 use soldier_core::risk::{FeeModelSnapshot, FeeStalenessConfig, RiskState};
-use soldier_core::venue::InstrumentKind;
 
 fn base_intent() -> OrderIntent {
-    OrderIntent {
-        instrument_kind: InstrumentKind::Perpetual,
-        order_type: OrderType::Limit,
-        trigger: None,
-        trigger_price: None,
-        linked_order_type: None,
-    }
+    common::perp_limit_intent()
 }
 
 fn sample_book(now_ms: u64) -> L2BookSnapshot {
@@ -52,6 +47,7 @@ fn base_context(
             fee_tier: 1,
             maker_fee_rate: 0.0002,
             taker_fee_rate: 0.0005,
+            settlement_fee_bps: None,
             fee_model_cached_at_ts_ms: Some(now_ms),
         },
         fee_staleness_config: FeeStalenessConfig::default(),
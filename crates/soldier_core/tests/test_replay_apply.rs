@@ -0,0 +1,72 @@
+use soldier_core::analytics::{
+    ReplayApplyMode, ReplayQuality, coverage_pct, replay_apply_decision, replay_quality,
+};
+
+fn decide(coverage: Option<f64>, readable: bool, haircut: Option<f64>) -> (ReplayApplyMode, f64) {
+    replay_apply_decision(replay_quality(coverage, readable), haircut)
+}
+
+#[test]
+fn test_replay_apply_good_coverage_applies_without_haircut() {
+    assert_eq!(replay_quality(Some(1.0), true), ReplayQuality::Good);
+    assert_eq!(
+        decide(Some(1.0), true, Some(0.5)),
+        (ReplayApplyMode::Apply, 1.0)
+    );
+}
+
+#[test]
+fn test_replay_apply_flips_at_95_pct_boundary() {
+    // AT-002: exactly 95% is APPLY; just below is APPLY_WITH_HAIRCUT.
+    assert_eq!(
+        decide(Some(0.95), true, Some(0.5)),
+        (ReplayApplyMode::Apply, 1.0)
+    );
+    assert_eq!(
+        decide(Some(0.9499), true, Some(0.5)),
+        (ReplayApplyMode::ApplyWithHaircut, 0.5)
+    );
+
+    // 57 of 60 one-minute slots over an hour is 95% coverage.
+    let now_ms = 3_600_000;
+    let snapshots: Vec<u64> = (0..57).map(|i| now_ms - i * 60_000).collect();
+    let coverage = coverage_pct(&snapshots, 1, now_ms, 60_000);
+    assert_eq!(replay_quality(Some(coverage), true), ReplayQuality::Good);
+}
+
+#[test]
+fn test_replay_apply_degraded_uses_configured_haircut() {
+    // AT-257: 90% coverage is DEGRADED.
+    assert_eq!(replay_quality(Some(0.90), true), ReplayQuality::Degraded);
+    assert_eq!(
+        decide(Some(0.90), true, Some(0.5)),
+        (ReplayApplyMode::ApplyWithHaircut, 0.5)
+    );
+    assert_eq!(
+        decide(Some(0.80), true, Some(1.0)),
+        (ReplayApplyMode::ApplyWithHaircut, 1.0)
+    );
+    // Missing or out-of-range haircut fails closed to SHADOW_ONLY.
+    for haircut in [None, Some(0.0), Some(1.5), Some(f64::NAN)] {
+        assert_eq!(
+            decide(Some(0.90), true, haircut),
+            (ReplayApplyMode::ShadowOnly, 1.0)
+        );
+    }
+}
+
+#[test]
+fn test_replay_apply_broken_is_shadow_only() {
+    // AT-1062: below 80%, unreadable snapshots, or uncomputable coverage.
+    assert_eq!(replay_quality(Some(0.7999), true), ReplayQuality::Broken);
+    assert_eq!(replay_quality(Some(1.0), false), ReplayQuality::Broken);
+    assert_eq!(replay_quality(None, true), ReplayQuality::Broken);
+    assert_eq!(
+        decide(Some(0.50), true, Some(0.5)),
+        (ReplayApplyMode::ShadowOnly, 1.0)
+    );
+    assert_eq!(
+        decide(Some(1.0), false, Some(0.5)),
+        (ReplayApplyMode::ShadowOnly, 1.0)
+    );
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use soldier_core::risk::{
+    ChurnBreaker, GlobalBudgetConfig, GlobalExposureBudget, InstrumentExposure,
+    InventorySkewConfig, LatchReason, PendingExposureTracker, ReserveResult, RiskContext,
+    RiskSnapshot, SelfImpactConfig, SelfImpactGuard, SelfImpactKey, TradeAggregates,
+};
+
+const NOW_MS: u64 = 1_000_000;
+
+#[test]
+fn test_risk_snapshot_reflects_reservation_and_latch_without_mutation() {
+    let churn_breaker = ChurnBreaker::new();
+    let self_impact_guard = SelfImpactGuard::new();
+    let global_budget = GlobalExposureBudget::new(GlobalBudgetConfig {
+        portfolio_delta_limit_usd: 1_000.0,
+        portfolio_delta_exit_limit_usd: None,
+    });
+    let mut portfolio_exposures = HashMap::new();
+    portfolio_exposures.insert(
+        "BTC-PERP".to_string(),
+        InstrumentExposure { delta_usd: 400.0 },
+    );
+    let pending_exposure = PendingExposureTracker::new(None);
+    pending_exposure.register_instrument("BTC-PERP".to_string(), Some(10.0));
+    assert!(matches!(
+        pending_exposure.reserve("intent-1".to_string(), "BTC-PERP", 3.0, 0.0),
+        ReserveResult::Reserved
+    ));
+
+    let ctx = RiskContext {
+        churn_breaker: &churn_breaker,
+        self_impact_guard: &self_impact_guard,
+        self_impact_config: SelfImpactConfig::default(),
+        // No public trade feed yet: the self-impact latch is set.
+        trade_aggregates: TradeAggregates {
+            public_notional_usd: 0.0,
+            self_notional_usd: 0.0,
+            public_trades_last_update_ts_ms: None,
+        },
+        inventory_skew_config: InventorySkewConfig::default(),
+        global_budget: &global_budget,
+        portfolio_exposures: &portfolio_exposures,
+        pending_exposure: &pending_exposure,
+        now_ms: NOW_MS,
+        now: Instant::now(),
+    };
+
+    let snapshot = RiskSnapshot::capture(&ctx);
+    assert_eq!(snapshot.pending_deltas.get("BTC-PERP"), Some(&3.0));
+    assert_eq!(snapshot.global_pending_delta, 3.0);
+    assert_eq!(
+        snapshot.self_impact_latch,
+        Some(LatchReason::WsTradesGapReconcileRequired)
+    );
+    assert!((snapshot.budget_headroom_usd - 600.0).abs() < 1e-9);
+    assert_eq!(
+        snapshot.to_json(),
+        "{\"budget_headroom_usd\":600,\"churn_blocked_keys\":0,\"churn_trip_count\":0,\
+         \"global_budget_breached\":false,\"global_pending_delta\":3,\"now_ms\":1000000,\
+         \"pending_deltas\":{\"BTC-PERP\":3},\"self_impact_cooldowns\":[],\
+         \"self_impact_latch\":\"WsTradesGapReconcileRequired\",\"self_impact_trip_count\":0}"
+    );
+
+    // Pure read: capturing again sees identical state and the reservation is untouched.
+    assert_eq!(RiskSnapshot::capture(&ctx), snapshot);
+    assert_eq!(pending_exposure.get_pending_delta("BTC-PERP"), 3.0);
+}
+
+#[test]
+fn test_risk_snapshot_reports_active_self_impact_cooldowns() {
+    let churn_breaker = ChurnBreaker::new();
+    let self_impact_guard = SelfImpactGuard::new();
+    let global_budget = GlobalExposureBudget::new(GlobalBudgetConfig {
+        portfolio_delta_limit_usd: 1_000.0,
+        portfolio_delta_exit_limit_usd: None,
+    });
+    let portfolio_exposures = HashMap::new();
+    let pending_exposure = PendingExposureTracker::new(None);
+    let config = SelfImpactConfig::default();
+    let now = Instant::now();
+
+    // A fresh feed dominated by our own prints trips the feedback-loop cooldown.
+    let aggregates = TradeAggregates {
+        public_notional_usd: 200_000.0,
+        self_notional_usd: 160_000.0,
+        public_trades_last_update_ts_ms: Some(NOW_MS),
+    };
+    let key = SelfImpactKey {
+        strategy_id: "delta_neutral_btc".to_string(),
+        structure_fingerprint: "BTC-PERP".to_string(),
+    };
+    let evaluation = self_impact_guard.evaluate_open(&key, aggregates, NOW_MS, now, config);
+    assert!(!evaluation.allowed);
+
+    let ctx = RiskContext {
+        churn_breaker: &churn_breaker,
+        self_impact_guard: &self_impact_guard,
+        self_impact_config: config,
+        trade_aggregates: aggregates,
+        inventory_skew_config: InventorySkewConfig::default(),
+        global_budget: &global_budget,
+        portfolio_exposures: &portfolio_exposures,
+        pending_exposure: &pending_exposure,
+        now_ms: NOW_MS,
+        now,
+    };
+
+    // The feed is fresh, so only the cooldown shows the guard is blocking.
+    let snapshot = RiskSnapshot::capture(&ctx);
+    assert_eq!(snapshot.self_impact_latch, None);
+    assert_eq!(snapshot.self_impact_cooldowns, vec![key]);
+    assert!(snapshot.to_json().contains(
+        "\"self_impact_cooldowns\":[{\"strategy_id\":\"delta_neutral_btc\",\
+         \"structure_fingerprint\":\"BTC-PERP\"}]"
+    ));
+}
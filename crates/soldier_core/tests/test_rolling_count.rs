@@ -0,0 +1,29 @@
+use soldier_core::analytics::RollingCount;
+
+const FIVE_MIN_MS: u64 = 5 * 60 * 1000;
+
+#[test]
+fn test_rolling_count_drops_events_as_they_age_out() {
+    let mut count_429 = RollingCount::new(FIVE_MIN_MS);
+    // One event per minute over a 6-minute span: t = 0, 1, ..., 6 min.
+    for minute in 0..=6 {
+        count_429.observe(minute * 60_000);
+    }
+
+    // At 6 min the 5-minute window (1 min, 6 min] holds minutes 2..=6.
+    assert_eq!(count_429.count_within(6 * 60_000, FIVE_MIN_MS), 5);
+    assert_eq!(count_429.count_within(6 * 60_000, 60_000), 1);
+
+    // As time advances with no new events, the count decays to zero.
+    assert_eq!(count_429.count_within(8 * 60_000 + 1, FIVE_MIN_MS), 3);
+    assert_eq!(count_429.count_within(11 * 60_000, FIVE_MIN_MS), 0);
+}
+
+#[test]
+fn test_rolling_count_window_is_capped_at_retention() {
+    let mut count_10028 = RollingCount::new(FIVE_MIN_MS);
+    count_10028.observe(0);
+    count_10028.observe(FIVE_MIN_MS);
+    // The first event is past retention and pruned even for a longer query window.
+    assert_eq!(count_10028.count_within(FIVE_MIN_MS, 2 * FIVE_MIN_MS), 1);
+}
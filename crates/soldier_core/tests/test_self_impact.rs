@@ -6,7 +6,7 @@ use std::time::Instant;
 /// AT-953: Stale trade feed => Degraded + latch + block opens
 #[test]
 fn test_self_impact_stale_feed_sets_latch() {
-    let mut guard = SelfImpactGuard::new();
+    let guard = SelfImpactGuard::new();
     let key = SelfImpactKey {
         strategy_id: "s1".to_string(),
         structure_fingerprint: "struct1".to_string(),
@@ -46,7 +46,7 @@ fn test_self_impact_stale_feed_sets_latch() {
 /// AT-953: Missing trade feed => Degraded + latch + block opens
 #[test]
 fn test_self_impact_missing_feed_sets_latch() {
-    let mut guard = SelfImpactGuard::new();
+    let guard = SelfImpactGuard::new();
     let key = SelfImpactKey {
         strategy_id: "s1".to_string(),
         structure_fingerprint: "struct1".to_string(),
@@ -77,7 +77,7 @@ fn test_self_impact_missing_feed_sets_latch() {
 /// AT-955: self_fraction trip => reject with FeedbackLoopGuardActive
 #[test]
 fn test_self_impact_fraction_trip_rejects() {
-    let mut guard = SelfImpactGuard::new();
+    let guard = SelfImpactGuard::new();
     let key = SelfImpactKey {
         strategy_id: "s1".to_string(),
         structure_fingerprint: "struct1".to_string(),
@@ -113,7 +113,7 @@ fn test_self_impact_fraction_trip_rejects() {
         result
             .reject_reason
             .as_ref()
-            .map_or(false, |r| r.contains("FeedbackLoopGuardActive")),
+            .is_some_and(|r| r.contains("FeedbackLoopGuardActive")),
         "Reject reason should be FeedbackLoopGuardActive, got: {:?}",
         result.reject_reason
     );
@@ -122,7 +122,7 @@ fn test_self_impact_fraction_trip_rejects() {
 /// AT-956: self_notional trip => reject with FeedbackLoopGuardActive
 #[test]
 fn test_self_impact_notional_trip_rejects() {
-    let mut guard = SelfImpactGuard::new();
+    let guard = SelfImpactGuard::new();
     let key = SelfImpactKey {
         strategy_id: "s1".to_string(),
         structure_fingerprint: "struct1".to_string(),
@@ -158,7 +158,7 @@ fn test_self_impact_notional_trip_rejects() {
         result
             .reject_reason
             .as_ref()
-            .map_or(false, |r| r.contains("FeedbackLoopGuardActive")),
+            .is_some_and(|r| r.contains("FeedbackLoopGuardActive")),
         "Reject reason should be FeedbackLoopGuardActive, got: {:?}",
         result.reject_reason
     );
@@ -167,7 +167,7 @@ fn test_self_impact_notional_trip_rejects() {
 /// AT-957: Below threshold => allow OPEN
 #[test]
 fn test_self_impact_below_threshold_allows() {
-    let mut guard = SelfImpactGuard::new();
+    let guard = SelfImpactGuard::new();
     let key = SelfImpactKey {
         strategy_id: "s1".to_string(),
         structure_fingerprint: "struct1".to_string(),
@@ -205,7 +205,7 @@ fn test_self_impact_below_threshold_allows() {
 /// Test cooldown behavior: after trip, subsequent OPENs are blocked during cooldown
 #[test]
 fn test_self_impact_cooldown_blocks_subsequent_opens() {
-    let mut guard = SelfImpactGuard::new();
+    let guard = SelfImpactGuard::new();
     let key = SelfImpactKey {
         strategy_id: "s1".to_string(),
         structure_fingerprint: "struct1".to_string(),
@@ -257,7 +257,7 @@ fn test_self_impact_cooldown_blocks_subsequent_opens() {
 /// Test trip counter metric
 #[test]
 fn test_self_impact_trip_counter_increments() {
-    let mut guard = SelfImpactGuard::new();
+    let guard = SelfImpactGuard::new();
     let key1 = SelfImpactKey {
         strategy_id: "s1".to_string(),
         structure_fingerprint: "struct1".to_string(),
@@ -286,3 +286,58 @@ fn test_self_impact_trip_counter_increments() {
     guard.evaluate_open(&key2, aggregates_trip, now_ms, now_instant, config);
     assert_eq!(guard.trip_count(), 2, "Trip count should increment to 2");
 }
+
+/// Windowed aggregates snapshot matches hand-summed trades and drops aged-out prints
+#[test]
+fn test_self_impact_current_aggregates_prunes_window() {
+    let guard = SelfImpactGuard::new();
+    let config = SelfImpactConfig::default(); // feedback_loop_window_s = 10
+
+    guard.record_trade(100_000, 20_000.0, false);
+    guard.record_trade(103_000, 5_000.0, true);
+    guard.record_trade(108_000, 15_000.0, false);
+    guard.record_trade(109_000, 2_500.0, true);
+
+    let snapshot = guard.current_aggregates(109_500, config);
+    assert_eq!(snapshot.public_notional_usd, 42_500.0);
+    assert_eq!(snapshot.self_notional_usd, 7_500.0);
+    assert_eq!(snapshot.public_trades_last_update_ts_ms, Some(109_000));
+
+    // At 112_000 the 100_000 print is 12s old and falls out of the 10s window.
+    let snapshot = guard.current_aggregates(112_000, config);
+    assert_eq!(snapshot.public_notional_usd, 22_500.0);
+    assert_eq!(snapshot.self_notional_usd, 7_500.0);
+
+    // At 113_000 the 103_000 self print is exactly 10s old and also drops.
+    let snapshot = guard.current_aggregates(113_000, config);
+    assert_eq!(snapshot.public_notional_usd, 17_500.0);
+    assert_eq!(snapshot.self_notional_usd, 2_500.0);
+
+    let snapshot = guard.current_aggregates(200_000, config);
+    assert_eq!(snapshot.public_notional_usd, 0.0);
+    assert_eq!(snapshot.self_notional_usd, 0.0);
+    assert_eq!(
+        snapshot.public_trades_last_update_ts_ms,
+        Some(109_000),
+        "last update survives pruning so staleness stays observable"
+    );
+}
+
+/// Snapshot feeds evaluate_open directly
+#[test]
+fn test_self_impact_current_aggregates_drives_trip() {
+    let guard = SelfImpactGuard::new();
+    let key = SelfImpactKey {
+        strategy_id: "s1".to_string(),
+        structure_fingerprint: "struct1".to_string(),
+    };
+    let config = SelfImpactConfig::default();
+
+    guard.record_trade(100_000, 60_000.0, false);
+    guard.record_trade(101_000, 40_000.0, true);
+
+    let now_ms = 102_000;
+    let aggregates = guard.current_aggregates(now_ms, config);
+    let result = guard.evaluate_open(&key, aggregates, now_ms, Instant::now(), config);
+    assert!(!result.allowed, "40% self fraction should trip");
+}
@@ -0,0 +1,83 @@
+use soldier_core::venue::{
+    OptionType, SymbolExpiry, SymbolKind, SymbolParseError, parse_deribit_symbol,
+};
+
+#[test]
+fn parses_inverse_and_linear_perpetuals() {
+    let inverse = parse_deribit_symbol("BTC-PERPETUAL").expect("inverse perp");
+    assert_eq!(inverse.base_currency, "BTC");
+    assert_eq!(inverse.quote_currency, None);
+    assert_eq!(inverse.kind, SymbolKind::Perpetual);
+
+    let linear = parse_deribit_symbol("ETH_USDC-PERPETUAL").expect("linear perp");
+    assert_eq!(linear.base_currency, "ETH");
+    assert_eq!(linear.quote_currency.as_deref(), Some("USDC"));
+    assert_eq!(linear.kind, SymbolKind::Perpetual);
+}
+
+#[test]
+fn parses_dated_future() {
+    let parsed = parse_deribit_symbol("BTC-5JUL24").expect("future");
+    assert_eq!(
+        parsed.kind,
+        SymbolKind::Future {
+            expiry: SymbolExpiry {
+                day: 5,
+                month: 7,
+                year: 2024,
+            },
+        }
+    );
+}
+
+#[test]
+fn parses_options_including_fractional_strikes() {
+    let call = parse_deribit_symbol("BTC-27JUN25-80000-C").expect("call");
+    assert_eq!(call.base_currency, "BTC");
+    assert_eq!(
+        call.kind,
+        SymbolKind::Option {
+            expiry: SymbolExpiry {
+                day: 27,
+                month: 6,
+                year: 2025,
+            },
+            strike: 80_000.0,
+            option_type: OptionType::Call,
+        }
+    );
+
+    let put = parse_deribit_symbol("XRP_USDC-30JUN23-0d625-P").expect("put");
+    assert!(matches!(
+        put.kind,
+        SymbolKind::Option {
+            strike,
+            option_type: OptionType::Put,
+            ..
+        } if strike == 0.625
+    ));
+}
+
+#[test]
+fn rejects_malformed_symbols() {
+    assert_eq!(
+        parse_deribit_symbol("BTC"),
+        Err(SymbolParseError::UnexpectedSegmentCount)
+    );
+    assert_eq!(
+        parse_deribit_symbol("-PERPETUAL"),
+        Err(SymbolParseError::MissingCurrency)
+    );
+    assert_eq!(
+        parse_deribit_symbol("BTC-27XYZ25"),
+        Err(SymbolParseError::InvalidExpiry)
+    );
+    assert_eq!(
+        parse_deribit_symbol("BTC-27JUN25-abc-C"),
+        Err(SymbolParseError::InvalidStrike)
+    );
+    assert_eq!(
+        parse_deribit_symbol("BTC-27JUN25-80000-X"),
+        Err(SymbolParseError::InvalidOptionType)
+    );
+}
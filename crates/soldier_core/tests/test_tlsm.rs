@@ -2,7 +2,7 @@ use std::sync::{Arc, Mutex};
 
 use soldier_core::execution::{
     Tlsm, TlsmError, TlsmEvent, TlsmIntent, TlsmLedger, TlsmLedgerEntry, TlsmLedgerError, TlsmSide,
-    TlsmState,
+    TlsmState, TlsmTimeouts,
 };
 
 #[derive(Clone, Default)]
@@ -111,6 +111,56 @@ fn test_tlsm_ledger_append_failure_is_atomic() {
     assert_eq!(tlsm.last_fill_ts(), None);
 }
 
+#[test]
+fn test_tlsm_tick_before_sent_timeout_is_noop() {
+    let ledger = TestLedger::default();
+    let mut tlsm = Tlsm::new(sample_intent());
+    let timeouts = TlsmTimeouts {
+        sent_timeout_ms: 5_000,
+    };
+
+    tlsm.apply_event(&ledger, TlsmEvent::Sent { ts_ms: 1_000 })
+        .expect("apply sent");
+    assert_eq!(tlsm.state_entered_ts(), 1_000);
+
+    let transition = tlsm.tick(&ledger, 5_999, &timeouts).expect("tick");
+    assert!(transition.is_none());
+    assert_eq!(tlsm.state(), TlsmState::Sent);
+    assert_eq!(ledger.len(), 1);
+}
+
+#[test]
+fn test_tlsm_tick_past_sent_timeout_moves_to_unconfirmed() {
+    let ledger = TestLedger::default();
+    let mut tlsm = Tlsm::new(sample_intent());
+    let timeouts = TlsmTimeouts {
+        sent_timeout_ms: 5_000,
+    };
+
+    tlsm.apply_event(&ledger, TlsmEvent::Sent { ts_ms: 1_000 })
+        .expect("apply sent");
+    let transition = tlsm
+        .tick(&ledger, 6_000, &timeouts)
+        .expect("tick")
+        .expect("timeout transition");
+    assert_eq!(transition.from, TlsmState::Sent);
+    assert_eq!(transition.to, TlsmState::Unconfirmed);
+    assert_eq!(transition.event, TlsmEvent::TimedOut { ts_ms: 6_000 });
+    assert_eq!(tlsm.state(), TlsmState::Unconfirmed);
+    assert_eq!(tlsm.state_entered_ts(), 6_000);
+    assert_eq!(ledger.entries()[1].tls_state, TlsmState::Unconfirmed);
+
+    // Already timed out: further ticks are no-ops until a late ack resolves it.
+    assert!(
+        tlsm.tick(&ledger, 20_000, &timeouts)
+            .expect("tick")
+            .is_none()
+    );
+    tlsm.apply_event(&ledger, TlsmEvent::Acked { ts_ms: 21_000 })
+        .expect("apply late ack");
+    assert_eq!(tlsm.state(), TlsmState::Acked);
+}
+
 fn apply_events(events: Vec<TlsmEvent>) -> TlsmState {
     let ledger = TestLedger::default();
     let mut tlsm = Tlsm::new(sample_intent());
@@ -0,0 +1,24 @@
+use soldier_core::risk::TradingMode;
+
+#[test]
+fn test_permits_intent_table() {
+    let cases = [
+        (TradingMode::Active, Some(true), true),
+        (TradingMode::Active, Some(false), true),
+        (TradingMode::Active, None, true),
+        (TradingMode::ReduceOnly, Some(true), true),
+        (TradingMode::ReduceOnly, Some(false), false),
+        (TradingMode::ReduceOnly, None, false),
+        (TradingMode::Kill, Some(true), false),
+        (TradingMode::Kill, Some(false), false),
+        (TradingMode::Kill, None, false),
+    ];
+
+    for (mode, reduce_only, expected) in cases {
+        assert_eq!(
+            mode.permits_intent(reduce_only),
+            expected,
+            "{mode:?} with reduce_only={reduce_only:?}"
+        );
+    }
+}
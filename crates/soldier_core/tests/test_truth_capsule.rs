@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use soldier_core::analytics::{
+    FileTruthCapsuleWriter, InMemoryTruthCapsuleWriter, TruthCapsule, TruthCapsuleError,
+    TruthCapsuleWriter,
+};
+
+fn temp_capsule_path(test_name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock")
+        .as_nanos();
+    path.push(format!(
+        "soldier_core_{}_{}_{}.capsule",
+        test_name,
+        std::process::id(),
+        nanos
+    ));
+    path
+}
+
+fn capsule(id: &str) -> TruthCapsule {
+    TruthCapsule {
+        decision_snapshot_id: id.to_string(),
+        ts_ms: 1_000,
+        inputs: BTreeMap::from([
+            ("risk_state".to_string(), "Healthy".to_string()),
+            ("fair_price".to_string(), "100.5".to_string()),
+        ]),
+        outputs: BTreeMap::from([("decision".to_string(), "Open \"buy\"".to_string())]),
+    }
+}
+
+#[test]
+fn test_truth_capsule_successful_writes_keep_error_counter_at_zero() {
+    let memory = InMemoryTruthCapsuleWriter::new();
+    memory.write(&capsule("snap-1")).expect("memory write");
+    assert_eq!(memory.truth_capsule_write_errors(), 0);
+    assert_eq!(
+        memory.lines(),
+        vec![
+            r#"{"decision_snapshot_id":"snap-1","inputs":{"fair_price":"100.5","risk_state":"Healthy"},"outputs":{"decision":"Open \"buy\""},"ts_ms":1000}"#
+        ]
+    );
+
+    let file = FileTruthCapsuleWriter::new(temp_capsule_path("ok"));
+    file.write(&capsule("snap-1")).expect("file write");
+    file.write(&capsule("snap-2")).expect("file write");
+    assert_eq!(file.truth_capsule_write_errors(), 0);
+    let contents = std::fs::read_to_string(file.path()).expect("read capsules");
+    assert_eq!(contents.lines().count(), 2);
+}
+
+#[test]
+fn test_truth_capsule_failing_sink_increments_write_errors() {
+    let memory = InMemoryTruthCapsuleWriter::with_capacity(0);
+    assert_eq!(
+        memory.write(&capsule("snap-1")),
+        Err(TruthCapsuleError::SinkFull)
+    );
+    assert_eq!(memory.truth_capsule_write_errors(), 1);
+
+    // A directory cannot be opened for append.
+    let file = FileTruthCapsuleWriter::new(std::env::temp_dir());
+    assert!(matches!(
+        file.write(&capsule("snap-1")),
+        Err(TruthCapsuleError::Io(_))
+    ));
+    assert!(matches!(
+        file.write(&capsule("snap-2")),
+        Err(TruthCapsuleError::Io(_))
+    ));
+    assert_eq!(file.truth_capsule_write_errors(), 2);
+}
@@ -1,5 +1,14 @@
 use std::fmt;
 
+use soldier_core::risk::MarginConfig;
+
+mod store;
+
+pub use store::{
+    ConfigChange, ConfigChangeEvent, ConfigStore, DEPTH_MIN_DEFAULT, KeyClass, OperationalConfig,
+    RuntimeConfig, SPREAD_MAX_BPS_DEFAULT, key_class,
+};
+
 /// Appendix A safety-critical defaults (centralized table).
 pub const INSTRUMENT_CACHE_TTL_S_DEFAULT: u64 = 3600;
 pub const EVIDENCEGUARD_GLOBAL_COOLDOWN_DEFAULT: u64 = 120;
@@ -38,6 +47,22 @@ pub enum ConfigError {
         key: &'static str,
         expected: ParamKind,
     },
+    OutOfRange {
+        key: &'static str,
+    },
+    CrossConfigMismatch {
+        key: &'static str,
+        other: &'static str,
+        safety_value: String,
+        other_value: String,
+    },
+    /// A safety-critical key differs in a runtime reload; these only change on restart.
+    SafetyCriticalChange {
+        key: &'static str,
+    },
+    InvalidOperational {
+        key: &'static str,
+    },
 }
 
 impl fmt::Display for ConfigError {
@@ -56,12 +81,79 @@ impl fmt::Display for ConfigError {
                 key,
                 expected.as_str()
             ),
+            ConfigError::OutOfRange { key } => write!(
+                f,
+                "out-of-range safety-critical config value: {} (expected ratio in [0, 1])",
+                key
+            ),
+            ConfigError::CrossConfigMismatch {
+                key,
+                other,
+                safety_value,
+                other_value,
+            } => write!(
+                f,
+                "safety-critical config value {} disagrees: SafetyConfig={} {}={}",
+                key, safety_value, other, other_value
+            ),
+            ConfigError::SafetyCriticalChange { key } => write!(
+                f,
+                "safety-critical config value cannot change at runtime: {}",
+                key
+            ),
+            ConfigError::InvalidOperational { key } => write!(
+                f,
+                "invalid operational config value: {} (expected finite, non-negative)",
+                key
+            ),
         }
     }
 }
 
 impl std::error::Error for ConfigError {}
 
+/// Duration in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Millis(pub u64);
+
+/// Duration in seconds. Convert with `Millis::from` rather than passing the raw value
+/// where milliseconds are expected:
+///
+/// ```compile_fail
+/// use soldier_infra::config::{Millis, Secs};
+/// let cooldown: Millis = Secs(120);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Secs(pub u64);
+
+impl From<Secs> for Millis {
+    fn from(secs: Secs) -> Self {
+        Millis(secs.0.saturating_mul(1000))
+    }
+}
+
+/// Fraction in `[0, 1]` (e.g. `mm_util_kill = 0.95`, never `95`).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Ratio(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RatioOutOfRange {
+    pub value: f64,
+}
+
+impl Ratio {
+    pub fn new(value: f64) -> Result<Self, RatioOutOfRange> {
+        if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+            return Err(RatioOutOfRange { value });
+        }
+        Ok(Self(value))
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct AppendixADefaults {
     pub instrument_cache_ttl_s: u64,
@@ -101,9 +193,9 @@ pub struct SafetyConfigInput {
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SafetyConfig {
-    pub instrument_cache_ttl_s: u64,
-    pub evidenceguard_global_cooldown: u64,
-    pub mm_util_kill: f64,
+    pub instrument_cache_ttl_s: Secs,
+    pub evidenceguard_global_cooldown: Secs,
+    pub mm_util_kill: Ratio,
 }
 
 pub fn apply_defaults(input: SafetyConfigInput) -> Result<SafetyConfig, ConfigError> {
@@ -120,14 +212,31 @@ pub fn apply_defaults(input: SafetyConfigInput) -> Result<SafetyConfig, ConfigEr
     )?;
     let mm_util_kill =
         resolve_required_f64_with_defaults(KEY_MM_UTIL_KILL, input.mm_util_kill, &defaults)?;
+    let mm_util_kill = Ratio::new(mm_util_kill).map_err(|_| ConfigError::OutOfRange {
+        key: KEY_MM_UTIL_KILL,
+    })?;
 
     Ok(SafetyConfig {
-        instrument_cache_ttl_s,
-        evidenceguard_global_cooldown,
+        instrument_cache_ttl_s: Secs(instrument_cache_ttl_s),
+        evidenceguard_global_cooldown: Secs(evidenceguard_global_cooldown),
         mm_util_kill,
     })
 }
 
+/// Checks that constants shared between `SafetyConfig` and the guard configs agree.
+/// Only `MarginConfig` exists today; add the other guard configs here as they land.
+pub fn cross_validate(safety: &SafetyConfig, margin: &MarginConfig) -> Result<(), ConfigError> {
+    if safety.mm_util_kill.get() != margin.mm_util_kill {
+        return Err(ConfigError::CrossConfigMismatch {
+            key: KEY_MM_UTIL_KILL,
+            other: "MarginConfig",
+            safety_value: safety.mm_util_kill.get().to_string(),
+            other_value: margin.mm_util_kill.to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn resolve_required_u64(key: &'static str, provided: Option<u64>) -> Result<u64, ConfigError> {
     let defaults = AppendixADefaults::default();
     resolve_required_u64_with_defaults(key, provided, &defaults)
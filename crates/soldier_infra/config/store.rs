@@ -0,0 +1,143 @@
+//! Runtime config reloads. Safety-critical (Appendix A) keys are fixed for the life of the
+//! process; operational thresholds may be hot-reloaded and every applied reload reports
+//! what changed.
+
+use super::{
+    ConfigError, KEY_EVIDENCEGUARD_GLOBAL_COOLDOWN, KEY_INSTRUMENT_CACHE_TTL_S, KEY_MM_UTIL_KILL,
+    SafetyConfig,
+};
+
+/// Contract A.2 defaults.
+pub const SPREAD_MAX_BPS_DEFAULT: f64 = 25.0;
+pub const DEPTH_MIN_DEFAULT: f64 = 300_000.0;
+const KEY_SPREAD_MAX_BPS: &str = "spread_max_bps";
+const KEY_DEPTH_MIN: &str = "depth_min";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyClass {
+    SafetyCritical,
+    Operational,
+}
+
+/// `None` for keys the store does not know.
+pub fn key_class(key: &str) -> Option<KeyClass> {
+    match key {
+        KEY_INSTRUMENT_CACHE_TTL_S | KEY_EVIDENCEGUARD_GLOBAL_COOLDOWN | KEY_MM_UTIL_KILL => {
+            Some(KeyClass::SafetyCritical)
+        }
+        KEY_SPREAD_MAX_BPS | KEY_DEPTH_MIN => Some(KeyClass::Operational),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OperationalConfig {
+    pub spread_max_bps: f64,
+    /// USD notional depth in the top-N levels.
+    pub depth_min: f64,
+}
+
+impl Default for OperationalConfig {
+    fn default() -> Self {
+        Self {
+            spread_max_bps: SPREAD_MAX_BPS_DEFAULT,
+            depth_min: DEPTH_MIN_DEFAULT,
+        }
+    }
+}
+
+impl OperationalConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (key, value) in self.values() {
+            if !value.is_finite() || value < 0.0 {
+                return Err(ConfigError::InvalidOperational { key });
+            }
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> [(&'static str, f64); 2] {
+        [
+            (KEY_SPREAD_MAX_BPS, self.spread_max_bps),
+            (KEY_DEPTH_MIN, self.depth_min),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RuntimeConfig {
+    pub safety: SafetyConfig,
+    pub operational: OperationalConfig,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    pub key: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+/// Operational keys whose value changed, in declaration order; empty for a no-op reload.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigChangeEvent {
+    pub changes: Vec<ConfigChange>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigStore {
+    current: RuntimeConfig,
+}
+
+impl ConfigStore {
+    pub fn new(initial: RuntimeConfig) -> Self {
+        Self { current: initial }
+    }
+
+    pub fn current(&self) -> &RuntimeConfig {
+        &self.current
+    }
+
+    /// Applies `next` all-or-nothing: any safety-critical difference or invalid operational
+    /// value rejects the whole reload and leaves the current config untouched.
+    pub fn reload(&mut self, next: RuntimeConfig) -> Result<ConfigChangeEvent, ConfigError> {
+        if let Some(key) = changed_safety_key(&self.current.safety, &next.safety) {
+            eprintln!("config_reload_rejected key={key} reason=safety_critical");
+            return Err(ConfigError::SafetyCriticalChange { key });
+        }
+        next.operational.validate()?;
+
+        let changes: Vec<ConfigChange> = self
+            .current
+            .operational
+            .values()
+            .into_iter()
+            .zip(next.operational.values())
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((key, old), (_, new))| ConfigChange {
+                key,
+                old: old.to_string(),
+                new: new.to_string(),
+            })
+            .collect();
+        for change in &changes {
+            eprintln!(
+                "config_reload_applied key={} old={} new={}",
+                change.key, change.old, change.new
+            );
+        }
+        self.current = next;
+        Ok(ConfigChangeEvent { changes })
+    }
+}
+
+fn changed_safety_key(current: &SafetyConfig, next: &SafetyConfig) -> Option<&'static str> {
+    if current.instrument_cache_ttl_s != next.instrument_cache_ttl_s {
+        Some(KEY_INSTRUMENT_CACHE_TTL_S)
+    } else if current.evidenceguard_global_cooldown != next.evidenceguard_global_cooldown {
+        Some(KEY_EVIDENCEGUARD_GLOBAL_COOLDOWN)
+    } else if current.mm_util_kill != next.mm_util_kill {
+        Some(KEY_MM_UTIL_KILL)
+    } else {
+        None
+    }
+}
@@ -1,12 +1,18 @@
 use serde::Deserialize;
 use serde::de::{self, Deserializer, Visitor};
+use soldier_core::risk::{FeeModelSchemaError, FeeModelSnapshot};
 use std::fmt;
 
+/// Fee fields are optional at the serde layer so a payload missing a tier still parses;
+/// `fee_model_snapshot` then rejects it instead of the whole summary failing opaquely.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DeribitAccountSummary {
-    pub fee_tier: u64,
-    pub maker_fee_rate: f64,
-    pub taker_fee_rate: f64,
+    #[serde(default)]
+    pub fee_tier: Option<u64>,
+    #[serde(default)]
+    pub maker_fee_rate: Option<f64>,
+    #[serde(default)]
+    pub taker_fee_rate: Option<f64>,
     #[serde(
         default,
         deserialize_with = "deserialize_epoch_ms_opt",
@@ -23,6 +29,23 @@ pub struct DeribitAccountSummary {
     pub equity: Option<f64>,
 }
 
+impl DeribitAccountSummary {
+    /// The account summary carries no settlement fee, so it comes from venue fee config;
+    /// `None` is fine for a perp-only book, and only option expiry costing needs it.
+    pub fn fee_model_snapshot(
+        &self,
+        settlement_fee_bps: Option<f64>,
+    ) -> Result<FeeModelSnapshot, FeeModelSchemaError> {
+        FeeModelSnapshot::from_fields(
+            self.fee_tier,
+            self.maker_fee_rate,
+            self.taker_fee_rate,
+            settlement_fee_bps,
+            self.fee_model_cached_at_ts_ms,
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DeribitAccountSummaryResponse {
     pub result: DeribitAccountSummary,
@@ -1,4 +1,7 @@
 pub mod account_summary;
 pub mod public;
 pub use account_summary::{DeribitAccountSummary, DeribitAccountSummaryResponse};
-pub use public::{DeribitInstrument, DeribitPublicInstrumentKind, DeribitPublicSettlementPeriod};
+pub use public::{
+    DeribitInstrument, DeribitPublicInstrumentKind, DeribitPublicSettlementPeriod,
+    InstrumentMetadataError, MinAmountPolicy,
+};
@@ -50,6 +50,7 @@ pub struct DeribitInstrument {
     pub amount_step: f64,
     pub min_amount: f64,
     pub contract_multiplier: f64,
+    pub expiration_ts_ms: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -64,6 +65,8 @@ struct DeribitInstrumentRaw {
     pub min_amount: f64,
     #[serde(rename = "contract_size", alias = "contract_multiplier")]
     pub contract_multiplier: f64,
+    #[serde(rename = "expiration_timestamp", default)]
+    pub expiration_ts_ms: Option<u64>,
 }
 
 impl<'de> Deserialize<'de> for DeribitInstrument {
@@ -81,10 +84,30 @@ impl<'de> Deserialize<'de> for DeribitInstrument {
             amount_step,
             min_amount: raw.min_amount,
             contract_multiplier: raw.contract_multiplier,
+            expiration_ts_ms: raw.expiration_ts_ms,
         })
     }
 }
 
+/// How `DeribitInstrument::normalize` treats a `min_amount` off the `amount_step` grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinAmountPolicy {
+    /// Round `min_amount` up to the next step multiple.
+    RoundUp,
+    /// Refuse the instrument.
+    Strict,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InstrumentMetadataError {
+    NonPositiveAmountStep,
+    MinAmountNotStepMultiple { min_amount: f64, amount_step: f64 },
+}
+
+/// Relative tolerance for "is a step multiple"; venue steps are decimal, so exact float
+/// division rarely lands on an integer.
+const STEP_MULTIPLE_EPSILON: f64 = 1e-9;
+
 impl DeribitInstrument {
     pub fn derive_instrument_kind(&self) -> InstrumentKind {
         InstrumentKind::from_deribit(
@@ -93,6 +116,32 @@ impl DeribitInstrument {
             self.quote_currency.as_str(),
         )
     }
+
+    /// Ensures `min_amount` is a multiple of `amount_step`; otherwise quantized sizes at the
+    /// minimum would be un-sendable. Off-grid minimums are rounded up or refused per `policy`.
+    pub fn normalize(
+        &self,
+        policy: MinAmountPolicy,
+    ) -> Result<DeribitInstrument, InstrumentMetadataError> {
+        if !self.amount_step.is_finite() || self.amount_step <= 0.0 {
+            return Err(InstrumentMetadataError::NonPositiveAmountStep);
+        }
+        let steps = self.min_amount / self.amount_step;
+        let nearest = steps.round();
+        if (steps - nearest).abs() <= STEP_MULTIPLE_EPSILON * steps.abs().max(1.0) {
+            return Ok(self.clone());
+        }
+        match policy {
+            MinAmountPolicy::RoundUp => Ok(DeribitInstrument {
+                min_amount: steps.ceil() * self.amount_step,
+                ..self.clone()
+            }),
+            MinAmountPolicy::Strict => Err(InstrumentMetadataError::MinAmountNotStepMultiple {
+                min_amount: self.min_amount,
+                amount_step: self.amount_step,
+            }),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -139,12 +188,60 @@ mod tests {
             "quote_currency": "BTC",
             "tick_size": 0.25,
             "min_trade_amount": 0.05,
-            "contract_size": 1.0
+            "contract_size": 1.0,
+            "expiration_timestamp": 1767225600000
         }"#;
 
         let instrument: DeribitInstrument =
             serde_json::from_str(payload).expect("instrument metadata should deserialize");
 
         assert_eq!(instrument.amount_step, instrument.min_amount);
+        assert_eq!(instrument.expiration_ts_ms, Some(1_767_225_600_000));
+    }
+
+    fn instrument_with_amounts(amount_step: f64, min_amount: f64) -> DeribitInstrument {
+        DeribitInstrument {
+            kind: DeribitPublicInstrumentKind::Future,
+            settlement_period: DeribitPublicSettlementPeriod::Perpetual,
+            quote_currency: "USD".to_string(),
+            tick_size: 0.5,
+            amount_step,
+            min_amount,
+            contract_multiplier: 10.0,
+            expiration_ts_ms: None,
+        }
+    }
+
+    #[test]
+    fn normalize_keeps_consistent_instrument() {
+        let instrument = instrument_with_amounts(0.1, 0.3);
+        assert_eq!(
+            instrument.normalize(MinAmountPolicy::Strict),
+            Ok(instrument.clone())
+        );
+    }
+
+    #[test]
+    fn normalize_rounds_off_grid_min_amount_up() {
+        let normalized = instrument_with_amounts(0.1, 0.15)
+            .normalize(MinAmountPolicy::RoundUp)
+            .expect("rounded up");
+        assert!((normalized.min_amount - 0.2).abs() < 1e-12);
+        assert_eq!(normalized.amount_step, 0.1);
+    }
+
+    #[test]
+    fn normalize_strict_rejects_off_grid_min_amount() {
+        assert_eq!(
+            instrument_with_amounts(0.1, 0.15).normalize(MinAmountPolicy::Strict),
+            Err(InstrumentMetadataError::MinAmountNotStepMultiple {
+                min_amount: 0.15,
+                amount_step: 0.1,
+            })
+        );
+        assert_eq!(
+            instrument_with_amounts(0.0, 0.15).normalize(MinAmountPolicy::RoundUp),
+            Err(InstrumentMetadataError::NonPositiveAmountStep)
+        );
     }
 }
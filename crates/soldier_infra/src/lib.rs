@@ -4,9 +4,11 @@
 pub mod config;
 pub mod deribit;
 pub mod health;
+pub mod recovery;
 pub mod store;
 pub mod wal;
 
 pub use deribit::{DeribitInstrument, DeribitPublicInstrumentKind, DeribitPublicSettlementPeriod};
+pub use recovery::find_duplicate_exchange_ids;
 pub use store::{TradeIdInsertOutcome, TradeIdRecord, TradeIdRegistry, TradeIdRegistryError};
 pub use wal::{DurableAppendOutcome, Wal, WalConfig, WalError, WalRecord, WalSide};
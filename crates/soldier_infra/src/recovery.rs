@@ -0,0 +1,198 @@
+//! Ledger consistency checks run during recovery, before any replayed intent is resent.
+
+use soldier_core::execution::{IntentNotional, OrderIntent, OrderType, Side, TlsmState};
+use soldier_core::risk::PendingExposureTracker;
+use soldier_core::venue::InstrumentMetadata;
+
+use crate::store::{self, LedgerRecord, LedgerReplay};
+
+/// Timestamps on a recovered record that no valid TLSM history could produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsmRecoveryError {
+    SentBeforeCreated,
+    AckWithoutSent,
+    AckBeforeSent,
+    FillWithoutSent,
+    FillBeforeSent,
+}
+
+/// Persisted fields too thin to rebuild a resend intent from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryError {
+    /// Neither `qty_q` nor `qty_steps` was persisted.
+    MissingQuantity,
+    /// Neither `limit_price_q` nor `price_ticks` was persisted.
+    MissingPrice,
+}
+
+/// Exchange order ids claimed by more than one intent in the replay, paired with the
+/// claiming `intent_hash`es. Ids and hashes are listed in replay order.
+pub fn find_duplicate_exchange_ids(replay: &LedgerReplay) -> Vec<(String, Vec<u64>)> {
+    let mut claims: Vec<(String, Vec<u64>)> = Vec::new();
+    for record in &replay.records {
+        let Some(exchange_order_id) = record.exchange_order_id.as_ref() else {
+            continue;
+        };
+        match claims.iter_mut().find(|(id, _)| id == exchange_order_id) {
+            Some((_, intents)) => {
+                if !intents.contains(&record.intent_hash) {
+                    intents.push(record.intent_hash);
+                }
+            }
+            None => claims.push((exchange_order_id.clone(), vec![record.intent_hash])),
+        }
+    }
+    claims.retain(|(_, intents)| intents.len() > 1);
+    for (exchange_order_id, intents) in &claims {
+        eprintln!(
+            "recovery_duplicate_exchange_order_id exchange_order_id={} intent_hashes={:?}",
+            exchange_order_id, intents
+        );
+    }
+    claims
+}
+
+/// TLSM state implied by which lifecycle timestamps a recovered record carries:
+/// none → Created, sent → Sent, ack → Acked, any fill → PartiallyFilled. A fill without
+/// an ack is accepted (fill-before-ack race); whether the order is fully filled is left to
+/// reconciliation.
+pub fn tlsm_state_from_record(record: &LedgerRecord) -> Result<TlsmState, TlsmRecoveryError> {
+    if let Some(sent_ts) = record.sent_ts
+        && sent_ts < record.created_ts
+    {
+        return Err(TlsmRecoveryError::SentBeforeCreated);
+    }
+    if let Some(ack_ts) = record.ack_ts {
+        match record.sent_ts {
+            None => return Err(TlsmRecoveryError::AckWithoutSent),
+            Some(sent_ts) if ack_ts < sent_ts => return Err(TlsmRecoveryError::AckBeforeSent),
+            Some(_) => {}
+        }
+    }
+    if let Some(last_fill_ts) = record.last_fill_ts {
+        match record.sent_ts {
+            None => return Err(TlsmRecoveryError::FillWithoutSent),
+            Some(sent_ts) if last_fill_ts < sent_ts => {
+                return Err(TlsmRecoveryError::FillBeforeSent);
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(match (record.sent_ts, record.ack_ts, record.last_fill_ts) {
+        (_, _, Some(_)) => TlsmState::PartiallyFilled,
+        (_, Some(_), None) => TlsmState::Acked,
+        (Some(_), None, None) => TlsmState::Sent,
+        (None, None, None) => TlsmState::Created,
+    })
+}
+
+/// Rebuilds the `OrderIntent` for a record reconciliation classified as resend, so it can be
+/// re-run through the gate stack. The ledger does not persist instrument kind or step/tick
+/// sizes, so `meta` (for `record.instrument`) supplies them: `qty_q`/`limit_price_q` win,
+/// otherwise `qty_steps * amount_step` / `price_ticks * tick_size`. Only limit orders are
+/// persisted, so the trigger fields stay unset.
+pub fn intent_from_record(
+    record: &LedgerRecord,
+    meta: &InstrumentMetadata,
+) -> Result<OrderIntent, RecoveryError> {
+    let qty = record
+        .qty_q
+        .or_else(|| {
+            record
+                .qty_steps
+                .map(|steps| steps as f64 * meta.amount_step)
+        })
+        .ok_or(RecoveryError::MissingQuantity)?;
+    let price = record
+        .limit_price_q
+        .or_else(|| {
+            record
+                .price_ticks
+                .map(|ticks| ticks as f64 * meta.tick_size)
+        })
+        .ok_or(RecoveryError::MissingPrice)?;
+
+    Ok(OrderIntent {
+        instrument_kind: meta.instrument_kind,
+        order_type: OrderType::Limit,
+        trigger: None,
+        trigger_price: None,
+        linked_order_type: None,
+        notional: Some(IntentNotional {
+            qty,
+            price,
+            contract_multiplier: meta.contract_multiplier,
+        }),
+        side: Some(match record.side {
+            store::Side::Buy => Side::Buy,
+            store::Side::Sell => Side::Sell,
+        }),
+        limit_price: Some(price),
+        reduce_only: None,
+        instrument_tradable: None,
+        reference_price: None,
+        instrument_id: Some(record.instrument.clone()),
+        open_order_count: None,
+        trigger_reference: None,
+    })
+}
+
+/// Outcome of [`rebuild_reservations`], as `intent_hash`es in replay order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReservationRebuild {
+    pub restored: Vec<u64>,
+    /// Terminal (Filled/Canceled/Failed) or never sent.
+    pub skipped: Vec<u64>,
+    /// Live but without `qty_q`, so the exposure could not be sized; callers should fail
+    /// closed until reconciliation sizes them.
+    pub missing_qty: Vec<u64>,
+}
+
+/// Re-seeds an empty `tracker` after a restart from orders that were sent and are not yet
+/// terminal per the persisted `tls_state`. Unsent records are skipped: they reserve again
+/// when re-run through the gate stack. The full `qty_q` is restored (signed by side), which
+/// over-counts a partially filled order until reconciliation releases the filled part.
+pub fn rebuild_reservations(
+    replay: &LedgerReplay,
+    tracker: &PendingExposureTracker,
+) -> ReservationRebuild {
+    let mut rebuild = ReservationRebuild::default();
+    for record in &replay.records {
+        let terminal = persisted_tlsm_state(&record.tls_state).is_some_and(TlsmState::is_terminal);
+        if terminal || record.sent_ts.is_none() {
+            rebuild.skipped.push(record.intent_hash);
+            continue;
+        }
+        let Some(qty) = record.qty_q.filter(|qty| qty.is_finite()) else {
+            eprintln!(
+                "recovery_reservation_missing_qty intent_hash={} instrument={}",
+                record.intent_hash, record.instrument
+            );
+            rebuild.missing_qty.push(record.intent_hash);
+            continue;
+        };
+        let delta = match record.side {
+            store::Side::Buy => qty.abs(),
+            store::Side::Sell => -qty.abs(),
+        };
+        tracker.restore_reservation(record.intent_hash.to_string(), &record.instrument, delta);
+        rebuild.restored.push(record.intent_hash);
+    }
+    rebuild
+}
+
+fn persisted_tlsm_state(tls_state: &str) -> Option<TlsmState> {
+    [
+        TlsmState::Created,
+        TlsmState::Sent,
+        TlsmState::Unconfirmed,
+        TlsmState::Acked,
+        TlsmState::PartiallyFilled,
+        TlsmState::Filled,
+        TlsmState::Canceled,
+        TlsmState::Failed,
+    ]
+    .into_iter()
+    .find(|state| state.as_str() == tls_state)
+}
@@ -7,14 +7,28 @@
 //! Success means RecordedBeforeDispatch. If the queue is full, the call returns immediately
 //! with an error (hot loop is not blocked) and `wal_write_errors` increments.
 //!
+//! Group commit: `record_group_before_dispatch` enqueues a combo's legs as one queue slot.
+//! The writer appends a `group_commit` marker and the legs in a single write; replay keeps a
+//! frame only when every leg follows its marker, so a torn append drops the whole group.
+//!
 //! Replay: `replay_latest` reads the WAL file and returns the latest record per intent_hash.
 //! The caller must reconcile with the exchange before dispatch. To mark replay outcomes
 //! (sent/ack/fill), append an updated record (see `record_replay_outcome`). A record with
 //! `sent_ts` set is treated as already dispatched and must not be resent.
+//!
+//! Sequencing: the writer thread stamps every record with a monotonically increasing `seq`
+//! (resumed from the max persisted seq on open). Replay orders by `seq`; legacy lines without
+//! one sort first in file order. `check_sequence` reports gaps/reordering (possible loss).
+//!
+//! Incremental replay: `replay_since` reads only bytes appended after a `ReplayCursor`;
+//! fold the result into an earlier replay with `LedgerReplay::merge`. The cursor remembers a
+//! checksum of the last line it consumed; if the WAL was truncated, compacted or rewritten
+//! underneath it (that line is no longer where the cursor left it), it falls back to a full
+//! re-scan.
 
 use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
@@ -63,6 +77,8 @@ pub struct LedgerRecord {
     pub last_fill_ts: Option<u64>,
     pub exchange_order_id: Option<String>,
     pub last_trade_id: Option<String>,
+    /// Assigned by the writer thread on append; any caller-provided value is overwritten.
+    pub seq: Option<u64>,
 }
 
 impl LedgerRecord {
@@ -124,7 +140,7 @@ impl LedgerRecord {
 
     fn to_line(&self) -> String {
         format!(
-            "intent_hash={}|group_id={}|leg_idx={}|instrument={}|side={}|qty_steps={}|qty_q={}|limit_price_q={}|price_ticks={}|tls_state={}|created_ts={}|sent_ts={}|ack_ts={}|last_fill_ts={}|exchange_order_id={}|last_trade_id={}",
+            "intent_hash={}|group_id={}|leg_idx={}|instrument={}|side={}|qty_steps={}|qty_q={}|limit_price_q={}|price_ticks={}|tls_state={}|created_ts={}|sent_ts={}|ack_ts={}|last_fill_ts={}|exchange_order_id={}|last_trade_id={}|seq={}",
             self.intent_hash,
             escape_field(&self.group_id),
             self.leg_idx,
@@ -141,10 +157,11 @@ impl LedgerRecord {
             format_opt_u64(self.last_fill_ts),
             format_opt_string(&self.exchange_order_id),
             format_opt_string(&self.last_trade_id),
+            format_opt_u64(self.seq),
         )
     }
 
-    fn from_line(line: &str) -> Result<Self, LedgerError> {
+    pub(crate) fn from_line(line: &str) -> Result<Self, LedgerError> {
         let mut fields: HashMap<&str, &str> = HashMap::new();
         for part in line.split('|') {
             if part.trim().is_empty() {
@@ -177,6 +194,7 @@ impl LedgerRecord {
             last_fill_ts: parse_opt_u64(fields.get("last_fill_ts"))?,
             exchange_order_id: parse_opt_string(fields.get("exchange_order_id"))?,
             last_trade_id: parse_opt_string(fields.get("last_trade_id"))?,
+            seq: parse_opt_u64(fields.get("seq"))?,
         };
         record.validate_minimum()?;
         Ok(record)
@@ -214,6 +232,72 @@ impl LedgerReplay {
             .iter()
             .find(|record| record.intent_hash == intent_hash)
     }
+
+    /// Latest record for a logical leg; when several intent hashes share the leg (e.g. a
+    /// rebuilt resend), the one replayed last wins.
+    pub fn record_by_group_leg(&self, group_id: &str, leg_idx: u32) -> Option<&LedgerRecord> {
+        self.records
+            .iter()
+            .rev()
+            .find(|record| record.group_id == group_id && record.leg_idx == leg_idx)
+    }
+
+    /// Latest record per leg of `group_id`, ordered by `leg_idx`.
+    pub fn records_for_group(&self, group_id: &str) -> Vec<&LedgerRecord> {
+        let mut legs: Vec<&LedgerRecord> = Vec::new();
+        for record in self.records.iter().filter(|r| r.group_id == group_id) {
+            match legs.iter_mut().find(|leg| leg.leg_idx == record.leg_idx) {
+                Some(leg) => *leg = record,
+                None => legs.push(record),
+            }
+        }
+        legs.sort_by_key(|record| record.leg_idx);
+        legs
+    }
+
+    /// Folds a later replay in: newer records replace older ones per intent_hash and move
+    /// to the end, matching `replay_latest` ordering.
+    pub fn merge(&mut self, newer: LedgerReplay) {
+        let mut ordered = std::mem::take(&mut self.records);
+        ordered.extend(newer.records);
+        self.records = latest_per_intent(ordered);
+    }
+}
+
+/// Byte offset into the WAL up to which records have been replayed (always a line boundary).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReplayCursor {
+    offset: u64,
+    rescanned: bool,
+    /// Last line consumed before `offset`; identifies the file the offset belongs to.
+    anchor: Option<LineAnchor>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LineAnchor {
+    start: u64,
+    checksum: u64,
+}
+
+impl ReplayCursor {
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// True when the previous cursor was invalid and the WAL was re-read from the start;
+    /// the accompanying replay is then complete and should replace, not merge into, prior state.
+    pub fn rescanned(&self) -> bool {
+        self.rescanned
+    }
+}
+
+/// Sequence anomaly found by `Ledger::check_sequence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeqAnomaly {
+    /// Seqs between `previous` and `found` are missing (lost records).
+    Gap { previous: u64, found: u64 },
+    /// `found` does not increase past `previous` (reordered or duplicated).
+    OutOfOrder { previous: u64, found: u64 },
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -249,6 +333,7 @@ impl From<std::io::Error> for LedgerError {
 
 enum LedgerWrite {
     Record(Box<LedgerRecord>),
+    Group(Vec<LedgerRecord>),
     Flush(mpsc::Sender<Result<(), LedgerError>>),
     Shutdown,
 }
@@ -281,6 +366,7 @@ impl Ledger {
         let path = path.as_ref().to_path_buf();
         ensure_parent_dir(&path)?;
         ensure_wal_file(&path)?;
+        let next_seq = max_persisted_seq(&path)? + 1;
 
         let (tx, rx) = mpsc::sync_channel(config.queue_capacity);
         let queue_depth = Arc::new(AtomicUsize::new(0));
@@ -293,7 +379,14 @@ impl Ledger {
         let writer_pause = Arc::clone(&writer_paused);
 
         let handle = thread::spawn(move || {
-            writer_loop(rx, writer_path, writer_depth, writer_errors, writer_pause);
+            writer_loop(
+                rx,
+                writer_path,
+                writer_depth,
+                writer_errors,
+                writer_pause,
+                next_seq,
+            );
         });
 
         Ok(Self {
@@ -343,6 +436,46 @@ impl Ledger {
         }
     }
 
+    /// Records every leg of a group all-or-nothing. Legs must share a non-empty `group_id`
+    /// with distinct `leg_idx`; on `QueueFull` nothing is enqueued.
+    pub fn record_group_before_dispatch(
+        &self,
+        records: Vec<LedgerRecord>,
+    ) -> Result<RecordOutcome, LedgerError> {
+        let Some(first) = records.first() else {
+            return Err(LedgerError::RecordSchema(
+                "group must have at least one leg".to_string(),
+            ));
+        };
+        for (idx, record) in records.iter().enumerate() {
+            record.validate_minimum()?;
+            if record.group_id != first.group_id {
+                return Err(LedgerError::RecordSchema(
+                    "group legs must share group_id".to_string(),
+                ));
+            }
+            if records[..idx]
+                .iter()
+                .any(|earlier| earlier.leg_idx == record.leg_idx)
+            {
+                return Err(LedgerError::RecordSchema(format!(
+                    "duplicate leg_idx {} in group",
+                    record.leg_idx
+                )));
+            }
+        }
+        match self.writer_tx.try_send(LedgerWrite::Group(records)) {
+            Ok(()) => {
+                self.queue_depth.fetch_add(1, Ordering::Relaxed);
+                Ok(RecordOutcome::RecordedBeforeDispatch)
+            }
+            Err(err) => {
+                self.wal_write_errors.fetch_add(1, Ordering::Relaxed);
+                Err(map_send_error(err))
+            }
+        }
+    }
+
     pub fn record_replay_outcome(
         &self,
         record: LedgerRecord,
@@ -370,40 +503,268 @@ impl Ledger {
 
     pub fn replay_latest(&self) -> Result<LedgerReplay, LedgerError> {
         ensure_wal_file(&self.path)?;
-        let file = File::open(&self.path)?;
-        let reader = BufReader::new(file);
-        let mut ordered: Vec<LedgerRecord> = Vec::new();
-        for (idx, line) in reader.lines().enumerate() {
-            let line = line?;
-            if line.trim().is_empty() {
+        let text = std::fs::read_to_string(&self.path)?;
+        let parsed = parse_wal_text(&text)
+            .map_err(|(line_no, err)| LedgerError::Parse(format!("line {line_no}: {err:?}")))?;
+
+        Ok(LedgerReplay {
+            records: latest_per_intent(parsed.records),
+        })
+    }
+
+    /// Replays only complete lines appended since `cursor`. A cursor whose last consumed line
+    /// is no longer at the same place with the same bytes (truncated, compacted or rewritten
+    /// WAL) triggers a full re-scan.
+    /// A group frame still missing legs at the tail is left for the next call.
+    pub fn replay_since(
+        &self,
+        cursor: ReplayCursor,
+    ) -> Result<(LedgerReplay, ReplayCursor), LedgerError> {
+        ensure_wal_file(&self.path)?;
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        let cursor_valid = match cursor.anchor {
+            None => cursor.offset == 0,
+            Some(anchor) => {
+                cursor.offset <= len
+                    && line_checksum(&mut file, anchor.start, cursor.offset)? == anchor.checksum
+            }
+        };
+        let start = if cursor_valid { cursor.offset } else { 0 };
+
+        file.seek(SeekFrom::Start(start))?;
+        let mut appended = Vec::new();
+        file.read_to_end(&mut appended)?;
+        // A trailing partial line is still being written; leave it for the next call.
+        let complete_len = appended
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |idx| idx + 1);
+
+        let text = std::str::from_utf8(&appended[..complete_len])
+            .map_err(|err| LedgerError::Parse(format!("offset {start}: {err}")))?;
+        let parsed = parse_wal_text(text).map_err(|(line_no, err)| {
+            LedgerError::Parse(format!("offset {start} line {line_no}: {err:?}"))
+        })?;
+
+        let consumed = &appended[..parsed.complete_len];
+        let anchor = match consumed.len() {
+            0 if cursor_valid => cursor.anchor,
+            0 => None,
+            len => {
+                let line_start = consumed[..len - 1]
+                    .iter()
+                    .rposition(|byte| *byte == b'\n')
+                    .map_or(0, |idx| idx + 1);
+                Some(LineAnchor {
+                    start: start + line_start as u64,
+                    checksum: fnv1a(&consumed[line_start..]),
+                })
+            }
+        };
+
+        Ok((
+            LedgerReplay {
+                records: latest_per_intent(parsed.records),
+            },
+            ReplayCursor {
+                offset: start + parsed.complete_len as u64,
+                rescanned: !cursor_valid,
+                anchor,
+            },
+        ))
+    }
+
+    /// Walks persisted `seq`s in file order and reports gaps and non-increasing steps.
+    /// Legacy lines without a seq are skipped.
+    pub fn check_sequence(&self) -> Result<Vec<SeqAnomaly>, LedgerError> {
+        ensure_wal_file(&self.path)?;
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut anomalies = Vec::new();
+        let mut previous: Option<u64> = None;
+        for line in reader.lines() {
+            let Some(found) = line_seq(&line?)? else {
                 continue;
+            };
+            match previous {
+                Some(previous) if found <= previous => {
+                    anomalies.push(SeqAnomaly::OutOfOrder { previous, found });
+                }
+                Some(previous) if found > previous + 1 => {
+                    anomalies.push(SeqAnomaly::Gap { previous, found });
+                }
+                _ => {}
             }
-            let record = LedgerRecord::from_line(&line)
-                .map_err(|err| LedgerError::Parse(format!("line {}: {:?}", idx + 1, err)))?;
-            ordered.push(record);
+            previous = Some(previous.map_or(found, |previous| previous.max(found)));
         }
+        Ok(anomalies)
+    }
+}
+
+const GROUP_MARKER_PREFIX: &str = "group_commit=";
 
-        let mut latest_by_intent: HashMap<u64, LedgerRecord> = HashMap::new();
-        let mut order: Vec<u64> = Vec::new();
-        for record in ordered {
-            if let Some(pos) = order.iter().position(|hash| *hash == record.intent_hash) {
-                order.remove(pos);
+struct ParsedWal {
+    records: Vec<LedgerRecord>,
+    /// Bytes up to the start of an incomplete trailing group frame (all of `text` if none).
+    complete_len: usize,
+}
+
+struct OpenGroup {
+    group_id: String,
+    expected_legs: usize,
+    legs: Vec<LedgerRecord>,
+    start: usize,
+}
+
+impl OpenGroup {
+    /// A leg of this frame: same group, a leg index not seen yet, and (when sequenced) the
+    /// seq right after the previous leg, since a frame's legs are numbered in one run.
+    fn accepts(&self, record: &LedgerRecord) -> bool {
+        record.group_id == self.group_id
+            && !self.legs.iter().any(|leg| leg.leg_idx == record.leg_idx)
+            && self
+                .legs
+                .last()
+                .is_none_or(|previous| match (previous.seq, record.seq) {
+                    (Some(previous), Some(seq)) => seq == previous + 1,
+                    _ => true,
+                })
+    }
+}
+
+/// Parses WAL lines in file order, expanding group frames. A frame interrupted by another
+/// marker or by any record that is not one of its legs is dropped. Errors carry the 1-based line.
+fn parse_wal_text(text: &str) -> Result<ParsedWal, (usize, LedgerError)> {
+    let mut records = Vec::new();
+    let mut open_group: Option<OpenGroup> = None;
+    let mut offset = 0;
+    for (idx, raw) in text.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += raw.len();
+        let line = raw.trim_end_matches(['\n', '\r']);
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(marker) = line.strip_prefix(GROUP_MARKER_PREFIX) {
+            let (group_id, expected_legs) =
+                parse_group_marker(marker).map_err(|err| (idx + 1, err))?;
+            if let Some(torn) = open_group.take() {
+                log_torn_group(&torn);
             }
-            order.push(record.intent_hash);
-            latest_by_intent.insert(record.intent_hash, record);
+            open_group = Some(OpenGroup {
+                group_id,
+                expected_legs,
+                legs: Vec::new(),
+                start,
+            });
+            continue;
         }
 
-        let mut latest = Vec::with_capacity(order.len());
-        for intent_hash in order {
-            if let Some(record) = latest_by_intent.remove(&intent_hash) {
-                latest.push(record);
+        let record = LedgerRecord::from_line(line).map_err(|err| (idx + 1, err))?;
+        match open_group.as_mut() {
+            Some(group) if group.accepts(&record) => group.legs.push(record),
+            Some(_) => {
+                if let Some(torn) = open_group.take() {
+                    log_torn_group(&torn);
+                }
+                records.push(record);
             }
+            None => records.push(record),
+        }
+        if let Some(group) = open_group.as_ref()
+            && group.legs.len() >= group.expected_legs
+            && let Some(group) = open_group.take()
+        {
+            records.extend(group.legs);
+        }
+    }
+
+    Ok(ParsedWal {
+        records,
+        complete_len: open_group.map_or(text.len(), |group| group.start),
+    })
+}
+
+/// `group_commit=<group_id>|legs=<n>`
+fn parse_group_marker(marker: &str) -> Result<(String, usize), LedgerError> {
+    let (group_id, legs) = marker
+        .split_once("|legs=")
+        .ok_or_else(|| LedgerError::Parse("group marker missing legs".to_string()))?;
+    let legs = legs
+        .parse()
+        .map_err(|_| LedgerError::Parse("invalid group marker legs".to_string()))?;
+    Ok((unescape_field(group_id)?, legs))
+}
+
+fn log_torn_group(group: &OpenGroup) {
+    eprintln!(
+        "ledger_torn_group_dropped group_id={} legs_found={} legs_expected={}",
+        group.group_id,
+        group.legs.len(),
+        group.expected_legs
+    );
+}
+
+/// Highest `seq` in the WAL (0 when none). Reads only the `seq` field so a damaged line
+/// elsewhere does not block open; replay still surfaces it.
+fn max_persisted_seq(path: &Path) -> Result<u64, LedgerError> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut max_seq = 0;
+    for line in reader.lines() {
+        if let Ok(Some(seq)) = line_seq(&line?) {
+            max_seq = max_seq.max(seq);
         }
+    }
+    Ok(max_seq)
+}
 
-        Ok(LedgerReplay { records: latest })
+fn line_seq(line: &str) -> Result<Option<u64>, LedgerError> {
+    match line.split('|').find_map(|part| part.strip_prefix("seq=")) {
+        Some(raw) => parse_opt_u64(Some(&raw)),
+        None => Ok(None),
     }
 }
 
+/// Checksum of the bytes in `start..end`; callers have checked `end` is within the file.
+fn line_checksum(file: &mut File, start: u64, end: u64) -> Result<u64, LedgerError> {
+    if start >= end {
+        return Ok(fnv1a(&[]));
+    }
+    let mut line = vec![0u8; (end - start) as usize];
+    file.seek(SeekFrom::Start(start))?;
+    file.read_exact(&mut line)?;
+    Ok(fnv1a(&line))
+}
+
+/// 64-bit FNV-1a: stable across processes, unlike `DefaultHasher`'s contract.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn latest_per_intent(mut ordered: Vec<LedgerRecord>) -> Vec<LedgerRecord> {
+    // Stable: unsequenced (legacy) records keep file order ahead of sequenced ones.
+    ordered.sort_by_key(|record| record.seq.unwrap_or(0));
+    let mut latest_by_intent: HashMap<u64, LedgerRecord> = HashMap::new();
+    let mut order: Vec<u64> = Vec::new();
+    for record in ordered {
+        if let Some(pos) = order.iter().position(|hash| *hash == record.intent_hash) {
+            order.remove(pos);
+        }
+        order.push(record.intent_hash);
+        latest_by_intent.insert(record.intent_hash, record);
+    }
+
+    let mut latest = Vec::with_capacity(order.len());
+    for intent_hash in order {
+        if let Some(record) = latest_by_intent.remove(&intent_hash) {
+            latest.push(record);
+        }
+    }
+    latest
+}
+
 impl Drop for Ledger {
     fn drop(&mut self) {
         self.writer_paused.store(false, Ordering::Relaxed);
@@ -420,6 +781,7 @@ fn writer_loop(
     queue_depth: Arc<AtomicUsize>,
     wal_write_errors: Arc<AtomicU64>,
     writer_paused: Arc<AtomicBool>,
+    mut next_seq: u64,
 ) {
     let mut file = match OpenOptions::new().create(true).append(true).open(&path) {
         Ok(file) => file,
@@ -431,16 +793,32 @@ fn writer_loop(
 
     loop {
         match rx.recv() {
-            Ok(LedgerWrite::Record(record)) => {
+            Ok(LedgerWrite::Record(mut record)) => {
                 while writer_paused.load(Ordering::Relaxed) {
                     thread::sleep(Duration::from_millis(10));
                 }
+                // A failed write still consumes its seq, so the loss shows up as a gap.
+                record.seq = Some(next_seq);
+                next_seq += 1;
                 let result = write_record(&mut file, &record);
                 if result.is_err() {
                     wal_write_errors.fetch_add(1, Ordering::Relaxed);
                 }
                 queue_depth.fetch_sub(1, Ordering::Relaxed);
             }
+            Ok(LedgerWrite::Group(mut records)) => {
+                while writer_paused.load(Ordering::Relaxed) {
+                    thread::sleep(Duration::from_millis(10));
+                }
+                for record in &mut records {
+                    record.seq = Some(next_seq);
+                    next_seq += 1;
+                }
+                if write_group(&mut file, &records).is_err() {
+                    wal_write_errors.fetch_add(1, Ordering::Relaxed);
+                }
+                queue_depth.fetch_sub(1, Ordering::Relaxed);
+            }
             Ok(LedgerWrite::Flush(reply)) => {
                 let result = file.sync_data().map_err(LedgerError::Io);
                 let _ = reply.send(result);
@@ -458,6 +836,24 @@ fn write_record(file: &mut File, record: &LedgerRecord) -> Result<(), LedgerErro
     Ok(())
 }
 
+/// Marker and legs go out in one `write_all` so a crash can only tear the frame's tail.
+fn write_group(file: &mut File, records: &[LedgerRecord]) -> Result<(), LedgerError> {
+    let Some(first) = records.first() else {
+        return Ok(());
+    };
+    let mut frame = format!(
+        "{GROUP_MARKER_PREFIX}{}|legs={}\n",
+        escape_field(&first.group_id),
+        records.len()
+    );
+    for record in records {
+        frame.push_str(&record.to_line());
+        frame.push('\n');
+    }
+    file.write_all(frame.as_bytes())?;
+    Ok(())
+}
+
 fn map_send_error(err: TrySendError<LedgerWrite>) -> LedgerError {
     match err {
         TrySendError::Full(_) => LedgerError::QueueFull,
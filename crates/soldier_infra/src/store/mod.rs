@@ -4,8 +4,8 @@ pub mod ledger;
 pub mod trade_id_registry;
 
 pub use ledger::{
-    Ledger, LedgerConfig, LedgerError, LedgerRecord, LedgerReplay, RecordOutcome, ReplayOutcome,
-    Side,
+    Ledger, LedgerConfig, LedgerError, LedgerRecord, LedgerReplay, RecordOutcome, ReplayCursor,
+    ReplayOutcome, SeqAnomaly, Side,
 };
 pub use trade_id_registry::{
     TradeIdInsertOutcome, TradeIdRecord, TradeIdRegistry, TradeIdRegistryError,
@@ -9,6 +9,8 @@ use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use soldier_core::idempotency::{DedupOutcome, record_duplicate_suppressed};
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TradeIdRecord {
     pub trade_id: String,
@@ -96,6 +98,15 @@ pub enum TradeIdInsertOutcome {
     Duplicate,
 }
 
+impl From<TradeIdInsertOutcome> for DedupOutcome {
+    fn from(outcome: TradeIdInsertOutcome) -> Self {
+        match outcome {
+            TradeIdInsertOutcome::Inserted => DedupOutcome::Accepted,
+            TradeIdInsertOutcome::Duplicate => DedupOutcome::DuplicateTradeId,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum TradeIdRegistryError {
     Io(std::io::Error),
@@ -174,6 +185,7 @@ impl TradeIdRegistry {
 
         if state.records.contains_key(&record.trade_id) {
             self.trade_id_duplicates.fetch_add(1, Ordering::Relaxed);
+            record_duplicate_suppressed(DedupOutcome::DuplicateTradeId);
             return Ok(TradeIdInsertOutcome::Duplicate);
         }
 
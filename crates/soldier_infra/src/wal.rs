@@ -3,9 +3,27 @@
 //! RecordedBeforeDispatch remains non-blocking (enqueue only). If the config flag
 //! `require_wal_fsync_before_dispatch` is enabled, callers can await a durability
 //! barrier that completes only after fsync (or equivalent) finishes.
+//!
+//! Replay: `replay_ordered` returns every written record in strict append order
+//! (one line per append, sides preserved). Call `flush` first to include queued records.
+//!
+//! Integrity: each line ends with `|crc=<crc32 hex>` over the preceding bytes. `replay(strict)`
+//! fails on the first bad line when strict; lenient skips bad lines (e.g. a torn tail after a
+//! crash) and counts them in `wal_corrupt_records_total`.
+//!
+//! Format versions: files created by this writer start with a `wal_format=2` header, and
+//! every line in them must carry a CRC. Legacy files (no header) replay their CRC-less lines
+//! as-is until the first CRC-bearing line; once the upgraded writer has appended to them, a
+//! line without a CRC is corrupt.
+//!
+//! Watermark: the writer fsyncs every `fsync_interval_ms` while records are pending, and on
+//! every barrier/flush. `durable_up_to_seq()` is the highest `LedgerRecord.seq` known to be on
+//! disk; records past it are written but not yet durable. `durable_append_count()` is the
+//! same watermark counted in appends by this handle (session-local, restarting at 0 on every
+//! open), for records that carry no seq.
 
 use std::fs::{File, OpenOptions};
-use std::io::Write;
+use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
@@ -15,6 +33,12 @@ use std::time::{Duration, Instant};
 
 use crate::store::{LedgerError, LedgerRecord, RecordOutcome, Side};
 
+/// First line of every WAL created by this writer; marks CRCs as mandatory.
+pub const WAL_FORMAT_HEADER: &str = "wal_format=2";
+const CRC_SEPARATOR: &str = "|crc=";
+/// `DurableWatermark::seq` sentinel for "no seq synced yet".
+const NO_DURABLE_SEQ: u64 = u64::MAX;
+
 pub type WalRecord = LedgerRecord;
 pub type WalSide = Side;
 
@@ -26,6 +50,8 @@ pub struct WalConfig {
     /// before dispatch (config flag: require_wal_fsync_before_dispatch).
     pub require_wal_fsync_before_dispatch: bool,
     pub durability_timeout: Duration,
+    /// Background fsync cadence for the durability watermark; 0 disables it.
+    pub fsync_interval_ms: u64,
 }
 
 impl Default for WalConfig {
@@ -35,6 +61,7 @@ impl Default for WalConfig {
             writer_pause_on_start: false,
             require_wal_fsync_before_dispatch: false,
             durability_timeout: Duration::from_secs(5),
+            fsync_interval_ms: 100,
         }
     }
 }
@@ -52,6 +79,7 @@ pub enum WalError {
     RecordSchema(String),
     Io(std::io::Error),
     BarrierTimeout,
+    Corrupt { line: usize, reason: String },
 }
 
 impl From<std::io::Error> for WalError {
@@ -65,19 +93,42 @@ enum WalWrite {
         record: Box<WalRecord>,
         barrier: Option<mpsc::Sender<Result<(), WalError>>>,
     },
+    Flush(mpsc::Sender<Result<(), WalError>>),
     Shutdown,
 }
 
 pub struct Wal {
+    path: PathBuf,
     writer_tx: SyncSender<WalWrite>,
     writer_handle: Mutex<Option<thread::JoinHandle<()>>>,
-    writer_paused: Arc<AtomicBool>,
+    pause: Arc<WriterPause>,
     queue_depth: Arc<AtomicUsize>,
     queue_capacity: usize,
     wal_write_errors: Arc<AtomicU64>,
     require_wal_fsync_before_dispatch: bool,
     durability_timeout: Duration,
     last_barrier_wait_ms: AtomicU64,
+    wal_corrupt_records: AtomicU64,
+    durable: Arc<DurableWatermark>,
+}
+
+/// Pause switch for the writer thread. `pending_flushes` lets a flush drain the queue through a
+/// paused writer without resuming it.
+struct WriterPause {
+    paused: AtomicBool,
+    pending_flushes: AtomicUsize,
+}
+
+impl WriterPause {
+    fn holds_writes(&self) -> bool {
+        self.paused.load(Ordering::Relaxed) && self.pending_flushes.load(Ordering::Acquire) == 0
+    }
+}
+
+/// How far fsync has advanced, published by the writer after every successful sync.
+struct DurableWatermark {
+    appends: AtomicU64,
+    seq: AtomicU64,
 }
 
 impl Wal {
@@ -95,31 +146,52 @@ impl Wal {
         let path = path.as_ref().to_path_buf();
         ensure_parent_dir(&path)?;
         ensure_wal_file(&path)?;
+        write_format_header_if_new(&path)?;
 
         let (tx, rx) = mpsc::sync_channel(config.queue_capacity);
         let queue_depth = Arc::new(AtomicUsize::new(0));
         let wal_write_errors = Arc::new(AtomicU64::new(0));
-        let writer_paused = Arc::new(AtomicBool::new(config.writer_pause_on_start));
+        let pause = Arc::new(WriterPause {
+            paused: AtomicBool::new(config.writer_pause_on_start),
+            pending_flushes: AtomicUsize::new(0),
+        });
 
         let writer_path = path.clone();
         let writer_depth = Arc::clone(&queue_depth);
         let writer_errors = Arc::clone(&wal_write_errors);
-        let writer_pause = Arc::clone(&writer_paused);
+        let writer_pause = Arc::clone(&pause);
+        let durable = Arc::new(DurableWatermark {
+            appends: AtomicU64::new(0),
+            seq: AtomicU64::new(NO_DURABLE_SEQ),
+        });
+        let writer_durable = Arc::clone(&durable);
+        let fsync_interval = Duration::from_millis(config.fsync_interval_ms);
 
         let handle = thread::spawn(move || {
-            writer_loop(rx, writer_path, writer_depth, writer_errors, writer_pause);
+            writer_loop(
+                rx,
+                writer_path,
+                writer_depth,
+                writer_errors,
+                writer_pause,
+                writer_durable,
+                fsync_interval,
+            );
         });
 
         Ok(Self {
+            path,
             writer_tx: tx,
             writer_handle: Mutex::new(Some(handle)),
-            writer_paused,
+            pause,
             queue_depth,
             queue_capacity: config.queue_capacity,
             wal_write_errors,
             require_wal_fsync_before_dispatch: config.require_wal_fsync_before_dispatch,
             durability_timeout: config.durability_timeout,
             last_barrier_wait_ms: AtomicU64::new(0),
+            wal_corrupt_records: AtomicU64::new(0),
+            durable,
         })
     }
 
@@ -135,12 +207,31 @@ impl Wal {
         self.wal_write_errors.load(Ordering::Relaxed)
     }
 
+    pub fn wal_corrupt_records_total(&self) -> u64 {
+        self.wal_corrupt_records.load(Ordering::Relaxed)
+    }
+
     pub fn wal_durability_barrier_wait_ms(&self) -> u64 {
         self.last_barrier_wait_ms.load(Ordering::Relaxed)
     }
 
+    /// Records appended since this `Wal` was opened that are fsynced to disk; 0 until the
+    /// first sync. Not a `LedgerRecord.seq` and not comparable across reopens.
+    pub fn durable_append_count(&self) -> u64 {
+        self.durable.appends.load(Ordering::Acquire)
+    }
+
+    /// Highest `LedgerRecord.seq` written by this handle and fsynced to disk; `None` until a
+    /// record carrying a seq is synced.
+    pub fn durable_up_to_seq(&self) -> Option<u64> {
+        match self.durable.seq.load(Ordering::Acquire) {
+            NO_DURABLE_SEQ => None,
+            seq => Some(seq),
+        }
+    }
+
     pub fn resume_writer(&self) {
-        self.writer_paused.store(false, Ordering::Relaxed);
+        self.pause.paused.store(false, Ordering::Relaxed);
     }
 
     pub fn record_before_dispatch(&self, record: WalRecord) -> Result<RecordOutcome, WalError> {
@@ -187,6 +278,76 @@ impl Wal {
         }
     }
 
+    pub fn flush(&self) -> Result<(), WalError> {
+        // Flush is a durability barrier; it must not hang behind a paused writer. The writer
+        // drains through it without resuming, and stays paused for later records.
+        self.pause.pending_flushes.fetch_add(1, Ordering::AcqRel);
+        let (tx, rx) = mpsc::channel();
+        if self.writer_tx.send(WalWrite::Flush(tx)).is_err() {
+            self.pause.pending_flushes.fetch_sub(1, Ordering::AcqRel);
+            return Err(WalError::WriterUnavailable(
+                "writer channel closed".to_string(),
+            ));
+        }
+
+        rx.recv_timeout(self.durability_timeout)
+            .map_err(|_| WalError::WriterUnavailable("flush timeout".to_string()))?
+    }
+
+    /// Every record written so far, in strict append order (no per-intent collapsing).
+    pub fn replay_ordered(&self) -> Result<Vec<WalRecord>, WalError> {
+        self.replay(true)
+    }
+
+    /// Replays in append order, verifying each line's CRC. Strict returns `Corrupt` on the
+    /// first bad line; lenient skips it and bumps `wal_corrupt_records_total`. CRC-less lines
+    /// are accepted only in the legacy prefix of a headerless file.
+    pub fn replay(&self, strict: bool) -> Result<Vec<WalRecord>, WalError> {
+        ensure_wal_file(&self.path)?;
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut records = Vec::new();
+        let mut crc_required = false;
+        let mut first_line = true;
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if std::mem::take(&mut first_line) && line == WAL_FORMAT_HEADER {
+                crc_required = true;
+                continue;
+            }
+            let body = if line.contains(CRC_SEPARATOR) {
+                crc_required = true;
+                verify_crc(&line)
+            } else if crc_required {
+                Err("missing crc".to_string())
+            } else {
+                Ok(line.as_str())
+            };
+            let parsed =
+                body.and_then(|body| WalRecord::from_line(body).map_err(|err| format!("{err:?}")));
+            match parsed {
+                Ok(record) => records.push(record),
+                Err(reason) if strict => {
+                    return Err(WalError::Corrupt {
+                        line: idx + 1,
+                        reason,
+                    });
+                }
+                Err(reason) => {
+                    self.wal_corrupt_records.fetch_add(1, Ordering::Relaxed);
+                    eprintln!(
+                        "wal_corrupt_records_total line={} reason={}",
+                        idx + 1,
+                        reason
+                    );
+                }
+            }
+        }
+        Ok(records)
+    }
+
     fn enqueue_record(
         &self,
         record: WalRecord,
@@ -210,7 +371,7 @@ impl Wal {
 
 impl Drop for Wal {
     fn drop(&mut self) {
-        self.writer_paused.store(false, Ordering::Relaxed);
+        self.pause.paused.store(false, Ordering::Relaxed);
         let _ = self.writer_tx.send(WalWrite::Shutdown);
         if let Ok(mut handle_opt) = self.writer_handle.lock()
             && let Some(handle) = handle_opt.take()
@@ -225,7 +386,9 @@ fn writer_loop(
     path: PathBuf,
     queue_depth: Arc<AtomicUsize>,
     wal_write_errors: Arc<AtomicU64>,
-    writer_paused: Arc<AtomicBool>,
+    writer_pause: Arc<WriterPause>,
+    durable: Arc<DurableWatermark>,
+    fsync_interval: Duration,
 ) {
     let mut file = match OpenOptions::new().create(true).append(true).open(&path) {
         Ok(file) => file,
@@ -235,35 +398,76 @@ fn writer_loop(
         }
     };
 
+    let mut written_count = 0u64;
+    let mut written_seq: Option<u64> = None;
+    let mut last_sync = Instant::now();
+    let sync =
+        |file: &File, written_count: u64, written_seq: Option<u64>| -> Result<(), WalError> {
+            let result = file.sync_data().map_err(WalError::Io);
+            match &result {
+                Ok(()) => {
+                    durable.appends.store(written_count, Ordering::Release);
+                    if let Some(seq) = written_seq {
+                        durable.seq.store(seq, Ordering::Release);
+                    }
+                }
+                Err(_) => {
+                    wal_write_errors.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            result
+        };
+
     loop {
-        match rx.recv() {
+        let next = if fsync_interval.is_zero() {
+            rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected)
+        } else {
+            rx.recv_timeout(fsync_interval.saturating_sub(last_sync.elapsed()))
+        };
+        match next {
             Ok(WalWrite::Record { record, barrier }) => {
-                while writer_paused.load(Ordering::Relaxed) {
+                while writer_pause.holds_writes() {
                     thread::sleep(Duration::from_millis(10));
                 }
                 let write_result = write_record(&mut file, &record);
                 let mut write_error = None;
-                if let Err(err) = write_result {
-                    wal_write_errors.fetch_add(1, Ordering::Relaxed);
-                    write_error = Some(err);
+                match write_result {
+                    Ok(()) => {
+                        written_count += 1;
+                        written_seq = written_seq.max(record.seq);
+                    }
+                    Err(err) => {
+                        wal_write_errors.fetch_add(1, Ordering::Relaxed);
+                        write_error = Some(err);
+                    }
                 }
                 if let Some(reply) = barrier {
                     let result = match write_error {
                         Some(err) => Err(err),
                         None => {
-                            let sync_result = file.sync_data().map_err(WalError::Io);
-                            if sync_result.is_err() {
-                                wal_write_errors.fetch_add(1, Ordering::Relaxed);
-                            }
-                            sync_result
+                            last_sync = Instant::now();
+                            sync(&file, written_count, written_seq)
                         }
                     };
                     let _ = reply.send(result);
                 }
                 queue_depth.fetch_sub(1, Ordering::Relaxed);
             }
+            Ok(WalWrite::Flush(reply)) => {
+                last_sync = Instant::now();
+                let _ = reply.send(sync(&file, written_count, written_seq));
+                writer_pause.pending_flushes.fetch_sub(1, Ordering::AcqRel);
+            }
             Ok(WalWrite::Shutdown) => break,
-            Err(_) => break,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+
+        if !fsync_interval.is_zero() && last_sync.elapsed() >= fsync_interval {
+            last_sync = Instant::now();
+            if written_count > durable.appends.load(Ordering::Acquire) {
+                let _ = sync(&file, written_count, written_seq);
+            }
         }
     }
 }
@@ -289,7 +493,8 @@ fn map_record_error(err: LedgerError) -> WalError {
 }
 
 fn write_record(file: &mut File, record: &WalRecord) -> Result<(), WalError> {
-    let line = record_to_line(record);
+    let body = record_to_line(record);
+    let line = format!("{body}{CRC_SEPARATOR}{:08x}", crc32(body.as_bytes()));
     file.write_all(line.as_bytes())?;
     file.write_all(b"\n")?;
     Ok(())
@@ -297,7 +502,7 @@ fn write_record(file: &mut File, record: &WalRecord) -> Result<(), WalError> {
 
 fn record_to_line(record: &WalRecord) -> String {
     format!(
-        "intent_hash={}|group_id={}|leg_idx={}|instrument={}|side={}|qty_steps={}|qty_q={}|limit_price_q={}|price_ticks={}|tls_state={}|created_ts={}|sent_ts={}|ack_ts={}|last_fill_ts={}|exchange_order_id={}|last_trade_id={}",
+        "intent_hash={}|group_id={}|leg_idx={}|instrument={}|side={}|qty_steps={}|qty_q={}|limit_price_q={}|price_ticks={}|tls_state={}|created_ts={}|sent_ts={}|ack_ts={}|last_fill_ts={}|exchange_order_id={}|last_trade_id={}|seq={}",
         record.intent_hash,
         escape_field(&record.group_id),
         record.leg_idx,
@@ -314,9 +519,56 @@ fn record_to_line(record: &WalRecord) -> String {
         format_opt_u64(record.last_fill_ts),
         format_opt_string(&record.exchange_order_id),
         format_opt_string(&record.last_trade_id),
+        format_opt_u64(record.seq),
     )
 }
 
+fn verify_crc(line: &str) -> Result<&str, String> {
+    let (body, crc_hex) = line
+        .rsplit_once(CRC_SEPARATOR)
+        .ok_or_else(|| "missing crc".to_string())?;
+    let expected =
+        u32::from_str_radix(crc_hex, 16).map_err(|_| format!("invalid crc: {crc_hex}"))?;
+    let actual = crc32(body.as_bytes());
+    if actual != expected {
+        return Err(format!(
+            "crc mismatch: expected {expected:08x} got {actual:08x}"
+        ));
+    }
+    Ok(body)
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// CRC-32 (IEEE 802.3, reflected, poly 0xEDB88320).
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut idx = 0;
+    while idx < 256 {
+        let mut crc = idx as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[idx] = crc;
+        idx += 1;
+    }
+    table
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc = (crc >> 8) ^ CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize];
+    }
+    !crc
+}
+
 fn format_opt_i64(value: Option<i64>) -> String {
     value.map(|v| v.to_string()).unwrap_or_default()
 }
@@ -366,3 +618,14 @@ fn ensure_wal_file(path: &Path) -> Result<(), WalError> {
     OpenOptions::new().create(true).append(true).open(path)?;
     Ok(())
 }
+
+/// Stamps an empty WAL with the format header; existing (possibly legacy) files are left as-is.
+fn write_format_header_if_new(path: &Path) -> Result<(), WalError> {
+    let mut file = OpenOptions::new().append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        file.write_all(WAL_FORMAT_HEADER.as_bytes())?;
+        file.write_all(b"\n")?;
+        file.sync_all()?;
+    }
+    Ok(())
+}
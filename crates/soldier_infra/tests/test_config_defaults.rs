@@ -1,9 +1,10 @@
 //! Integration tests for Appendix A config defaults (PRD S1-010).
 
+use soldier_core::risk::MarginConfig;
 use soldier_infra::config::{
     ConfigError, EVIDENCEGUARD_GLOBAL_COOLDOWN_DEFAULT, INSTRUMENT_CACHE_TTL_S_DEFAULT,
-    MM_UTIL_KILL_DEFAULT, ParamKind, SafetyConfigInput, apply_defaults, resolve_required_f64,
-    resolve_required_u64,
+    MM_UTIL_KILL_DEFAULT, Millis, ParamKind, Ratio, SafetyConfigInput, Secs, apply_defaults,
+    cross_validate, resolve_required_f64, resolve_required_u64,
 };
 
 /// GIVEN config omits instrument_cache_ttl_s and evidenceguard_global_cooldown
@@ -21,15 +22,17 @@ fn test_defaults_applied_for_instrument_cache_and_evidenceguard_cooldown() {
         apply_defaults(input).expect("defaults should apply for missing Appendix A values");
 
     assert_eq!(
-        config.instrument_cache_ttl_s, INSTRUMENT_CACHE_TTL_S_DEFAULT,
+        config.instrument_cache_ttl_s,
+        Secs(INSTRUMENT_CACHE_TTL_S_DEFAULT),
         "instrument_cache_ttl_s MUST use Appendix A default"
     );
     assert_eq!(
-        config.evidenceguard_global_cooldown, EVIDENCEGUARD_GLOBAL_COOLDOWN_DEFAULT,
+        config.evidenceguard_global_cooldown,
+        Secs(EVIDENCEGUARD_GLOBAL_COOLDOWN_DEFAULT),
         "evidenceguard_global_cooldown MUST use Appendix A default"
     );
     assert!(
-        (config.mm_util_kill - 0.90).abs() < f64::EPSILON,
+        (config.mm_util_kill.get() - 0.90).abs() < f64::EPSILON,
         "provided mm_util_kill should be preserved"
     );
 }
@@ -48,7 +51,7 @@ fn test_default_applied_for_mm_util_kill() {
     let config = apply_defaults(input).expect("mm_util_kill should default when missing");
 
     assert!(
-        (config.mm_util_kill - MM_UTIL_KILL_DEFAULT).abs() < f64::EPSILON,
+        (config.mm_util_kill.get() - MM_UTIL_KILL_DEFAULT).abs() < f64::EPSILON,
         "mm_util_kill MUST use Appendix A default"
     );
 }
@@ -96,3 +99,70 @@ fn test_type_mismatch_fails_closed() {
         "error message MUST be deterministic"
     );
 }
+
+/// GIVEN a ratio outside [0, 1]
+/// WHEN it is constructed or supplied as mm_util_kill
+/// THEN it is rejected instead of silently disabling the kill threshold.
+#[test]
+fn test_ratio_out_of_range_rejected() {
+    assert!(Ratio::new(1.5).is_err());
+    assert!(Ratio::new(f64::NAN).is_err());
+    assert_eq!(Ratio::new(0.95).expect("valid ratio").get(), 0.95);
+
+    let err = apply_defaults(SafetyConfigInput {
+        instrument_cache_ttl_s: None,
+        evidenceguard_global_cooldown: None,
+        mm_util_kill: Some(95.0),
+    })
+    .expect_err("percentage passed as ratio must fail closed");
+    assert_eq!(
+        err,
+        ConfigError::OutOfRange {
+            key: "mm_util_kill"
+        }
+    );
+}
+
+/// GIVEN a duration in seconds
+/// WHEN it is converted to milliseconds
+/// THEN the value is scaled by 1000.
+#[test]
+fn test_secs_to_millis_conversion() {
+    assert_eq!(Millis::from(Secs(120)), Millis(120_000));
+    assert_eq!(Millis::from(Secs(u64::MAX)), Millis(u64::MAX));
+}
+
+fn default_safety_input() -> SafetyConfigInput {
+    SafetyConfigInput {
+        instrument_cache_ttl_s: None,
+        evidenceguard_global_cooldown: None,
+        mm_util_kill: None,
+    }
+}
+
+#[test]
+fn test_cross_validate_accepts_consistent_defaults() {
+    let safety = apply_defaults(default_safety_input()).expect("defaults");
+    assert_eq!(cross_validate(&safety, &MarginConfig::default()), Ok(()));
+}
+
+#[test]
+fn test_cross_validate_reports_mm_util_kill_mismatch() {
+    let safety = apply_defaults(default_safety_input()).expect("defaults");
+    let margin = MarginConfig {
+        mm_util_kill: 0.90,
+        ..MarginConfig::default()
+    };
+
+    let err = cross_validate(&safety, &margin).expect_err("mismatch must fail");
+    assert_eq!(
+        err,
+        ConfigError::CrossConfigMismatch {
+            key: "mm_util_kill",
+            other: "MarginConfig",
+            safety_value: "0.95".to_string(),
+            other_value: "0.9".to_string(),
+        }
+    );
+    assert!(err.to_string().contains("mm_util_kill"));
+}
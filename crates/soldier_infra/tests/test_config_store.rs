@@ -0,0 +1,84 @@
+//! Integration tests for runtime config reloads.
+
+use soldier_infra::config::{
+    ConfigChange, ConfigError, ConfigStore, KeyClass, OperationalConfig, Ratio, RuntimeConfig,
+    SafetyConfigInput, apply_defaults, key_class,
+};
+
+fn runtime_config() -> RuntimeConfig {
+    let safety = apply_defaults(SafetyConfigInput {
+        instrument_cache_ttl_s: None,
+        evidenceguard_global_cooldown: None,
+        mm_util_kill: None,
+    })
+    .expect("defaults");
+    RuntimeConfig {
+        safety,
+        operational: OperationalConfig::default(),
+    }
+}
+
+#[test]
+fn test_config_store_classifies_keys() {
+    assert_eq!(key_class("mm_util_kill"), Some(KeyClass::SafetyCritical));
+    assert_eq!(
+        key_class("instrument_cache_ttl_s"),
+        Some(KeyClass::SafetyCritical)
+    );
+    assert_eq!(key_class("spread_max_bps"), Some(KeyClass::Operational));
+    assert_eq!(key_class("depth_min"), Some(KeyClass::Operational));
+    assert_eq!(key_class("unknown_key"), None);
+}
+
+#[test]
+fn test_config_store_rejects_runtime_mm_util_kill_change() {
+    let initial = runtime_config();
+    let mut store = ConfigStore::new(initial);
+
+    let mut next = initial;
+    next.safety.mm_util_kill = Ratio::new(0.90).expect("ratio");
+    next.operational.spread_max_bps = 30.0;
+    assert_eq!(
+        store.reload(next),
+        Err(ConfigError::SafetyCriticalChange {
+            key: "mm_util_kill"
+        })
+    );
+    assert_eq!(store.current(), &initial, "rejected reload applies nothing");
+}
+
+#[test]
+fn test_config_store_applies_spread_max_bps_with_change_event() {
+    let initial = runtime_config();
+    let mut store = ConfigStore::new(initial);
+
+    let mut next = initial;
+    next.operational.spread_max_bps = 30.0;
+    let event = store.reload(next).expect("operational change accepted");
+    assert_eq!(
+        event.changes,
+        vec![ConfigChange {
+            key: "spread_max_bps",
+            old: "25".to_string(),
+            new: "30".to_string(),
+        }]
+    );
+    assert_eq!(store.current().operational.spread_max_bps, 30.0);
+
+    let event = store.reload(next).expect("no-op reload");
+    assert!(event.changes.is_empty());
+}
+
+#[test]
+fn test_config_store_rejects_invalid_operational_value() {
+    let initial = runtime_config();
+    let mut store = ConfigStore::new(initial);
+
+    let mut next = initial;
+    next.operational.depth_min = f64::NAN;
+    assert_eq!(
+        store.reload(next),
+        Err(ConfigError::InvalidOperational { key: "depth_min" })
+    );
+    assert_eq!(store.current(), &initial);
+}
@@ -36,6 +36,7 @@ fn sample_record(intent_hash: u64) -> LedgerRecord {
         last_fill_ts: None,
         exchange_order_id: None,
         last_trade_id: None,
+        seq: None,
     }
 }
 
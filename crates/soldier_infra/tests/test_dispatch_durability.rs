@@ -3,6 +3,7 @@ use std::sync::{Arc, mpsc};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use soldier_infra::store::RecordOutcome;
+use soldier_infra::wal::WAL_FORMAT_HEADER;
 use soldier_infra::{Wal, WalConfig, WalError, WalRecord, WalSide};
 
 fn temp_wal_path(test_name: &str) -> PathBuf {
@@ -38,6 +39,14 @@ fn sample_record(intent_hash: u64) -> WalRecord {
         last_fill_ts: None,
         exchange_order_id: None,
         last_trade_id: None,
+        seq: None,
+    }
+}
+
+fn sequenced_record(seq: u64) -> WalRecord {
+    WalRecord {
+        seq: Some(seq),
+        ..sample_record(seq)
     }
 }
 
@@ -140,3 +149,247 @@ fn test_open_blocked_when_wal_enqueue_fails() {
 
     wal.resume_writer();
 }
+
+#[test]
+fn test_wal_replay_ordered_preserves_append_order_and_sides() {
+    let path = temp_wal_path("replay_ordered");
+    let wal = Wal::open(&path).expect("open wal");
+
+    let submitted: Vec<WalRecord> = (1..=6)
+        .map(|intent_hash| WalRecord {
+            side: if intent_hash % 2 == 0 {
+                WalSide::Sell
+            } else {
+                WalSide::Buy
+            },
+            ..sample_record(intent_hash)
+        })
+        .collect();
+
+    for record in &submitted[..3] {
+        wal.record_before_dispatch(record.clone())
+            .expect("record before flush");
+    }
+    wal.flush().expect("flush");
+    for record in &submitted[3..] {
+        wal.record_before_dispatch(record.clone())
+            .expect("record after flush");
+    }
+    wal.flush().expect("flush again");
+
+    let replayed = wal.replay_ordered().expect("replay");
+    assert_eq!(replayed, submitted);
+    drop(wal);
+
+    // A re-opened WAL replays the same order
+    let wal = Wal::open(&path).expect("reopen wal");
+    assert_eq!(
+        wal.replay_ordered().expect("replay after reopen"),
+        submitted
+    );
+}
+
+fn write_three_records(test_name: &str) -> PathBuf {
+    let path = temp_wal_path(test_name);
+    let wal = Wal::open(&path).expect("open wal");
+    for intent_hash in 1..=3 {
+        wal.record_before_dispatch(sample_record(intent_hash))
+            .expect("record");
+    }
+    wal.flush().expect("flush");
+    path
+}
+
+#[test]
+fn test_wal_replay_strict_fails_on_corrupt_mid_file_record() {
+    let path = write_three_records("crc_mid_file");
+    let contents = std::fs::read_to_string(&path).expect("read wal");
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    // Line 1 is the format header; corrupt the second record.
+    lines[2] = lines[2].replace("BTC-PERP", "ETH-PERP");
+    std::fs::write(&path, lines.join("\n") + "\n").expect("write wal");
+
+    let wal = Wal::open(&path).expect("reopen wal");
+    let err = wal.replay(true).expect_err("strict replay must fail");
+    assert!(matches!(err, WalError::Corrupt { line: 3, .. }), "{err:?}");
+    assert_eq!(wal.wal_corrupt_records_total(), 0);
+
+    let hashes: Vec<u64> = wal
+        .replay(false)
+        .expect("lenient replay")
+        .iter()
+        .map(|record| record.intent_hash)
+        .collect();
+    assert_eq!(hashes, vec![1, 3]);
+    assert_eq!(wal.wal_corrupt_records_total(), 1);
+}
+
+#[test]
+fn test_wal_replay_lenient_skips_torn_tail() {
+    let path = write_three_records("crc_torn_tail");
+    let mut contents = std::fs::read_to_string(&path).expect("read wal");
+    let torn = contents.lines().nth(1).expect("first record")[..40].to_string();
+    contents.push_str(&torn);
+    std::fs::write(&path, contents).expect("write wal");
+
+    let wal = Wal::open(&path).expect("reopen wal");
+    assert!(matches!(
+        wal.replay(true),
+        Err(WalError::Corrupt { line: 5, .. })
+    ));
+
+    let replayed = wal.replay(false).expect("lenient replay");
+    assert_eq!(replayed.len(), 3);
+    assert_eq!(wal.wal_corrupt_records_total(), 1);
+}
+
+fn strip_crc(line: &str) -> &str {
+    line.rsplit_once("|crc=").expect("crc suffix").0
+}
+
+#[test]
+fn test_wal_replays_legacy_lines_without_crc() {
+    // A pre-CRC WAL: no format header, bare record lines.
+    let source = write_three_records("legacy_source");
+    let contents = std::fs::read_to_string(&source).expect("read wal");
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines[0], WAL_FORMAT_HEADER);
+    let path = temp_wal_path("legacy_upgrade");
+    std::fs::write(
+        &path,
+        format!("{}\n{}\n", strip_crc(lines[1]), strip_crc(lines[2])),
+    )
+    .expect("write legacy wal");
+
+    let wal = Wal::open(&path).expect("open legacy wal");
+    let hashes: Vec<u64> = wal
+        .replay(true)
+        .expect("strict replay of legacy lines")
+        .iter()
+        .map(|record| record.intent_hash)
+        .collect();
+    assert_eq!(hashes, vec![1, 2]);
+
+    // The upgraded writer appends CRC lines; a CRC-less line after them is corrupt.
+    wal.record_before_dispatch(sample_record(3))
+        .expect("record");
+    wal.flush().expect("flush");
+    assert_eq!(wal.replay(true).expect("mixed replay").len(), 3);
+    let mut upgraded = std::fs::read_to_string(&path).expect("read wal");
+    upgraded.push_str(strip_crc(lines[1]));
+    upgraded.push('\n');
+    std::fs::write(&path, upgraded).expect("write wal");
+    assert!(matches!(
+        wal.replay(true),
+        Err(WalError::Corrupt { line: 4, .. })
+    ));
+}
+
+#[test]
+fn test_wal_with_format_header_rejects_lines_without_crc() {
+    let path = write_three_records("header_requires_crc");
+    let contents = std::fs::read_to_string(&path).expect("read wal");
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    lines[2] = strip_crc(&lines[2]).to_string();
+    std::fs::write(&path, lines.join("\n") + "\n").expect("write wal");
+
+    let wal = Wal::open(&path).expect("reopen wal");
+    assert!(matches!(
+        wal.replay(true),
+        Err(WalError::Corrupt { line: 3, .. })
+    ));
+    assert_eq!(wal.replay(false).expect("lenient replay").len(), 2);
+}
+
+#[test]
+fn test_wal_background_fsync_advances_durable_watermark() {
+    let path = temp_wal_path("fsync_watermark");
+    let wal = Wal::open_with_config(
+        &path,
+        WalConfig {
+            fsync_interval_ms: 20,
+            ..WalConfig::default()
+        },
+    )
+    .expect("open wal");
+    assert_eq!(wal.durable_append_count(), 0);
+    assert_eq!(wal.durable_up_to_seq(), None);
+
+    for seq in 1..=3 {
+        wal.record_before_dispatch(sequenced_record(seq))
+            .expect("record");
+    }
+    let deadline = std::time::Instant::now() + Duration::from_secs(2);
+    while wal.durable_up_to_seq() < Some(3) && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(wal.durable_up_to_seq(), Some(3));
+    assert_eq!(wal.durable_append_count(), 3);
+}
+
+#[test]
+fn test_wal_flush_makes_every_append_durable() {
+    let path = temp_wal_path("flush_watermark");
+    let wal = Wal::open_with_config(
+        &path,
+        WalConfig {
+            fsync_interval_ms: 0,
+            ..WalConfig::default()
+        },
+    )
+    .expect("open wal");
+
+    for seq in 1..=4 {
+        wal.record_before_dispatch(sequenced_record(seq))
+            .expect("record");
+    }
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(
+        wal.durable_up_to_seq(),
+        None,
+        "no background fsync when disabled"
+    );
+    assert_eq!(wal.durable_append_count(), 0);
+
+    wal.flush().expect("flush");
+    assert_eq!(wal.durable_up_to_seq(), Some(4));
+    assert_eq!(wal.durable_append_count(), 4);
+    drop(wal);
+
+    // Session-local: a reopened handle counts its own appends from zero.
+    let wal = Wal::open(&path).expect("reopen wal");
+    assert_eq!(wal.durable_append_count(), 0);
+    assert_eq!(wal.replay_ordered().expect("replay").len(), 4);
+}
+
+#[test]
+fn test_wal_flush_drains_paused_writer_without_resuming_it() {
+    let path = temp_wal_path("flush_keeps_pause");
+    let wal = Wal::open_with_config(
+        &path,
+        WalConfig {
+            writer_pause_on_start: true,
+            fsync_interval_ms: 0,
+            ..WalConfig::default()
+        },
+    )
+    .expect("open wal");
+
+    for seq in 1..=2 {
+        wal.record_before_dispatch(sequenced_record(seq))
+            .expect("record");
+    }
+    wal.flush().expect("flush drains the paused queue");
+    assert_eq!(wal.durable_up_to_seq(), Some(2));
+
+    // Still paused: a record queued after the flush is not written.
+    wal.record_before_dispatch(sequenced_record(3))
+        .expect("record");
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(wal.replay_ordered().expect("replay").len(), 2);
+    assert_eq!(wal.wal_queue_depth(), 1);
+
+    wal.resume_writer();
+    wal.flush().expect("flush");
+    assert_eq!(wal.durable_up_to_seq(), Some(3));
+}
@@ -4,7 +4,17 @@ use std::thread;
 use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use soldier_infra::store::{Ledger, LedgerConfig, LedgerError, LedgerRecord, ReplayOutcome, Side};
+use soldier_core::execution::{IntentNotional, OrderType, Side as IntentSide, TlsmState};
+use soldier_core::risk::PendingExposureTracker;
+use soldier_core::venue::{InstrumentKind, InstrumentMetadata};
+use soldier_infra::recovery::{
+    RecoveryError, TlsmRecoveryError, find_duplicate_exchange_ids, intent_from_record,
+    rebuild_reservations, tlsm_state_from_record,
+};
+use soldier_infra::store::{
+    Ledger, LedgerConfig, LedgerError, LedgerRecord, LedgerReplay, ReplayCursor, ReplayOutcome,
+    SeqAnomaly, Side,
+};
 
 fn temp_wal_path(test_name: &str) -> PathBuf {
     let mut path = std::env::temp_dir();
@@ -39,6 +49,7 @@ fn sample_record(intent_hash: u64) -> LedgerRecord {
         last_fill_ts: None,
         exchange_order_id: None,
         last_trade_id: None,
+        seq: None,
     }
 }
 
@@ -77,6 +88,53 @@ fn test_ledger_replay_no_resend_after_crash() {
     assert_eq!(latest.sent_ts, Some(200));
 }
 
+#[test]
+fn test_ledger_replay_lookup_by_group_leg() {
+    let path = temp_wal_path("group_leg");
+    let ledger = Ledger::open(&path).expect("open ledger");
+    let leg = |intent_hash: u64, group_id: &str, leg_idx: u32| LedgerRecord {
+        group_id: group_id.to_string(),
+        leg_idx,
+        ..sample_record(intent_hash)
+    };
+    // Legs appended out of order, plus an unrelated group.
+    for record in [
+        leg(1, "group-a", 2),
+        leg(2, "group-a", 0),
+        leg(3, "group-b", 0),
+        leg(4, "group-a", 1),
+    ] {
+        ledger.record_before_dispatch(record).expect("record leg");
+    }
+    ledger.flush().expect("flush");
+    ledger
+        .record_replay_outcome(leg(2, "group-a", 0), ReplayOutcome::Sent { sent_ts: 50 })
+        .expect("mark leg 0 sent");
+    ledger.flush().expect("flush after sent");
+
+    let replay = ledger.replay_latest().expect("replay");
+    let leg0 = replay
+        .record_by_group_leg("group-a", 0)
+        .expect("group-a leg 0");
+    assert_eq!(leg0.intent_hash, 2);
+    assert_eq!(leg0.sent_ts, Some(50));
+    assert_eq!(
+        replay
+            .record_by_group_leg("group-b", 0)
+            .map(|r| r.intent_hash),
+        Some(3)
+    );
+    assert!(replay.record_by_group_leg("group-a", 3).is_none());
+
+    let legs: Vec<(u32, u64)> = replay
+        .records_for_group("group-a")
+        .iter()
+        .map(|record| (record.leg_idx, record.intent_hash))
+        .collect();
+    assert_eq!(legs, vec![(0, 2), (1, 4), (2, 1)]);
+    assert!(replay.records_for_group("group-c").is_empty());
+}
+
 #[test]
 fn test_ledger_append_queue_full_increments_error() {
     let path = temp_wal_path("queue_full");
@@ -168,3 +226,552 @@ fn test_ledger_drop_does_not_hang_when_writer_paused() {
     rx.recv_timeout(Duration::from_secs(2))
         .expect("drop should not deadlock");
 }
+
+fn record_with_exchange_id(intent_hash: u64, exchange_order_id: &str) -> LedgerRecord {
+    LedgerRecord {
+        exchange_order_id: Some(exchange_order_id.to_string()),
+        ..sample_record(intent_hash)
+    }
+}
+
+#[test]
+fn test_recovery_reports_duplicate_exchange_order_ids() {
+    let replay = LedgerReplay {
+        records: vec![
+            record_with_exchange_id(1, "ex-100"),
+            record_with_exchange_id(2, "ex-200"),
+            record_with_exchange_id(3, "ex-100"),
+            sample_record(4),
+        ],
+    };
+
+    let duplicates = find_duplicate_exchange_ids(&replay);
+    assert_eq!(duplicates, vec![("ex-100".to_string(), vec![1, 3])]);
+}
+
+#[test]
+fn test_recovery_clean_replay_has_no_duplicate_exchange_ids() {
+    let replay = LedgerReplay {
+        records: vec![
+            record_with_exchange_id(1, "ex-100"),
+            record_with_exchange_id(2, "ex-200"),
+            sample_record(3),
+        ],
+    };
+
+    assert!(find_duplicate_exchange_ids(&replay).is_empty());
+}
+
+#[test]
+fn test_ledger_replay_since_cursor_returns_only_new_records() {
+    let path = temp_wal_path("replay_cursor");
+    let ledger = Ledger::open(&path).expect("open ledger");
+
+    ledger
+        .record_before_dispatch(sample_record(1))
+        .expect("record 1");
+    ledger
+        .record_before_dispatch(sample_record(2))
+        .expect("record 2");
+    ledger.flush().expect("flush");
+
+    let (mut state, cursor) = ledger
+        .replay_since(ReplayCursor::default())
+        .expect("initial replay");
+    assert!(!cursor.rescanned());
+    assert_eq!(state.records.len(), 2);
+
+    ledger
+        .record_replay_outcome(sample_record(1), ReplayOutcome::Sent { sent_ts: 50 })
+        .expect("mark 1 sent");
+    ledger
+        .record_before_dispatch(sample_record(3))
+        .expect("record 3");
+    ledger.flush().expect("flush");
+
+    let (delta, next) = ledger.replay_since(cursor).expect("incremental replay");
+    assert!(next.offset() > cursor.offset());
+    let hashes: Vec<u64> = delta.records.iter().map(|r| r.intent_hash).collect();
+    assert_eq!(hashes, vec![1, 3], "only records appended after the cursor");
+
+    state.merge(delta);
+    assert_eq!(state, ledger.replay_latest().expect("full replay"));
+
+    let (empty, unchanged) = ledger.replay_since(next).expect("no new data");
+    assert!(empty.records.is_empty());
+    assert_eq!(unchanged.offset(), next.offset());
+}
+
+#[test]
+fn test_ledger_replay_since_rescans_when_wal_truncated() {
+    let path = temp_wal_path("replay_cursor_truncated");
+    let ledger = Ledger::open(&path).expect("open ledger");
+    for intent_hash in 1..=3 {
+        ledger
+            .record_before_dispatch(sample_record(intent_hash))
+            .expect("record");
+    }
+    ledger.flush().expect("flush");
+    let (_, cursor) = ledger
+        .replay_since(ReplayCursor::default())
+        .expect("initial replay");
+    drop(ledger);
+
+    // Compaction rewrote the WAL shorter than the cursor
+    std::fs::write(&path, "").expect("truncate wal");
+    let ledger = Ledger::open(&path).expect("reopen ledger");
+    ledger
+        .record_before_dispatch(sample_record(9))
+        .expect("record after compaction");
+    ledger.flush().expect("flush");
+
+    let (replay, next) = ledger
+        .replay_since(cursor)
+        .expect("replay after compaction");
+    assert!(next.rescanned());
+    let hashes: Vec<u64> = replay.records.iter().map(|r| r.intent_hash).collect();
+    assert_eq!(hashes, vec![9]);
+}
+
+#[test]
+fn test_ledger_replay_since_rescans_when_wal_rewritten_longer() {
+    let path = temp_wal_path("replay_cursor_rewritten");
+    let ledger = Ledger::open(&path).expect("open ledger");
+    for intent_hash in 1..=2 {
+        ledger
+            .record_before_dispatch(sample_record(intent_hash))
+            .expect("record");
+    }
+    ledger.flush().expect("flush");
+    let (_, cursor) = ledger
+        .replay_since(ReplayCursor::default())
+        .expect("initial replay");
+    drop(ledger);
+
+    // Rotation replaced the WAL with unrelated records of the same shape, so the old offset
+    // still lands on a line boundary in the new file.
+    std::fs::write(&path, "").expect("rotate wal");
+    let ledger = Ledger::open(&path).expect("reopen ledger");
+    for intent_hash in 7..=9 {
+        ledger
+            .record_before_dispatch(sample_record(intent_hash))
+            .expect("record after rotation");
+    }
+    ledger.flush().expect("flush");
+    let rotated = std::fs::read(&path).expect("read wal");
+    assert_eq!(rotated[cursor.offset() as usize - 1], b'\n');
+
+    let (replay, next) = ledger.replay_since(cursor).expect("replay after rotation");
+    assert!(next.rescanned());
+    let hashes: Vec<u64> = replay.records.iter().map(|r| r.intent_hash).collect();
+    assert_eq!(hashes, vec![7, 8, 9]);
+
+    // The fresh cursor resumes normally.
+    let (empty, resumed) = ledger.replay_since(next).expect("no new data");
+    assert!(!resumed.rescanned());
+    assert!(empty.records.is_empty());
+}
+
+#[test]
+fn test_ledger_seq_strictly_increasing_and_resumes_after_reopen() {
+    let path = temp_wal_path("seq_resume");
+    let ledger = Ledger::open(&path).expect("open ledger");
+    for intent_hash in 1..=3 {
+        ledger
+            .record_before_dispatch(LedgerRecord {
+                seq: Some(999),
+                ..sample_record(intent_hash)
+            })
+            .expect("record");
+    }
+    ledger.flush().expect("flush");
+    let seqs: Vec<Option<u64>> = ledger
+        .replay_latest()
+        .expect("replay")
+        .records
+        .iter()
+        .map(|record| record.seq)
+        .collect();
+    assert_eq!(seqs, vec![Some(1), Some(2), Some(3)], "writer assigns seq");
+    drop(ledger);
+
+    let reopened = Ledger::open(&path).expect("reopen ledger");
+    reopened
+        .record_before_dispatch(sample_record(4))
+        .expect("record after reopen");
+    reopened.flush().expect("flush");
+    let replay = reopened.replay_latest().expect("replay");
+    assert_eq!(
+        replay
+            .record_by_intent_hash(4)
+            .and_then(|record| record.seq),
+        Some(4)
+    );
+    assert!(reopened.check_sequence().expect("check").is_empty());
+}
+
+#[test]
+fn test_ledger_seq_detects_gap_and_reorder_and_replays_by_seq() {
+    let path = temp_wal_path("seq_anomaly");
+    let ledger = Ledger::open(&path).expect("open ledger");
+    for intent_hash in 1..=4 {
+        ledger
+            .record_before_dispatch(sample_record(intent_hash))
+            .expect("record");
+    }
+    ledger.flush().expect("flush");
+    drop(ledger);
+
+    // Reorder lines to seq 1, 3, 2, 4.
+    let contents = std::fs::read_to_string(&path).expect("read wal");
+    let lines: Vec<&str> = contents.lines().collect();
+    let shuffled = [lines[0], lines[2], lines[1], lines[3]].join("\n") + "\n";
+    std::fs::write(&path, shuffled).expect("write wal");
+
+    let ledger = Ledger::open(&path).expect("reopen ledger");
+    assert_eq!(
+        ledger.check_sequence().expect("check"),
+        vec![
+            SeqAnomaly::Gap {
+                previous: 1,
+                found: 3
+            },
+            SeqAnomaly::OutOfOrder {
+                previous: 3,
+                found: 2
+            },
+        ]
+    );
+    let hashes: Vec<u64> = ledger
+        .replay_latest()
+        .expect("replay")
+        .records
+        .iter()
+        .map(|record| record.intent_hash)
+        .collect();
+    assert_eq!(hashes, vec![1, 2, 3, 4], "replay orders by seq, not line");
+}
+
+fn record_with_timestamps(
+    sent_ts: Option<u64>,
+    ack_ts: Option<u64>,
+    last_fill_ts: Option<u64>,
+) -> LedgerRecord {
+    LedgerRecord {
+        created_ts: 10,
+        sent_ts,
+        ack_ts,
+        last_fill_ts,
+        ..sample_record(7)
+    }
+}
+
+#[test]
+fn test_recovery_tlsm_state_from_record_timestamps() {
+    let cases = [
+        (None, None, None, TlsmState::Created),
+        (Some(20), None, None, TlsmState::Sent),
+        (Some(20), Some(30), None, TlsmState::Acked),
+        (Some(20), Some(30), Some(40), TlsmState::PartiallyFilled),
+        // Fill observed before the ack (WS race) still implies the order was live.
+        (Some(20), None, Some(25), TlsmState::PartiallyFilled),
+    ];
+    for (sent_ts, ack_ts, last_fill_ts, expected) in cases {
+        let record = record_with_timestamps(sent_ts, ack_ts, last_fill_ts);
+        assert_eq!(
+            tlsm_state_from_record(&record),
+            Ok(expected),
+            "sent={sent_ts:?} ack={ack_ts:?} fill={last_fill_ts:?}"
+        );
+    }
+}
+
+#[test]
+fn test_recovery_tlsm_state_rejects_contradictory_timestamps() {
+    assert_eq!(
+        tlsm_state_from_record(&record_with_timestamps(Some(20), None, Some(15))),
+        Err(TlsmRecoveryError::FillBeforeSent)
+    );
+    assert_eq!(
+        tlsm_state_from_record(&record_with_timestamps(None, Some(30), None)),
+        Err(TlsmRecoveryError::AckWithoutSent)
+    );
+    assert_eq!(
+        tlsm_state_from_record(&record_with_timestamps(Some(5), None, None)),
+        Err(TlsmRecoveryError::SentBeforeCreated)
+    );
+}
+
+fn perp_metadata() -> InstrumentMetadata {
+    InstrumentMetadata {
+        instrument_kind: InstrumentKind::Perpetual,
+        tick_size: 0.5,
+        amount_step: 10.0,
+        min_amount: 10.0,
+        contract_multiplier: 1.0,
+        expiration_ts_ms: None,
+    }
+}
+
+#[test]
+fn test_recovery_intent_from_record_rebuilds_quantized_intent() {
+    let record = LedgerRecord {
+        side: Side::Sell,
+        qty_steps: Some(3),
+        limit_price_q: None,
+        price_ticks: Some(201),
+        ..sample_record(11)
+    };
+    let intent = intent_from_record(&record, &perp_metadata()).expect("rebuild");
+    assert_eq!(intent.instrument_kind, InstrumentKind::Perpetual);
+    assert_eq!(intent.order_type, OrderType::Limit);
+    assert_eq!(intent.side, Some(IntentSide::Sell));
+    assert_eq!(intent.limit_price, Some(100.5));
+    assert_eq!(
+        intent.notional,
+        Some(IntentNotional {
+            qty: 30.0,
+            price: 100.5,
+            contract_multiplier: 1.0,
+        })
+    );
+    assert_eq!(intent.trigger, None);
+
+    // Quantized forms take precedence over step/tick counts.
+    let record = LedgerRecord {
+        qty_q: Some(20.0),
+        ..sample_record(12)
+    };
+    let intent = intent_from_record(&record, &perp_metadata()).expect("rebuild");
+    assert_eq!(intent.notional.map(|n| n.qty), Some(20.0));
+    assert_eq!(intent.side, Some(IntentSide::Buy));
+}
+
+#[test]
+fn test_recovery_intent_from_record_rejects_underspecified_record() {
+    let record = LedgerRecord {
+        qty_steps: None,
+        qty_q: None,
+        ..sample_record(13)
+    };
+    assert_eq!(
+        intent_from_record(&record, &perp_metadata()),
+        Err(RecoveryError::MissingQuantity)
+    );
+
+    let record = LedgerRecord {
+        limit_price_q: None,
+        price_ticks: None,
+        ..sample_record(14)
+    };
+    assert_eq!(
+        intent_from_record(&record, &perp_metadata()),
+        Err(RecoveryError::MissingPrice)
+    );
+}
+
+#[test]
+fn test_recovery_rebuilds_reservations_for_live_records_only() {
+    let record =
+        |intent_hash: u64, tls_state: &str, sent_ts: Option<u64>, side: Side| LedgerRecord {
+            side,
+            qty_q: Some(2.0),
+            tls_state: tls_state.to_string(),
+            sent_ts,
+            ..sample_record(intent_hash)
+        };
+    let replay = LedgerReplay {
+        records: vec![
+            record(1, "Sent", Some(10), Side::Buy),
+            record(2, "Filled", Some(10), Side::Buy),
+            record(3, "Acked", Some(10), Side::Sell),
+            record(4, "Created", None, Side::Buy),
+            record(5, "Canceled", Some(10), Side::Sell),
+            LedgerRecord {
+                qty_q: None,
+                ..record(6, "Sent", Some(10), Side::Buy)
+            },
+        ],
+    };
+    let tracker = PendingExposureTracker::new(None);
+
+    let rebuild = rebuild_reservations(&replay, &tracker);
+    assert_eq!(rebuild.restored, vec![1, 3]);
+    assert_eq!(rebuild.skipped, vec![2, 4, 5]);
+    assert_eq!(rebuild.missing_qty, vec![6]);
+    assert_eq!(tracker.get_pending_delta("BTC-PERP"), 4.0);
+    assert_eq!(tracker.get_pending_signed_delta("BTC-PERP"), 0.0);
+    assert!(tracker.release(&"1".to_string(), "BTC-PERP"));
+}
+
+fn group_leg(intent_hash: u64, group_id: &str, leg_idx: u32) -> LedgerRecord {
+    LedgerRecord {
+        group_id: group_id.to_string(),
+        leg_idx,
+        ..sample_record(intent_hash)
+    }
+}
+
+#[test]
+fn test_ledger_group_commit_replays_all_legs() {
+    let path = temp_wal_path("group_commit");
+    let ledger = Ledger::open(&path).expect("open ledger");
+
+    ledger
+        .record_before_dispatch(sample_record(1))
+        .expect("single record");
+    ledger
+        .record_group_before_dispatch(vec![
+            group_leg(10, "combo-1", 0),
+            group_leg(11, "combo-1", 1),
+            group_leg(12, "combo-1", 2),
+        ])
+        .expect("group record");
+    ledger.flush().expect("flush");
+
+    let replay = ledger.replay_latest().expect("replay");
+    let legs: Vec<(u32, u64)> = replay
+        .records_for_group("combo-1")
+        .iter()
+        .map(|record| (record.leg_idx, record.intent_hash))
+        .collect();
+    assert_eq!(legs, vec![(0, 10), (1, 11), (2, 12)]);
+    assert!(replay.record_by_intent_hash(1).is_some());
+    assert!(ledger.check_sequence().expect("sequence").is_empty());
+
+    let (incremental, _) = ledger
+        .replay_since(ReplayCursor::default())
+        .expect("replay since");
+    assert_eq!(incremental, replay);
+
+    let err = ledger
+        .record_group_before_dispatch(vec![
+            group_leg(20, "combo-2", 0),
+            group_leg(21, "combo-3", 1),
+        ])
+        .expect_err("mixed group ids");
+    assert!(matches!(err, LedgerError::RecordSchema(_)));
+}
+
+#[test]
+fn test_ledger_group_commit_queue_full_persists_no_legs() {
+    let path = temp_wal_path("group_queue_full");
+    let ledger = Ledger::open_with_config(
+        &path,
+        LedgerConfig {
+            queue_capacity: 1,
+            writer_pause_on_start: true,
+        },
+    )
+    .expect("open ledger");
+
+    // Fill the queue behind the paused writer.
+    let mut filled = 0;
+    while ledger
+        .record_before_dispatch(sample_record(100 + filled))
+        .is_ok()
+    {
+        filled += 1;
+    }
+    let errors_before = ledger.wal_write_errors_total();
+
+    let err = ledger
+        .record_group_before_dispatch(vec![
+            group_leg(10, "combo-1", 0),
+            group_leg(11, "combo-1", 1),
+        ])
+        .expect_err("queue full");
+    assert!(matches!(err, LedgerError::QueueFull));
+    assert_eq!(ledger.wal_write_errors_total(), errors_before + 1);
+
+    ledger.resume_writer();
+    ledger.flush().expect("flush");
+    let replay = ledger.replay_latest().expect("replay");
+    assert_eq!(replay.records.len() as u64, filled);
+    assert!(replay.records_for_group("combo-1").is_empty());
+}
+
+#[test]
+fn test_ledger_torn_group_frame_is_dropped_on_replay() {
+    let path = temp_wal_path("torn_group");
+    let ledger = Ledger::open(&path).expect("open ledger");
+    ledger
+        .record_group_before_dispatch(vec![
+            group_leg(10, "combo-1", 0),
+            group_leg(11, "combo-1", 1),
+        ])
+        .expect("group record");
+    ledger.flush().expect("flush");
+    drop(ledger);
+
+    // Simulate a crash mid-append: a second frame whose last leg never landed.
+    let contents = std::fs::read_to_string(&path).expect("read wal");
+    let mut lines = contents.lines();
+    let _ = lines.next();
+    let leg_line = lines
+        .next()
+        .expect("leg line")
+        .replace("combo-1", "combo-2");
+    let mut torn = contents.clone();
+    torn.push_str("group_commit=combo-2|legs=2\n");
+    torn.push_str(&leg_line);
+    torn.push('\n');
+    std::fs::write(&path, &torn).expect("write torn wal");
+
+    let ledger = Ledger::open(&path).expect("reopen ledger");
+    let replay = ledger.replay_latest().expect("replay");
+    assert_eq!(replay.records_for_group("combo-1").len(), 2);
+    assert!(replay.records_for_group("combo-2").is_empty());
+
+    // Incremental replay stops before the incomplete frame so it is re-read later.
+    let (_, cursor) = ledger
+        .replay_since(ReplayCursor::default())
+        .expect("replay since");
+    assert_eq!(cursor.offset(), contents.len() as u64);
+}
+
+#[test]
+fn test_ledger_torn_group_frame_does_not_absorb_later_standalone_record() {
+    let path = temp_wal_path("torn_group_standalone");
+    let ledger = Ledger::open(&path).expect("open ledger");
+    ledger
+        .record_group_before_dispatch(vec![
+            group_leg(10, "combo-1", 0),
+            group_leg(11, "combo-1", 1),
+        ])
+        .expect("group record");
+    ledger.flush().expect("flush");
+    drop(ledger);
+
+    // Crash after the first leg of a two-leg frame landed.
+    let contents = std::fs::read_to_string(&path).expect("read wal");
+    let leg_line = contents
+        .lines()
+        .nth(1)
+        .expect("leg line")
+        .replace("combo-1", "combo-2");
+    let mut torn = contents.clone();
+    torn.push_str("group_commit=combo-2|legs=2\n");
+    torn.push_str(&leg_line);
+    torn.push('\n');
+    std::fs::write(&path, &torn).expect("write torn wal");
+
+    // After restart, an outcome for leg 0 is appended as a standalone record.
+    let ledger = Ledger::open(&path).expect("reopen ledger");
+    ledger
+        .record_replay_outcome(
+            group_leg(12, "combo-2", 0),
+            ReplayOutcome::Sent { sent_ts: 5 },
+        )
+        .expect("standalone record");
+    ledger.flush().expect("flush");
+
+    let replay = ledger.replay_latest().expect("replay");
+    let combo_2: Vec<u64> = replay
+        .records_for_group("combo-2")
+        .iter()
+        .map(|record| record.intent_hash)
+        .collect();
+    assert_eq!(combo_2, vec![12]);
+    assert_eq!(replay.records_for_group("combo-1").len(), 2);
+}
@@ -2,6 +2,8 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
 
+use soldier_core::execution::{QuantizedSteps, Side};
+use soldier_core::idempotency::{DedupOutcome, IntentDedup, IntentHashInput};
 use soldier_infra::{TradeIdInsertOutcome, TradeIdRecord, TradeIdRegistry};
 
 static REGISTRY_COUNTER: AtomicUsize = AtomicUsize::new(0);
@@ -116,3 +118,33 @@ fn test_trade_id_registry_concurrent_insert() {
         .collect();
     assert_eq!(lines.len(), 1);
 }
+
+#[test]
+fn test_dedup_outcome_distinguishes_trade_id_from_content_hash() {
+    let path = temp_registry_path("dedup_outcome");
+    let registry = TradeIdRegistry::open(&path).expect("open registry");
+    let record = sample_record("trade-789");
+    let first = registry.record_trade(record.clone()).expect("insert trade");
+    let second = registry.record_trade(record).expect("duplicate trade");
+    assert_eq!(DedupOutcome::from(first), DedupOutcome::Accepted);
+    assert_eq!(DedupOutcome::from(second), DedupOutcome::DuplicateTradeId);
+
+    let dedup = IntentDedup::new(1_000);
+    let input = IntentHashInput {
+        instrument_id: "BTC-PERP",
+        side: Side::Buy,
+        quantized: QuantizedSteps {
+            qty_steps: 12,
+            price_ticks: 201,
+            qty_q: 1.2,
+            limit_price_q: 100.5,
+        },
+        group_id: "group-1",
+        leg_idx: 0,
+    };
+    assert_eq!(dedup.check_outcome(&input, 10_000), DedupOutcome::Accepted);
+    assert_eq!(
+        dedup.check_outcome(&input, 10_001),
+        DedupOutcome::DuplicateContentHash
+    );
+}